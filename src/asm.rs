@@ -8,8 +8,12 @@
 
 //! Wrappers around ARMv8-A instructions.
 
+pub mod at;
 pub mod barrier;
+pub mod cache;
+pub mod pstate;
 pub mod random;
+pub mod tlb;
 
 /// The classic no-op
 #[inline(always)]
@@ -83,6 +87,52 @@ pub fn sev() {
     unimplemented!()
 }
 
+/// Supervisor Call
+///
+/// Generates an exception targeting EL1 with the immediate `IMM` in the syndrome. Registers that
+/// are not preserved by the C calling convention are considered clobbered. See
+/// [`smccc`](crate::smccc) for calls that pass arguments and results in registers.
+#[inline(always)]
+pub fn svc<const IMM: u16>() {
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        core::arch::asm!("svc #{imm}", imm = const IMM, clobber_abi("C"))
+    }
+
+    #[cfg(not(target_arch = "aarch64"))]
+    unimplemented!()
+}
+
+/// Hypervisor Call
+///
+/// Generates an exception targeting EL2 with the immediate `IMM` in the syndrome. Registers that
+/// are not preserved by the C calling convention are considered clobbered.
+#[inline(always)]
+pub fn hvc<const IMM: u16>() {
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        core::arch::asm!("hvc #{imm}", imm = const IMM, clobber_abi("C"))
+    }
+
+    #[cfg(not(target_arch = "aarch64"))]
+    unimplemented!()
+}
+
+/// Secure Monitor Call
+///
+/// Generates an exception targeting EL3 with the immediate `IMM` in the syndrome. Registers that
+/// are not preserved by the C calling convention are considered clobbered.
+#[inline(always)]
+pub fn smc<const IMM: u16>() {
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        core::arch::asm!("smc #{imm}", imm = const IMM, clobber_abi("C"))
+    }
+
+    #[cfg(not(target_arch = "aarch64"))]
+    unimplemented!()
+}
+
 /// Exception return
 ///
 /// Will jump to wherever the corresponding link register points to, and therefore never return.
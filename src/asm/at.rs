@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Address translation instructions.
+//!
+//! The result of an `AT` instruction is reported in `PAR_EL1`. Since `PAR_EL1` is shared by all
+//! address translation instructions, callers must make sure that no other `AT` instruction is
+//! executed between the translation and the read of the result, e.g. by an interrupt handler.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::asm::at;
+//!
+//! let va = 0xffff_0000_0008_0000;
+//!
+//! match at::at(at::S1E1R, va) {
+//!     Ok(translation) => {
+//!         let _pa = translation.pa;
+//!     }
+//!     Err(fault) => {
+//!         let _fst = fault.fst;
+//!     }
+//! }
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{esr::FaultStatus, PAR_EL1},
+};
+use tock_registers::{interfaces::Readable, LocalRegisterCopy};
+
+mod sealed {
+    pub trait At {
+        fn __at(&self, va: usize);
+    }
+}
+
+macro_rules! at {
+    ($A:ident) => {
+        impl sealed::At for $A {
+            #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+            #[inline(always)]
+            fn __at(&self, va: usize) {
+                match () {
+                    #[cfg(target_arch = "aarch64")]
+                    () => unsafe {
+                        core::arch::asm!(concat!("AT ", stringify!($A), ", {x}"), x = in(reg) va, options(nostack))
+                    },
+
+                    #[cfg(not(target_arch = "aarch64"))]
+                    () => unimplemented!(),
+                }
+            }
+        }
+    };
+}
+
+/// Stage 1 translation with EL0 permissions, read access, for the EL1&0 translation regime.
+pub struct S1E0R;
+/// Stage 1 translation with EL0 permissions, write access, for the EL1&0 translation regime.
+pub struct S1E0W;
+/// Stage 1 translation with EL1 permissions, read access, for the EL1&0 translation regime.
+pub struct S1E1R;
+/// Stage 1 translation with EL1 permissions, write access, for the EL1&0 translation regime.
+pub struct S1E1W;
+/// Stage 1 translation with EL1 permissions, read access, taking PSTATE.PAN into account.
+/// Requires FEAT_PAN2.
+pub struct S1E1RP;
+/// Stage 1 translation with EL1 permissions, write access, taking PSTATE.PAN into account.
+/// Requires FEAT_PAN2.
+pub struct S1E1WP;
+/// Stage 1 translation with EL2 permissions, read access, for the EL2 or EL2&0 translation regime.
+pub struct S1E2R;
+/// Stage 1 translation with EL2 permissions, write access, for the EL2 or EL2&0 translation
+/// regime.
+pub struct S1E2W;
+/// Stage 1 and 2 translation with EL0 permissions, read access, for the EL1&0 translation regime.
+pub struct S12E0R;
+/// Stage 1 and 2 translation with EL0 permissions, write access, for the EL1&0 translation regime.
+pub struct S12E0W;
+/// Stage 1 and 2 translation with EL1 permissions, read access, for the EL1&0 translation regime.
+pub struct S12E1R;
+/// Stage 1 and 2 translation with EL1 permissions, write access, for the EL1&0 translation regime.
+pub struct S12E1W;
+/// Stage 1 translation with EL3 permissions, read access, for the EL3 translation regime.
+pub struct S1E3R;
+/// Stage 1 translation with EL3 permissions, write access, for the EL3 translation regime.
+pub struct S1E3W;
+
+at!(S1E0R);
+at!(S1E0W);
+at!(S1E1R);
+at!(S1E1W);
+at!(S1E1RP);
+at!(S1E1WP);
+at!(S1E2R);
+at!(S1E2W);
+at!(S12E0R);
+at!(S12E0W);
+at!(S12E1R);
+at!(S12E1W);
+at!(S1E3R);
+at!(S1E3W);
+
+/// Shareability attribute of a translated address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Shareability {
+    NonShareable,
+    OuterShareable,
+    InnerShareable,
+}
+
+/// Result of a successful address translation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Translation {
+    /// The output address, including the offset of the input address within its 4KiB page.
+    pub pa: u64,
+    /// Memory attributes, in the encoding of the `Attr<n>` fields of `MAIR_ELx`.
+    pub attr: u8,
+    /// Shareability attribute.
+    pub sh: Shareability,
+    /// Non-secure attribute, for translations from Secure state.
+    pub ns: bool,
+}
+
+/// Translation stage at which an address translation aborted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Stage1,
+    Stage2,
+}
+
+/// Result of an aborted address translation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TranslationFault {
+    /// Fault status code.
+    pub fst: FaultStatus,
+    /// The translation aborted because of a stage 2 fault during a stage 1 translation table walk.
+    pub ptw: bool,
+    /// Translation stage at which the translation aborted.
+    pub stage: Stage,
+}
+
+/// Decodes a `PAR_EL1` value. `va` provides the page offset of the returned output address.
+pub fn decode_par(par: u64, va: usize) -> Result<Translation, TranslationFault> {
+    let par: LocalRegisterCopy<u64, PAR_EL1::Register> = LocalRegisterCopy::new(par);
+
+    if par.matches_all(PAR_EL1::F::TranslationAborted) {
+        return Err(TranslationFault {
+            fst: FaultStatus::from_bits(par.read(PAR_EL1::FST) as u8),
+            ptw: par.is_set(PAR_EL1::PTW),
+            stage: if par.matches_all(PAR_EL1::S::Stage2) {
+                Stage::Stage2
+            } else {
+                Stage::Stage1
+            },
+        });
+    }
+
+    let pa = (par.read(PAR_EL1::PA_51_48) << 48) | (par.read(PAR_EL1::PA) << 12);
+
+    Ok(Translation {
+        pa: pa | (va as u64 & 0xfff),
+        attr: par.read(PAR_EL1::ATTR) as u8,
+        sh: match par.read_as_enum(PAR_EL1::SH) {
+            Some(PAR_EL1::SH::Value::OuterShareable) => Shareability::OuterShareable,
+            Some(PAR_EL1::SH::Value::InnerShareable) => Shareability::InnerShareable,
+            _ => Shareability::NonShareable,
+        },
+        ns: par.is_set(PAR_EL1::NS),
+    })
+}
+
+/// Performs an address translation of `va` and returns the result read from `PAR_EL1`.
+#[inline(always)]
+pub fn at<A>(arg: A, va: usize) -> Result<Translation, TranslationFault>
+where
+    A: sealed::At,
+{
+    arg.__at(va);
+    barrier::isb(barrier::SY);
+
+    decode_par(PAR_EL1.get(), va)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_success() {
+        // Normal WB memory, Inner Shareable, 52-bit PA.
+        let par = (0xff << 56) | (0xa << 48) | 0x1234_5678_9000 | (0b11 << 7);
+
+        assert_eq!(
+            decode_par(par, 0xffff_0000_0000_0abc),
+            Ok(Translation {
+                pa: 0xa_1234_5678_9abc,
+                attr: 0xff,
+                sh: Shareability::InnerShareable,
+                ns: false,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_fault() {
+        // Stage 2 translation fault, level 2, on a stage 1 walk.
+        let par = (1 << 11) | (1 << 9) | (1 << 8) | (0b00_0110 << 1) | 1;
+
+        assert_eq!(
+            decode_par(par, 0),
+            Err(TranslationFault {
+                fst: FaultStatus::Translation { level: 2 },
+                ptw: true,
+                stage: Stage::Stage2,
+            })
+        );
+    }
+}
@@ -32,7 +32,11 @@ macro_rules! dmb_dsb {
                         core::arch::asm!(concat!("DMB ", stringify!($A)), options(nostack))
                     },
 
-                    #[cfg(not(target_arch = "aarch64"))]
+                    // Barriers have no effect on the mock register file.
+                    #[cfg(all(not(target_arch = "aarch64"), feature = "mock"))]
+                    () => {}
+
+                    #[cfg(not(any(target_arch = "aarch64", feature = "mock")))]
                     () => unimplemented!(),
                 }
             }
@@ -46,7 +50,10 @@ macro_rules! dmb_dsb {
                         core::arch::asm!(concat!("DSB ", stringify!($A)), options(nostack))
                     },
 
-                    #[cfg(not(target_arch = "aarch64"))]
+                    #[cfg(all(not(target_arch = "aarch64"), feature = "mock"))]
+                    () => {}
+
+                    #[cfg(not(any(target_arch = "aarch64", feature = "mock")))]
                     () => unimplemented!(),
                 }
             }
@@ -87,7 +94,10 @@ impl sealed::Isb for SY {
             #[cfg(target_arch = "aarch64")]
             () => unsafe { core::arch::asm!("ISB SY", options(nostack)) },
 
-            #[cfg(not(target_arch = "aarch64"))]
+            #[cfg(all(not(target_arch = "aarch64"), feature = "mock"))]
+            () => {}
+
+            #[cfg(not(any(target_arch = "aarch64", feature = "mock")))]
             () => unimplemented!(),
         }
     }
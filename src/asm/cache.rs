@@ -0,0 +1,427 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Cache maintenance instructions.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::asm::cache;
+//!
+//! let buf = [0u8; 256];
+//!
+//! // Make the buffer visible to a non-coherent DMA master.
+//! cache::clean_dcache_range(buf.as_ptr() as usize, buf.len());
+//!
+//! // Clean and invalidate all data caches by set/way, e.g. before turning the MMU off.
+//! unsafe { cache::dcache_all(cache::CISW) };
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{CCSIDR_EL1, CLIDR_EL1, CSSELR_EL1, CTR_EL0},
+};
+use tock_registers::interfaces::{Readable, Writeable};
+
+mod sealed {
+    pub trait DcByVa {
+        fn __dc(&self, addr: usize);
+    }
+
+    pub trait DcBySetWay {
+        fn __dc(&self, set_way: u64);
+    }
+
+    pub trait Ic {
+        fn __ic(&self);
+    }
+
+    pub trait IcByVa {
+        fn __ic(&self, addr: usize);
+    }
+}
+
+macro_rules! dc_op {
+    (@insn $A:ident) => {
+        concat!("DC ", stringify!($A))
+    };
+    (@insn $A:ident = $sys:literal) => {
+        $sys
+    };
+    ($A:ident $(= $sys:literal)?, $trait:ident, $ty:ty) => {
+        impl sealed::$trait for $A {
+            #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+            #[inline(always)]
+            fn __dc(&self, arg: $ty) {
+                match () {
+                    #[cfg(target_arch = "aarch64")]
+                    () => unsafe {
+                        core::arch::asm!(concat!(dc_op!(@insn $A $(= $sys)?), ", {x}"), x = in(reg) arg, options(nostack))
+                    },
+
+                    #[cfg(not(target_arch = "aarch64"))]
+                    () => unimplemented!(),
+                }
+            }
+        }
+    };
+}
+
+/// Data or unified cache line Clean and Invalidate by VA to PoC.
+pub struct CIVAC;
+/// Data or unified cache line Clean by VA to PoC.
+pub struct CVAC;
+/// Data or unified cache line Clean by VA to PoU.
+pub struct CVAU;
+/// Data or unified cache line Clean by VA to PoP. Requires FEAT_DPB.
+pub struct CVAP;
+/// Data or unified cache line Invalidate by VA to PoC.
+pub struct IVAC;
+/// Data Cache Zero by VA. Zeroes a naturally aligned block of the size given by `DCZID_EL0.BS`.
+pub struct ZVA;
+
+/// Data or unified cache line Clean and Invalidate by Set/Way.
+pub struct CISW;
+/// Data or unified cache line Clean by Set/Way.
+pub struct CSW;
+/// Data or unified cache line Invalidate by Set/Way.
+pub struct ISW;
+
+dc_op!(CIVAC, DcByVa, usize);
+dc_op!(CVAC, DcByVa, usize);
+dc_op!(CVAU, DcByVa, usize);
+// Emitted by its `SYS` encoding, so that it assembles without the `ccpp` target feature.
+dc_op!(CVAP = "sys #3, c7, c12, #1", DcByVa, usize);
+dc_op!(IVAC, DcByVa, usize);
+dc_op!(ZVA, DcByVa, usize);
+
+dc_op!(CISW, DcBySetWay, u64);
+dc_op!(CSW, DcBySetWay, u64);
+dc_op!(ISW, DcBySetWay, u64);
+
+/// Instruction cache Invalidate All to PoU.
+pub struct IALLU;
+/// Instruction cache Invalidate All to PoU, Inner Shareable.
+pub struct IALLUIS;
+/// Instruction cache line Invalidate by VA to PoU.
+pub struct IVAU;
+
+macro_rules! ic_op {
+    ($A:ident) => {
+        impl sealed::Ic for $A {
+            #[inline(always)]
+            fn __ic(&self) {
+                match () {
+                    #[cfg(target_arch = "aarch64")]
+                    () => unsafe {
+                        core::arch::asm!(concat!("IC ", stringify!($A)), options(nostack))
+                    },
+
+                    #[cfg(not(target_arch = "aarch64"))]
+                    () => unimplemented!(),
+                }
+            }
+        }
+    };
+}
+
+ic_op!(IALLU);
+ic_op!(IALLUIS);
+
+impl sealed::IcByVa for IVAU {
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    #[inline(always)]
+    fn __ic(&self, addr: usize) {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe { core::arch::asm!("IC IVAU, {x}", x = in(reg) addr, options(nostack)) },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
+
+/// Data Cache operation by virtual address.
+///
+/// # Safety
+///
+/// [`IVAC`] discards the contents of the whole cache line holding `addr`, and [`ZVA`] zeroes the
+/// whole block holding `addr`. The caller must ensure that the line or block holds no data that
+/// is still needed, also outside of the object that `addr` points into. The clean operations have
+/// no such requirement.
+#[inline(always)]
+pub unsafe fn dc<A>(arg: A, addr: usize)
+where
+    A: sealed::DcByVa,
+{
+    arg.__dc(addr)
+}
+
+/// Data Cache operation by set/way.
+///
+/// `set_way` holds the cache level in bits \[3:1\], the set in bits \[B-1:L\] and the way in
+/// bits \[31:32-A\], where L is Log2 of the line length in bytes, B is L + Log2 of the number of
+/// sets and A is Log2 of the associativity, both rounded up. See [`set_way`] to build the operand.
+///
+/// # Safety
+///
+/// [`ISW`] discards the contents of the cache line at `set_way`, which can hold dirty data of any
+/// address. The caller must ensure that the line holds no data that is still needed, e.g. because
+/// the caches have not been enabled since reset. The clean operations have no such requirement.
+#[inline(always)]
+pub unsafe fn dc_sw<A>(arg: A, set_way: u64)
+where
+    A: sealed::DcBySetWay,
+{
+    arg.__dc(set_way)
+}
+
+/// Instruction Cache operation on the whole cache.
+#[inline(always)]
+pub fn ic<A>(arg: A)
+where
+    A: sealed::Ic,
+{
+    arg.__ic()
+}
+
+/// Instruction Cache operation by virtual address.
+#[inline(always)]
+pub fn ic_va<A>(arg: A, addr: usize)
+where
+    A: sealed::IcByVa,
+{
+    arg.__ic(addr)
+}
+
+/// Geometry of a single cache level, as reported by `CCSIDR_EL1`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CacheGeometry {
+    /// Cache level, starting at 0 for L1.
+    pub level: u8,
+    /// Log2 of the number of bytes in a cache line.
+    pub line_shift: u32,
+    /// Number of sets.
+    pub num_sets: u32,
+    /// Number of ways.
+    pub associativity: u32,
+}
+
+impl CacheGeometry {
+    /// Selects the data or unified cache at `level` (starting at 0 for L1) in `CSSELR_EL1` and
+    /// reads its geometry from `CCSIDR_EL1`.
+    pub fn read_dcache(level: u8) -> Self {
+        CSSELR_EL1.write(CSSELR_EL1::Level.val(level as u64) + CSSELR_EL1::InD::Data);
+        barrier::isb(barrier::SY);
+
+        CacheGeometry {
+            level,
+            line_shift: CCSIDR_EL1.read(CCSIDR_EL1::LineSize) as u32 + 4,
+            num_sets: CCSIDR_EL1.get_num_sets() as u32 + 1,
+            associativity: CCSIDR_EL1.get_associativity() as u32 + 1,
+        }
+    }
+}
+
+/// Builds the operand of a set/way operation for the given cache geometry.
+#[inline(always)]
+pub fn set_way(geometry: &CacheGeometry, set: u32, way: u32) -> u64 {
+    let way_bits = 32 - (geometry.associativity - 1).leading_zeros();
+    let way = if way_bits == 0 {
+        0
+    } else {
+        (way as u64) << (32 - way_bits)
+    };
+
+    way | ((set as u64) << geometry.line_shift) | ((geometry.level as u64) << 1)
+}
+
+/// Performs a set/way operation on every data or unified cache up to the Level of Coherence, as
+/// indicated by `CLIDR_EL1`.
+///
+/// Set/way operations are local to the executing PE and are only useful for cache maintenance
+/// during power-up or power-down sequences. They cannot be used to make memory coherent with
+/// other observers.
+///
+/// # Safety
+///
+/// [`ISW`] discards the contents of all data and unified caches up to the Level of Coherence,
+/// including any dirty data. The caller must ensure that the caches hold no data that is still
+/// needed, e.g. because they have not been enabled since reset. The clean operations have no such
+/// requirement.
+pub unsafe fn dcache_all<A>(arg: A)
+where
+    A: sealed::DcBySetWay,
+{
+    let clidr = CLIDR_EL1.get();
+    let loc = CLIDR_EL1.read(CLIDR_EL1::LoC) as u8;
+
+    barrier::dsb(barrier::SY);
+
+    for level in 0..loc {
+        // Ctype<n>: 0b010 data only, 0b011 separate instruction and data, 0b100 unified.
+        let ctype = (clidr >> (3 * level)) & 0b111;
+        if ctype < 0b010 {
+            continue;
+        }
+
+        let geometry = CacheGeometry::read_dcache(level);
+        for set in 0..geometry.num_sets {
+            for way in 0..geometry.associativity {
+                arg.__dc(set_way(&geometry, set, way));
+            }
+        }
+    }
+
+    // Restore the cache selection to L1 data.
+    CSSELR_EL1.set(0);
+    barrier::dsb(barrier::SY);
+    barrier::isb(barrier::SY);
+}
+
+/// Calls `f` with the address of every line of `line_size` bytes that holds `[start, start + len)`,
+/// which may end at the top of the address space.
+#[inline(always)]
+fn for_each_line(start: usize, len: usize, line_size: usize, mut f: impl FnMut(usize)) {
+    if len == 0 {
+        return;
+    }
+
+    let last = start.saturating_add(len - 1);
+    let mut addr = start & !(line_size - 1);
+
+    loop {
+        f(addr);
+
+        match addr.checked_add(line_size) {
+            Some(next) if next <= last => addr = next,
+            _ => break,
+        }
+    }
+}
+
+/// Cleans all data cache lines that hold `[start, start + len)` to the Point of Coherency.
+pub fn clean_dcache_range(start: usize, len: usize) {
+    for_each_line(start, len, CTR_EL0.dcache_line_size(), |addr| {
+        // SAFETY: CVAC writes dirty data back to the PoC and leaves the line valid.
+        unsafe { dc(CVAC, addr) }
+    });
+    barrier::dsb(barrier::SY);
+}
+
+/// Cleans and invalidates all data cache lines that hold `[start, start + len)` to the Point of
+/// Coherency.
+pub fn clean_invalidate_dcache_range(start: usize, len: usize) {
+    for_each_line(start, len, CTR_EL0.dcache_line_size(), |addr| {
+        // SAFETY: CIVAC writes dirty data back to the PoC before it invalidates the line.
+        unsafe { dc(CIVAC, addr) }
+    });
+    barrier::dsb(barrier::SY);
+}
+
+/// Invalidates all data cache lines that hold `[start, start + len)` to the Point of Coherency.
+///
+/// Lines that only partially overlap the range are invalidated as a whole, discarding any dirty
+/// data that they hold outside of the range.
+///
+/// # Safety
+///
+/// The caller must ensure that the range holds no dirty data that is still needed. If the range
+/// is not aligned to the cache writeback granule (`CTR_EL0.CWG`), the same applies to the memory
+/// sharing the first and last cache line with the range.
+pub unsafe fn invalidate_dcache_range(start: usize, len: usize) {
+    for_each_line(start, len, CTR_EL0.dcache_line_size(), |addr| {
+        dc(IVAC, addr)
+    });
+    barrier::dsb(barrier::SY);
+}
+
+/// Makes instructions written to `[start, start + len)` visible to instruction fetches of all
+/// PEs in the Inner Shareable domain.
+///
+/// Skips the data cache clean if `CTR_EL0.IDC` is set and the instruction cache invalidation if
+/// `CTR_EL0.DIC` is set.
+pub fn sync_icache_range(start: usize, len: usize) {
+    let ctr = CTR_EL0.extract();
+
+    if !ctr.is_set(CTR_EL0::IDC) {
+        for_each_line(start, len, CTR_EL0.dcache_line_size(), |addr| {
+            // SAFETY: CVAU writes dirty data back to the PoU and leaves the line valid.
+            unsafe { dc(CVAU, addr) }
+        });
+    }
+    barrier::dsb(barrier::ISH);
+
+    if !ctr.is_set(CTR_EL0::DIC) {
+        for_each_line(start, len, CTR_EL0.icache_line_size(), |addr| {
+            ic_va(IVAU, addr)
+        });
+        barrier::dsb(barrier::ISH);
+    }
+    barrier::isb(barrier::SY);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_way_operand() {
+        // 32 KiB, 4-way, 64-byte lines.
+        let l1 = CacheGeometry {
+            level: 0,
+            line_shift: 6,
+            num_sets: 128,
+            associativity: 4,
+        };
+        assert_eq!(set_way(&l1, 0, 0), 0);
+        assert_eq!(set_way(&l1, 127, 3), (3 << 30) | (127 << 6));
+
+        // 1 MiB, 16-way, 64-byte lines, L2.
+        let l2 = CacheGeometry {
+            level: 1,
+            line_shift: 6,
+            num_sets: 1024,
+            associativity: 16,
+        };
+        assert_eq!(set_way(&l2, 5, 15), (15 << 28) | (5 << 6) | (1 << 1));
+
+        // Direct mapped caches have no way bits, 3 ways round up to 2 bits.
+        let dm = CacheGeometry {
+            associativity: 1,
+            ..l1
+        };
+        assert_eq!(set_way(&dm, 1, 0), 1 << 6);
+        let w3 = CacheGeometry {
+            associativity: 3,
+            ..l1
+        };
+        assert_eq!(set_way(&w3, 0, 2), 2 << 30);
+    }
+
+    #[test]
+    fn line_iteration() {
+        let mut lines = [0usize; 4];
+        let mut n = 0;
+        for_each_line(0x1030, 0x50, 0x40, |addr| {
+            lines[n] = addr;
+            n += 1;
+        });
+        assert_eq!(&lines[..n], &[0x1000, 0x1040]);
+
+        n = 0;
+        for_each_line(0x1030, 0, 0x40, |_| n += 1);
+        assert_eq!(n, 0);
+
+        for_each_line(usize::MAX - 0x50, 0x100, 0x40, |addr| {
+            lines[n] = addr;
+            n += 1;
+        });
+        assert_eq!(&lines[..n], &[usize::MAX - 0x7f, usize::MAX - 0x3f]);
+    }
+}
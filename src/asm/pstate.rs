@@ -0,0 +1,268 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Process state changes with `MSR <pstatefield>, #imm`.
+//!
+//! Each function is a single instruction, instead of a read-modify-write of the register holding
+//! the field. Fields that require an architecture extension are encoded generically, so that they
+//! do not depend on the target features enabled at compile time.
+//!
+//! In the mock backend, the functions are recorded as writes of the registers holding the fields,
+//! e.g. [`DAIF`](const@crate::registers::DAIF) for [`daif_set`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::asm::pstate;
+//!
+//! // msr daifset, #2
+//! pstate::daif_set::<{ pstate::DAIF_I }>();
+//!
+//! // msr pan, #1
+//! pstate::set_pan(true);
+//! ```
+
+/// Debug exceptions mask bit of the [`daif_set`] and [`daif_clear`] immediate.
+pub const DAIF_D: u8 = 0b1000;
+/// SError interrupt mask bit of the [`daif_set`] and [`daif_clear`] immediate.
+pub const DAIF_A: u8 = 0b0100;
+/// IRQ mask bit of the [`daif_set`] and [`daif_clear`] immediate.
+pub const DAIF_I: u8 = 0b0010;
+/// FIQ mask bit of the [`daif_set`] and [`daif_clear`] immediate.
+pub const DAIF_F: u8 = 0b0001;
+/// All mask bits of the [`daif_set`] and [`daif_clear`] immediate.
+pub const DAIF_ALL: u8 = DAIF_D | DAIF_A | DAIF_I | DAIF_F;
+
+/// Executes `$asm`, or, in the mock backend, replaces the bits `$mask` of the register `$name` with
+/// `$bits`.
+macro_rules! msr_imm {
+    ($asm:expr, $name:literal, $mask:expr, $bits:expr $(, $op:ident = const $value:expr)?) => {
+        match () {
+            #[cfg(all(target_arch = "aarch64", not(feature = "mock")))]
+            () => unsafe { core::arch::asm!($asm $(, $op = const $value)?, options(nostack)) },
+
+            #[cfg(feature = "mock")]
+            () => {
+                let value = crate::registers::mock::peek($name);
+                crate::registers::mock::write($name, (value & !($mask)) | $bits)
+            }
+
+            #[cfg(not(any(target_arch = "aarch64", feature = "mock")))]
+            () => unimplemented!(),
+        }
+    };
+}
+
+/// Like `msr_imm!`, for instructions changing `PSTATE.SM` or `PSTATE.ZA` of the register `SVCR`.
+///
+/// Entering or exiting Streaming SVE mode sets the SIMD&FP and SVE registers to zero, so they are
+/// declared as clobbered.
+macro_rules! msr_svcr {
+    ($asm:expr, $mask:expr, $bits:expr) => {
+        match () {
+            #[cfg(all(target_arch = "aarch64", not(feature = "mock")))]
+            () => unsafe {
+                core::arch::asm!(
+                    $asm,
+                    out("v0") _, out("v1") _, out("v2") _, out("v3") _, out("v4") _,
+                    out("v5") _, out("v6") _, out("v7") _, out("v8") _, out("v9") _,
+                    out("v10") _, out("v11") _, out("v12") _, out("v13") _, out("v14") _,
+                    out("v15") _, out("v16") _, out("v17") _, out("v18") _, out("v19") _,
+                    out("v20") _, out("v21") _, out("v22") _, out("v23") _, out("v24") _,
+                    out("v25") _, out("v26") _, out("v27") _, out("v28") _, out("v29") _,
+                    out("v30") _, out("v31") _,
+                    out("p0") _, out("p1") _, out("p2") _, out("p3") _, out("p4") _,
+                    out("p5") _, out("p6") _, out("p7") _, out("p8") _, out("p9") _,
+                    out("p10") _, out("p11") _, out("p12") _, out("p13") _, out("p14") _,
+                    out("p15") _,
+                    out("ffr") _,
+                    options(nostack)
+                )
+            },
+
+            #[cfg(any(feature = "mock", not(target_arch = "aarch64")))]
+            () => msr_imm!($asm, "SVCR", $mask, $bits),
+        }
+    };
+}
+
+/// Defines a function setting the single bit field at `$offset` of the register `$name`, with the
+/// instructions `$set` and `$clear`.
+macro_rules! msr_imm_bit {
+    ($(#[$attr:meta])* $fn:ident, $name:literal, $offset:literal, $set:literal, $clear:literal) => {
+        $(#[$attr])*
+        #[inline(always)]
+        pub fn $fn(enable: bool) {
+            if enable {
+                msr_imm!($set, $name, 1 << $offset, 1 << $offset)
+            } else {
+                msr_imm!($clear, $name, 1 << $offset, 0)
+            }
+        }
+    };
+}
+
+/// Masks the exceptions selected by `MASK`, a combination of [`DAIF_D`], [`DAIF_A`], [`DAIF_I`]
+/// and [`DAIF_F`].
+#[inline(always)]
+pub fn daif_set<const MASK: u8>() {
+    msr_imm!(
+        "msr daifset, #{mask}",
+        "DAIF",
+        0,
+        ((MASK & 0xf) as u64) << 6,
+        mask = const MASK
+    )
+}
+
+/// Unmasks the exceptions selected by `MASK`, a combination of [`DAIF_D`], [`DAIF_A`],
+/// [`DAIF_I`] and [`DAIF_F`].
+#[inline(always)]
+pub fn daif_clear<const MASK: u8>() {
+    msr_imm!(
+        "msr daifclr, #{mask}",
+        "DAIF",
+        ((MASK & 0xf) as u64) << 6,
+        0,
+        mask = const MASK
+    )
+}
+
+/// Selects the stack pointer of the current Exception level (`msr spsel, #1`), or `SP_EL0`.
+///
+/// # Safety
+///
+/// The compiler is not aware of the change of the stack pointer. The caller must ensure that the
+/// stack is not in use across the call, e.g. by calling it before setting up the stack of the
+/// selected pointer.
+#[inline(always)]
+pub unsafe fn set_spsel(enable: bool) {
+    if enable {
+        msr_imm!("msr spsel, #1", "SPSEL", 1, 1)
+    } else {
+        msr_imm!("msr spsel, #0", "SPSEL", 1, 0)
+    }
+}
+
+msr_imm_bit!(
+    /// Sets or clears `PSTATE.PAN`. Requires FEAT_PAN.
+    set_pan,
+    "PAN",
+    22,
+    "msr S0_0_C4_C1_4, xzr",
+    "msr S0_0_C4_C0_4, xzr"
+);
+
+msr_imm_bit!(
+    /// Sets or clears `PSTATE.UAO`. Requires FEAT_UAO.
+    set_uao,
+    "UAO",
+    23,
+    "msr S0_0_C4_C1_3, xzr",
+    "msr S0_0_C4_C0_3, xzr"
+);
+
+msr_imm_bit!(
+    /// Sets or clears `PSTATE.DIT`. Requires FEAT_DIT.
+    set_dit,
+    "DIT",
+    24,
+    "msr S0_3_C4_C1_2, xzr",
+    "msr S0_3_C4_C0_2, xzr"
+);
+
+msr_imm_bit!(
+    /// Sets or clears `PSTATE.SSBS`. Requires FEAT_SSBS.
+    set_ssbs,
+    "SSBS",
+    12,
+    "msr S0_3_C4_C1_1, xzr",
+    "msr S0_3_C4_C0_1, xzr"
+);
+
+msr_imm_bit!(
+    /// Sets or clears `PSTATE.TCO`. Requires FEAT_MTE.
+    set_tco,
+    "TCO",
+    25,
+    "msr S0_3_C4_C1_4, xzr",
+    "msr S0_3_C4_C0_4, xzr"
+);
+
+msr_imm_bit!(
+    /// Sets or clears `PSTATE.ALLINT`. Requires FEAT_NMI.
+    set_allint,
+    "ALLINT",
+    13,
+    "msr S0_1_C4_C1_0, xzr",
+    "msr S0_1_C4_C0_0, xzr"
+);
+
+/// Enters (`smstart sm`) or exits (`smstop sm`) Streaming SVE mode. Requires FEAT_SME.
+///
+/// The SIMD&FP and SVE registers are set to zero.
+#[inline(always)]
+pub fn set_streaming_mode(enable: bool) {
+    if enable {
+        msr_svcr!("msr S0_3_C4_C3_3, xzr", 0b01, 0b01)
+    } else {
+        msr_svcr!("msr S0_3_C4_C2_3, xzr", 0b01, 0)
+    }
+}
+
+/// Enables (`smstart za`) or disables (`smstop za`) the ZA storage. Requires FEAT_SME.
+#[inline(always)]
+pub fn set_za(enable: bool) {
+    if enable {
+        msr_svcr!("msr S0_3_C4_C5_3, xzr", 0b10, 0b10)
+    } else {
+        msr_svcr!("msr S0_3_C4_C4_3, xzr", 0b10, 0)
+    }
+}
+
+/// Enters Streaming SVE mode and enables the ZA storage (`smstart`). Requires FEAT_SME.
+///
+/// The SIMD&FP and SVE registers are set to zero.
+#[inline(always)]
+pub fn smstart() {
+    msr_svcr!("msr S0_3_C4_C7_3, xzr", 0b11, 0b11)
+}
+
+/// Exits Streaming SVE mode and disables the ZA storage (`smstop`). Requires FEAT_SME.
+///
+/// The SIMD&FP and SVE registers are set to zero.
+#[inline(always)]
+pub fn smstop() {
+    msr_svcr!("msr S0_3_C4_C6_3, xzr", 0b11, 0)
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::registers::{mock, DAIF, PAN, SVCR};
+    use tock_registers::interfaces::Readable;
+
+    #[test]
+    fn fields() {
+        mock::clear();
+        mock::set_reset_value("DAIF", 0b1111 << 6);
+
+        daif_clear::<{ DAIF_I | DAIF_F }>();
+        assert!(DAIF.matches_all(DAIF::D::Masked + DAIF::I::Unmasked + DAIF::F::Unmasked));
+        daif_set::<DAIF_I>();
+        assert!(DAIF.is_set(DAIF::I));
+
+        set_pan(true);
+        assert!(PAN.matches_all(PAN::PAN::Enabled));
+        set_pan(false);
+        assert_eq!(mock::peek("PAN"), 0);
+
+        smstart();
+        set_streaming_mode(false);
+        assert!(SVCR.matches_all(SVCR::ZA::Enabled + SVCR::SM::Disabled));
+    }
+}
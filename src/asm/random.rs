@@ -29,10 +29,9 @@ impl ArmRng {
     #[cfg(target_arch = "aarch64")]
     #[inline]
     pub fn new() -> Option<Self> {
-        use crate::registers::ID_AA64ISAR0_EL1;
-        use tock_registers::interfaces::Readable;
+        use crate::features::Feature;
 
-        if ID_AA64ISAR0_EL1.is_set(ID_AA64ISAR0_EL1::RNDR) {
+        if Feature::RNG.is_present_on_current_cpu() {
             Some(ArmRng)
         } else {
             None
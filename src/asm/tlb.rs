@@ -0,0 +1,540 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! TLB maintenance instructions.
+//!
+//! The `TLBI` instructions are not ordered with respect to prior page table updates or subsequent
+//! memory accesses. Callers are responsible for issuing the required barriers.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::asm::{
+//!     barrier,
+//!     tlb::{self, Asid, Granule, Ttl},
+//! };
+//!
+//! // A level 3 page table entry for VA 0x4000_0000 in ASID 5 was just changed.
+//! barrier::dsb(barrier::ISHST);
+//! tlb::tlbi_va(
+//!     tlb::VALE1IS,
+//!     0x4000_0000,
+//!     Asid(5),
+//!     Ttl::new(Granule::Size4KiB, 3),
+//! );
+//! barrier::dsb(barrier::ISH);
+//! barrier::isb(barrier::SY);
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{ID_AA64ISAR0_EL1, ID_AA64MMFR2_EL1, VTTBR_EL2},
+};
+use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
+
+/// Address Space Identifier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Asid(pub u16);
+
+/// Virtual Machine Identifier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Vmid(pub u16);
+
+pub use crate::paging::Granule;
+
+/// Encoding of the granule in the `TTL` and `TG` fields of TLBI operands.
+const fn tg(granule: Granule) -> u64 {
+    match granule {
+        Granule::Size4KiB => 0b01,
+        Granule::Size16KiB => 0b10,
+        Granule::Size64KiB => 0b11,
+    }
+}
+
+/// Translation Table Level hint. Indicates the level of the translation table walk that holds the
+/// leaf entry for the address being invalidated.
+///
+/// Only used if FEAT_TTL is implemented, see `ID_AA64MMFR2_EL1.TTL`. Otherwise, the hint is
+/// dropped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ttl {
+    granule: Granule,
+    level: u8,
+}
+
+impl Ttl {
+    /// Creates a hint for a leaf entry at `level` of a translation table walk using `granule`.
+    ///
+    /// Returns `None` if the hint cannot encode `level`, i.e. for levels above 3 and for level 0
+    /// with the 16KiB and 64KiB granules.
+    pub const fn new(granule: Granule, level: u8) -> Option<Self> {
+        let min = match granule {
+            Granule::Size4KiB => 0,
+            Granule::Size16KiB | Granule::Size64KiB => 1,
+        };
+
+        if level < min || level > 3 {
+            return None;
+        }
+
+        Some(Ttl { granule, level })
+    }
+
+    /// The 4-bit `TTL` field.
+    const fn bits(self) -> u64 {
+        (tg(self.granule) << 2) | self.level as u64
+    }
+}
+
+mod sealed {
+    pub trait Tlbi {
+        fn __tlbi(&self);
+    }
+
+    pub trait TlbiOperand {
+        fn __tlbi(&self, operand: u64);
+    }
+
+    pub trait ByAsid: TlbiOperand {}
+    pub trait ByVa: TlbiOperand {}
+    pub trait ByVaAllAsid: TlbiOperand {}
+    pub trait ByIpa: TlbiOperand {}
+
+    pub trait ByRange: TlbiOperand {
+        type Single: ByVa;
+        type All: ByAsid;
+
+        const SINGLE: Self::Single;
+        const ALL: Self::All;
+    }
+}
+
+// Operations added by FEAT_TLBIOS and FEAT_TLBIRANGE are emitted by their `SYS` encoding, so that
+// they assemble without the `tlb-rmi` target feature.
+macro_rules! tlbi {
+    (@insn $A:ident) => {
+        concat!("TLBI ", stringify!($A))
+    };
+    (@insn $A:ident = $sys:literal) => {
+        $sys
+    };
+    ($A:ident $(= $sys:literal)?) => {
+        impl sealed::Tlbi for $A {
+            #[inline(always)]
+            fn __tlbi(&self) {
+                match () {
+                    #[cfg(target_arch = "aarch64")]
+                    () => unsafe {
+                        core::arch::asm!(tlbi!(@insn $A $(= $sys)?), options(nostack))
+                    },
+
+                    #[cfg(not(target_arch = "aarch64"))]
+                    () => unimplemented!(),
+                }
+            }
+        }
+    };
+    (@operand $A:ident $(= $sys:literal)?) => {
+        impl sealed::TlbiOperand for $A {
+            #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+            #[inline(always)]
+            fn __tlbi(&self, operand: u64) {
+                match () {
+                    #[cfg(target_arch = "aarch64")]
+                    () => unsafe {
+                        core::arch::asm!(concat!(tlbi!(@insn $A $(= $sys)?), ", {x}"), x = in(reg) operand, options(nostack))
+                    },
+
+                    #[cfg(not(target_arch = "aarch64"))]
+                    () => unimplemented!(),
+                }
+            }
+        }
+    };
+    ($A:ident $(= $sys:literal)?, $kind:ident) => {
+        tlbi!(@operand $A $(= $sys)?);
+
+        impl sealed::$kind for $A {}
+    };
+}
+
+macro_rules! tlbi_range {
+    ($A:ident = $sys:literal, $single:ident, $all:ident) => {
+        tlbi!(@operand $A = $sys);
+
+        impl sealed::ByRange for $A {
+            type Single = $single;
+            type All = $all;
+
+            const SINGLE: $single = $single;
+            const ALL: $all = $all;
+        }
+    };
+}
+
+/// Invalidate all stage 1 EL1&0 entries of the current VMID.
+pub struct VMALLE1;
+/// Invalidate all stage 1 EL1&0 entries of the current VMID, Inner Shareable.
+pub struct VMALLE1IS;
+/// Invalidate all stage 1 EL1&0 entries of the current VMID, Outer Shareable.
+pub struct VMALLE1OS;
+/// Invalidate all stage 1 and stage 2 EL1&0 entries of the current VMID.
+pub struct VMALLS12E1;
+/// Invalidate all stage 1 and stage 2 EL1&0 entries of the current VMID, Inner Shareable.
+pub struct VMALLS12E1IS;
+/// Invalidate all stage 1 and stage 2 EL1&0 entries of the current VMID, Outer Shareable.
+pub struct VMALLS12E1OS;
+/// Invalidate all EL2 entries.
+pub struct ALLE2;
+/// Invalidate all EL2 entries, Inner Shareable.
+pub struct ALLE2IS;
+/// Invalidate all EL2 entries, Outer Shareable.
+pub struct ALLE2OS;
+/// Invalidate all EL3 entries.
+pub struct ALLE3;
+/// Invalidate all EL3 entries, Inner Shareable.
+pub struct ALLE3IS;
+/// Invalidate all EL3 entries, Outer Shareable.
+pub struct ALLE3OS;
+
+tlbi!(VMALLE1);
+tlbi!(VMALLE1IS);
+tlbi!(VMALLE1OS = "sys #0, c8, c1, #0");
+tlbi!(VMALLS12E1);
+tlbi!(VMALLS12E1IS);
+tlbi!(VMALLS12E1OS = "sys #4, c8, c1, #6");
+tlbi!(ALLE2);
+tlbi!(ALLE2IS);
+tlbi!(ALLE2OS = "sys #4, c8, c1, #0");
+tlbi!(ALLE3);
+tlbi!(ALLE3IS);
+tlbi!(ALLE3OS = "sys #6, c8, c1, #0");
+
+/// Invalidate stage 1 EL1&0 entries by ASID.
+pub struct ASIDE1;
+/// Invalidate stage 1 EL1&0 entries by ASID, Inner Shareable.
+pub struct ASIDE1IS;
+/// Invalidate stage 1 EL1&0 entries by ASID, Outer Shareable.
+pub struct ASIDE1OS;
+
+tlbi!(ASIDE1, ByAsid);
+tlbi!(ASIDE1IS, ByAsid);
+tlbi!(ASIDE1OS = "sys #0, c8, c1, #2", ByAsid);
+
+/// Invalidate stage 1 EL1&0 entries by VA and ASID.
+pub struct VAE1;
+/// Invalidate stage 1 EL1&0 entries by VA and ASID, Inner Shareable.
+pub struct VAE1IS;
+/// Invalidate stage 1 EL1&0 entries by VA and ASID, Outer Shareable.
+pub struct VAE1OS;
+/// Invalidate stage 1 EL1&0 last level entries by VA and ASID.
+pub struct VALE1;
+/// Invalidate stage 1 EL1&0 last level entries by VA and ASID, Inner Shareable.
+pub struct VALE1IS;
+/// Invalidate stage 1 EL1&0 last level entries by VA and ASID, Outer Shareable.
+pub struct VALE1OS;
+
+tlbi!(VAE1, ByVa);
+tlbi!(VAE1IS, ByVa);
+tlbi!(VAE1OS = "sys #0, c8, c1, #1", ByVa);
+tlbi!(VALE1, ByVa);
+tlbi!(VALE1IS, ByVa);
+tlbi!(VALE1OS = "sys #0, c8, c1, #5", ByVa);
+
+/// Invalidate stage 1 EL1&0 entries by VA, all ASIDs.
+pub struct VAAE1;
+/// Invalidate stage 1 EL1&0 entries by VA, all ASIDs, Inner Shareable.
+pub struct VAAE1IS;
+/// Invalidate stage 1 EL1&0 entries by VA, all ASIDs, Outer Shareable.
+pub struct VAAE1OS;
+/// Invalidate stage 1 EL1&0 last level entries by VA, all ASIDs.
+pub struct VAALE1;
+/// Invalidate stage 1 EL1&0 last level entries by VA, all ASIDs, Inner Shareable.
+pub struct VAALE1IS;
+/// Invalidate stage 1 EL1&0 last level entries by VA, all ASIDs, Outer Shareable.
+pub struct VAALE1OS;
+
+tlbi!(VAAE1, ByVaAllAsid);
+tlbi!(VAAE1IS, ByVaAllAsid);
+tlbi!(VAAE1OS = "sys #0, c8, c1, #3", ByVaAllAsid);
+tlbi!(VAALE1, ByVaAllAsid);
+tlbi!(VAALE1IS, ByVaAllAsid);
+tlbi!(VAALE1OS = "sys #0, c8, c1, #7", ByVaAllAsid);
+
+/// Invalidate stage 2 entries by IPA of the current VMID.
+pub struct IPAS2E1;
+/// Invalidate stage 2 entries by IPA of the current VMID, Inner Shareable.
+pub struct IPAS2E1IS;
+/// Invalidate stage 2 entries by IPA of the current VMID, Outer Shareable.
+pub struct IPAS2E1OS;
+/// Invalidate stage 2 last level entries by IPA of the current VMID.
+pub struct IPAS2LE1;
+/// Invalidate stage 2 last level entries by IPA of the current VMID, Inner Shareable.
+pub struct IPAS2LE1IS;
+/// Invalidate stage 2 last level entries by IPA of the current VMID, Outer Shareable.
+pub struct IPAS2LE1OS;
+
+tlbi!(IPAS2E1, ByIpa);
+tlbi!(IPAS2E1IS, ByIpa);
+tlbi!(IPAS2E1OS = "sys #4, c8, c4, #0", ByIpa);
+tlbi!(IPAS2LE1, ByIpa);
+tlbi!(IPAS2LE1IS, ByIpa);
+tlbi!(IPAS2LE1OS = "sys #4, c8, c4, #4", ByIpa);
+
+/// Invalidate stage 1 EL1&0 entries by VA range and ASID. Requires FEAT_TLBIRANGE.
+pub struct RVAE1;
+/// Invalidate stage 1 EL1&0 entries by VA range and ASID, Inner Shareable. Requires
+/// FEAT_TLBIRANGE.
+pub struct RVAE1IS;
+/// Invalidate stage 1 EL1&0 entries by VA range and ASID, Outer Shareable. Requires
+/// FEAT_TLBIRANGE.
+pub struct RVAE1OS;
+/// Invalidate stage 1 EL1&0 last level entries by VA range and ASID. Requires FEAT_TLBIRANGE.
+pub struct RVALE1;
+/// Invalidate stage 1 EL1&0 last level entries by VA range and ASID, Inner Shareable. Requires
+/// FEAT_TLBIRANGE.
+pub struct RVALE1IS;
+/// Invalidate stage 1 EL1&0 last level entries by VA range and ASID, Outer Shareable. Requires
+/// FEAT_TLBIRANGE.
+pub struct RVALE1OS;
+
+tlbi_range!(RVAE1 = "sys #0, c8, c6, #1", VAE1, ASIDE1);
+tlbi_range!(RVAE1IS = "sys #0, c8, c2, #1", VAE1IS, ASIDE1IS);
+tlbi_range!(RVAE1OS = "sys #0, c8, c5, #1", VAE1OS, ASIDE1OS);
+tlbi_range!(RVALE1 = "sys #0, c8, c6, #5", VALE1, ASIDE1);
+tlbi_range!(RVALE1IS = "sys #0, c8, c2, #5", VALE1IS, ASIDE1IS);
+tlbi_range!(RVALE1OS = "sys #0, c8, c5, #5", VALE1OS, ASIDE1OS);
+
+/// Maximum number of pages that a single range operation can invalidate.
+pub const MAX_RANGE_PAGES: usize = range_pages(3, 31);
+
+#[inline(always)]
+fn has_feature_ttl() -> bool {
+    ID_AA64MMFR2_EL1.read(ID_AA64MMFR2_EL1::TTL) != 0
+}
+
+#[inline(always)]
+fn has_feature_tlbirange() -> bool {
+    ID_AA64ISAR0_EL1.read(ID_AA64ISAR0_EL1::TLB) >= 0b0010
+}
+
+#[inline(always)]
+fn ttl_bits(ttl: Option<Ttl>) -> u64 {
+    match ttl {
+        Some(ttl) if has_feature_ttl() => ttl.bits(),
+        _ => 0,
+    }
+}
+
+const fn asid_operand(asid: Asid) -> u64 {
+    (asid.0 as u64) << 48
+}
+
+const fn va_operand(va: usize, asid: Asid, ttl: u64) -> u64 {
+    asid_operand(asid) | (ttl << 44) | ((va as u64 >> 12) & ((1 << 44) - 1))
+}
+
+const fn ipa_operand(ipa: u64, ttl: u64) -> u64 {
+    (ttl << 44) | ((ipa >> 12) & ((1 << 40) - 1))
+}
+
+const fn range_operand(
+    va: usize,
+    asid: Asid,
+    granule: Granule,
+    scale: u64,
+    num: u64,
+    level: Option<u8>,
+) -> u64 {
+    // Level 0 cannot be encoded, 0b00 means no hint.
+    let ttl = match level {
+        Some(level @ 1..=3) => level as u64,
+        _ => 0,
+    };
+
+    asid_operand(asid)
+        | (tg(granule) << 46)
+        | (scale << 44)
+        | (num << 39)
+        | (ttl << 37)
+        | ((va as u64 >> granule.shift()) & ((1 << 37) - 1))
+}
+
+/// Number of pages covered by a range operation with the given `scale` and `num`.
+const fn range_pages(scale: u64, num: u64) -> usize {
+    ((num + 1) << (5 * scale + 1)) as usize
+}
+
+/// TLB invalidate operation without operand.
+#[inline(always)]
+pub fn tlbi<A>(arg: A)
+where
+    A: sealed::Tlbi,
+{
+    arg.__tlbi()
+}
+
+/// TLB invalidate operation by ASID.
+#[inline(always)]
+pub fn tlbi_asid<A>(arg: A, asid: Asid)
+where
+    A: sealed::ByAsid,
+{
+    arg.__tlbi(asid_operand(asid))
+}
+
+/// TLB invalidate operation by VA and ASID.
+#[inline(always)]
+pub fn tlbi_va<A>(arg: A, va: usize, asid: Asid, ttl: Option<Ttl>)
+where
+    A: sealed::ByVa,
+{
+    arg.__tlbi(va_operand(va, asid, ttl_bits(ttl)))
+}
+
+/// TLB invalidate operation by VA, for all ASIDs.
+#[inline(always)]
+pub fn tlbi_vaa<A>(arg: A, va: usize, ttl: Option<Ttl>)
+where
+    A: sealed::ByVaAllAsid,
+{
+    arg.__tlbi(va_operand(va, Asid(0), ttl_bits(ttl)))
+}
+
+/// TLB invalidate operation by IPA, for the current VMID.
+///
+/// The operation applies to Non-secure IPA space.
+#[inline(always)]
+pub fn tlbi_ipa<A>(arg: A, ipa: u64, ttl: Option<Ttl>)
+where
+    A: sealed::ByIpa,
+{
+    arg.__tlbi(ipa_operand(ipa, ttl_bits(ttl)))
+}
+
+/// TLB invalidate operation for `pages` pages of size `granule` starting at `va`.
+///
+/// The range is split into as few range operations as possible. A remaining odd page is
+/// invalidated with the corresponding single page operation. If `level` is given, it is passed
+/// as the level hint of every operation that can encode it.
+///
+/// If FEAT_TLBIRANGE is not implemented, every page is invalidated with the single page
+/// operation. If more than [`MAX_RANGE_PAGES`] pages are requested, the whole ASID is invalidated
+/// instead.
+pub fn tlbi_range<A>(
+    arg: A,
+    mut va: usize,
+    asid: Asid,
+    mut pages: usize,
+    granule: Granule,
+    level: Option<u8>,
+) where
+    A: sealed::ByRange,
+{
+    use sealed::TlbiOperand;
+
+    if pages > MAX_RANGE_PAGES {
+        A::ALL.__tlbi(asid_operand(asid));
+        return;
+    }
+
+    let ttl = ttl_bits(level.and_then(|level| Ttl::new(granule, level)));
+    let singles = if !has_feature_tlbirange() {
+        pages
+    } else {
+        pages % 2
+    };
+
+    for _ in 0..singles {
+        A::SINGLE.__tlbi(va_operand(va, asid, ttl));
+        va += granule.size();
+    }
+    pages -= singles;
+
+    // Starting with the largest scale leaves an even number of pages below 64 for scale 0, which
+    // is always covered completely.
+    for scale in (0..4).rev() {
+        let num = core::cmp::min(pages, range_pages(scale, 31)) >> (5 * scale + 1);
+
+        if num > 0 {
+            let num = num as u64 - 1;
+            let covered = range_pages(scale, num);
+
+            arg.__tlbi(range_operand(va, asid, granule, scale, num, level));
+            va += covered << granule.shift();
+            pages -= covered;
+        }
+    }
+}
+
+/// Runs `f` with the VMID in `VTTBR_EL2` temporarily set to `vmid`.
+///
+/// Operations that apply to the current VMID, e.g. [`VMALLS12E1IS`] or [`IPAS2E1IS`], then act on
+/// the given VMID. Must be executed at EL2 with interrupts masked.
+pub fn with_vmid<R>(vmid: Vmid, f: impl FnOnce() -> R) -> R {
+    let saved = VTTBR_EL2.get();
+
+    VTTBR_EL2.modify(VTTBR_EL2::VMID.val(vmid.0 as u64));
+    barrier::isb(barrier::SY);
+
+    let ret = f();
+
+    VTTBR_EL2.set(saved);
+    barrier::isb(barrier::SY);
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn va_operand_encoding() {
+        let ttl = Ttl::new(Granule::Size4KiB, 3).unwrap().bits();
+        assert_eq!(
+            va_operand(0xffff_0000_4000_1000, Asid(5), ttl),
+            (5 << 48) | (0b0111 << 44) | 0xff0_0004_0001
+        );
+
+        let ttl = Ttl::new(Granule::Size64KiB, 2).unwrap().bits();
+        assert_eq!(va_operand(0x1_0000, Asid(0), ttl), (0b1110 << 44) | 0x10);
+    }
+
+    #[test]
+    fn ttl_levels() {
+        assert!(Ttl::new(Granule::Size4KiB, 0).is_some());
+        assert!(Ttl::new(Granule::Size16KiB, 0).is_none());
+        assert!(Ttl::new(Granule::Size64KiB, 1).is_some());
+        assert!(Ttl::new(Granule::Size4KiB, 4).is_none());
+    }
+
+    #[test]
+    fn ipa_operand_encoding() {
+        assert_eq!(ipa_operand(0x8_4020_3000, 0), 0x84_0203);
+    }
+
+    #[test]
+    fn range_operand_encoding() {
+        // 64 pages of 16KiB at 0x8000_0000: scale 1, num 0.
+        assert_eq!(range_pages(1, 0), 64);
+        assert_eq!(
+            range_operand(0x8000_0000, Asid(1), Granule::Size16KiB, 1, 0, Some(3)),
+            (1 << 48) | (0b10 << 46) | (1 << 44) | (0b11 << 37) | (0x8000_0000 >> 14)
+        );
+
+        // Levels without a hint encoding.
+        for level in [None, Some(0), Some(4)] {
+            assert_eq!(
+                range_operand(0, Asid(0), Granule::Size4KiB, 0, 0, level),
+                0b01 << 46
+            );
+        }
+
+        assert_eq!(MAX_RANGE_PAGES, 32 << 16);
+    }
+}
@@ -0,0 +1,412 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Decodes register values on the host.
+//!
+//! Prints the fields of a register value, as defined by this crate, and the exception syndrome of
+//! `ESR_ELx` values:
+//!
+//! ```text
+//! $ aarch64-decode esr_el2 0x96000045
+//! $ aarch64-decode --json spsr_el1 0x3c5
+//! ```
+//!
+//! Built with the `decoder` feature. The tool requires `std`, builds for bare-metal targets produce
+//! an empty binary.
+
+#![cfg_attr(target_os = "none", no_std, no_main)]
+
+#[cfg(not(target_os = "none"))]
+fn main() {
+    decoder::main()
+}
+
+#[cfg(target_os = "none")]
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+#[cfg(not(target_os = "none"))]
+mod decoder {
+    use aarch64_cpu::registers::{
+        esr::{ErrorType, FaultStatus, SErrorSyndrome, Syndrome},
+        metadata::{self, FieldInfo, RegisterInfo},
+    };
+    use std::{
+        env,
+        fmt::{Debug, Display, Write},
+        process,
+    };
+
+    const USAGE: &str = "usage: aarch64-decode [--json] <REGISTER> <VALUE>";
+
+    /// Parses a hexadecimal value, with or without `0x` prefix and `_` separators.
+    fn parse_value(s: &str) -> Option<u64> {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s)
+            .replace('_', "");
+
+        u64::from_str_radix(&digits, 16).ok()
+    }
+
+    /// Returns the bit range of a field, e.g. `[31:26]`.
+    fn bits(field: &FieldInfo) -> String {
+        if field.width == 1 {
+            format!("[{}]", field.offset)
+        } else {
+            format!("[{}:{}]", field.offset + field.width - 1, field.offset)
+        }
+    }
+
+    /// Returns the decoded syndrome, if `info` is an exception syndrome register.
+    fn syndrome(info: &RegisterInfo, value: u64) -> Option<Syndrome> {
+        info.name
+            .starts_with("ESR_EL")
+            .then(|| Syndrome::from_esr(value))
+    }
+
+    /// Returns the register accessed by a trapped `MRS` or `MSR` instruction.
+    fn trapped_register(syndrome: &Syndrome) -> Option<&'static str> {
+        match syndrome {
+            Syndrome::TrappedMsrMrs(msr_mrs) => msr_mrs.register().map(|info| info.name),
+            _ => None,
+        }
+    }
+
+    fn text(info: &RegisterInfo, value: u64) -> String {
+        let mut out = String::new();
+        let name_width = info.fields.iter().map(|f| f.name.len()).max().unwrap_or(0);
+
+        writeln!(out, "{} = {:#x}", info.name, value).unwrap();
+        if info.fields.is_empty() {
+            writeln!(out, "  (no fields defined)").unwrap();
+        }
+
+        for field in info.fields {
+            let field_value = field.read(value);
+
+            write!(
+                out,
+                "  {:name_width$} {:8} {:#x}",
+                field.name,
+                bits(field),
+                field_value,
+            )
+            .unwrap();
+            if let Some(name) = field.value_name(field_value) {
+                write!(out, " {}", name).unwrap();
+            }
+            out.push('\n');
+        }
+
+        if let Some(syndrome) = syndrome(info, value) {
+            writeln!(out, "syndrome: {:#?}", syndrome).unwrap();
+            if let Some(name) = trapped_register(&syndrome) {
+                writeln!(out, "register: {}", name).unwrap();
+            }
+        }
+
+        out
+    }
+
+    /// Returns `s` as a JSON string.
+    fn json_string(s: &str) -> String {
+        let mut out = String::from("\"");
+
+        for c in s.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+
+        out
+    }
+
+    /// Members of a JSON object, written by [`Object::finish`].
+    #[derive(Default)]
+    struct Object(String);
+
+    impl Object {
+        /// Adds the member `key` with `value`, which is already JSON.
+        fn raw(mut self, key: &str, value: impl Display) -> Self {
+            if !self.0.is_empty() {
+                self.0.push(',');
+            }
+            write!(self.0, "{}:{}", json_string(key), value).unwrap();
+
+            self
+        }
+
+        /// Adds the member `key` with the string `value`.
+        fn string(self, key: &str, value: &str) -> Self {
+            self.raw(key, json_string(value))
+        }
+
+        /// Adds the member `key` with `value`, or `null`.
+        fn option(self, key: &str, value: Option<impl Display>) -> Self {
+            match value {
+                Some(value) => self.raw(key, value),
+                None => self.raw(key, "null"),
+            }
+        }
+
+        fn finish(self) -> String {
+            format!("{{{}}}", self.0)
+        }
+    }
+
+    /// Returns the name of the enum variant `value`.
+    fn variant(value: &impl Debug) -> String {
+        let name = format!("{:?}", value);
+
+        match name.find(|c: char| !c.is_alphanumeric()) {
+            Some(end) => name[..end].to_string(),
+            None => name,
+        }
+    }
+
+    fn json_fault_status(status: &FaultStatus) -> String {
+        let object = Object::default().string("type", &variant(status));
+
+        match *status {
+            FaultStatus::AddressSize { level }
+            | FaultStatus::Translation { level }
+            | FaultStatus::AccessFlag { level }
+            | FaultStatus::Permission { level }
+            | FaultStatus::SyncExternalOnWalk { level }
+            | FaultStatus::SyncParityOnWalk { level }
+            | FaultStatus::GranuleProtectionOnWalk { level } => object.raw("level", level),
+            FaultStatus::Other(code) => object.raw("code", code),
+            _ => object,
+        }
+        .finish()
+    }
+
+    /// Returns the syndrome as a JSON object, with the Exception Class as `class` and the decoded
+    /// ISS fields as further members.
+    fn json_syndrome(syndrome: &Syndrome) -> String {
+        let object = Object::default();
+
+        let object = match syndrome {
+            Syndrome::Other(class, iss) => {
+                return object
+                    .string("class", &variant(class))
+                    .raw("iss", iss)
+                    .finish()
+            }
+            Syndrome::Reserved(ec, iss) => {
+                return object
+                    .string("class", "Reserved")
+                    .raw("ec", ec)
+                    .raw("iss", iss)
+                    .finish()
+            }
+            _ => object.string("class", &variant(syndrome)),
+        };
+
+        match syndrome {
+            Syndrome::SVC32(imm16)
+            | Syndrome::HVC32(imm16)
+            | Syndrome::SVC64(imm16)
+            | Syndrome::HVC64(imm16)
+            | Syndrome::SMC64(imm16)
+            | Syndrome::Bkpt32(imm16)
+            | Syndrome::Brk64(imm16) => object.raw("imm16", imm16),
+            Syndrome::TrappedWFIorWFE(wfx) => object
+                .string("ti", &variant(&wfx.ti))
+                .option("cond", wfx.cond)
+                .option("rn", wfx.rn),
+            Syndrome::TrappedMsrMrs(msr_mrs) => object
+                .raw("op0", msr_mrs.op0)
+                .raw("op1", msr_mrs.op1)
+                .raw("crn", msr_mrs.crn)
+                .raw("crm", msr_mrs.crm)
+                .raw("op2", msr_mrs.op2)
+                .raw("rt", msr_mrs.rt)
+                .string("direction", &variant(&msr_mrs.direction)),
+            Syndrome::InstrAbortLowerEL(abort) | Syndrome::InstrAbortCurrentEL(abort) => object
+                .raw("set", abort.set)
+                .raw("fnv", abort.fnv)
+                .raw("ea", abort.ea)
+                .raw("s1ptw", abort.s1ptw)
+                .raw("ifsc", json_fault_status(&abort.ifsc)),
+            Syndrome::DataAbortLowerEL(abort) | Syndrome::DataAbortCurrentEL(abort) => object
+                .option(
+                    "isv",
+                    abort.isv.map(|instr| {
+                        Object::default()
+                            .raw("sas", instr.sas.bytes())
+                            .raw("sse", instr.sse)
+                            .raw("srt", instr.srt)
+                            .raw("sf", instr.sf)
+                            .raw("ar", instr.ar)
+                            .finish()
+                    }),
+                )
+                .raw("vncr", abort.vncr)
+                .raw("set", abort.set)
+                .raw("fnv", abort.fnv)
+                .raw("ea", abort.ea)
+                .raw("cm", abort.cm)
+                .raw("s1ptw", abort.s1ptw)
+                .raw("wnr", abort.wnr)
+                .raw("dfsc", json_fault_status(&abort.dfsc)),
+            Syndrome::TrappedFP32(fp) | Syndrome::TrappedFP64(fp) => object
+                .raw("tfv", fp.tfv)
+                .raw("idf", fp.idf)
+                .raw("ixf", fp.ixf)
+                .raw("uff", fp.uff)
+                .raw("off", fp.off)
+                .raw("dzf", fp.dzf)
+                .raw("iof", fp.iof),
+            Syndrome::SError(SErrorSyndrome::ImplDefined(iss)) => {
+                object.string("type", "ImplDefined").raw("iss", iss)
+            }
+            Syndrome::SError(SErrorSyndrome::Architected {
+                iesb,
+                aet,
+                ea,
+                dfsc,
+            }) => {
+                let object = object.string("type", "Architected").raw("iesb", iesb);
+                let object = match aet {
+                    ErrorType::Reserved(aet) => object.raw("aet", aet),
+                    aet => object.string("aet", &variant(aet)),
+                };
+
+                object.raw("ea", ea).raw("dfsc", dfsc)
+            }
+            Syndrome::BreakpointLowerEL(ifsc) | Syndrome::BreakpointCurrentEL(ifsc) => {
+                object.raw("ifsc", json_fault_status(ifsc))
+            }
+            Syndrome::SoftwareStepLowerEL(step) | Syndrome::SoftwareStepCurrentEL(step) => object
+                .option("ex", step.ex)
+                .raw("ifsc", json_fault_status(&step.ifsc)),
+            Syndrome::WatchpointLowerEL(watchpoint) | Syndrome::WatchpointCurrentEL(watchpoint) => {
+                object
+                    .raw("vncr", watchpoint.vncr)
+                    .raw("fnv", watchpoint.fnv)
+                    .raw("cm", watchpoint.cm)
+                    .raw("wnr", watchpoint.wnr)
+                    .raw("dfsc", json_fault_status(&watchpoint.dfsc))
+            }
+            _ => object,
+        }
+        .finish()
+    }
+
+    fn json(info: &RegisterInfo, value: u64) -> String {
+        let mut out = String::new();
+
+        write!(
+            out,
+            "{{\"register\":{},\"value\":\"{:#x}\",\"fields\":[",
+            json_string(info.name),
+            value
+        )
+        .unwrap();
+
+        for (i, field) in info.fields.iter().enumerate() {
+            let field_value = field.read(value);
+
+            if i > 0 {
+                out.push(',');
+            }
+            write!(
+                out,
+                "{{\"name\":{},\"offset\":{},\"width\":{},\"value\":{},\"meaning\":{}}}",
+                json_string(field.name),
+                field.offset,
+                field.width,
+                field_value,
+                field
+                    .value_name(field_value)
+                    .map_or_else(|| String::from("null"), json_string),
+            )
+            .unwrap();
+        }
+        out.push(']');
+
+        if let Some(syndrome) = syndrome(info, value) {
+            write!(out, ",\"syndrome\":{}", json_syndrome(&syndrome)).unwrap();
+            if let Some(name) = trapped_register(&syndrome) {
+                write!(out, ",\"trapped_register\":{}", json_string(name)).unwrap();
+            }
+        }
+        out.push('}');
+
+        out
+    }
+
+    pub fn main() {
+        let mut args: Vec<String> = env::args().skip(1).collect();
+        let json_output = args.iter().any(|arg| arg == "--json");
+        args.retain(|arg| arg != "--json");
+
+        let (name, value) = match args.as_slice() {
+            [name, value] => (name, value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        };
+
+        let info = metadata::by_name(name).unwrap_or_else(|| {
+            eprintln!("unknown register: {}", name);
+            process::exit(1);
+        });
+        let value = parse_value(value).unwrap_or_else(|| {
+            eprintln!("invalid hexadecimal value: {}", value);
+            process::exit(1);
+        });
+
+        if json_output {
+            println!("{}", json(info, value));
+        } else {
+            print!("{}", text(info, value));
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn values() {
+            assert_eq!(parse_value("0x9600_0045"), Some(0x9600_0045));
+            assert_eq!(parse_value("3C5"), Some(0x3c5));
+            assert_eq!(parse_value("0xg"), None);
+        }
+
+        #[test]
+        fn output() {
+            let esr = metadata::by_name("esr_el2").unwrap();
+
+            let text = text(esr, 0x9600_0045);
+            assert!(text.contains("  EC   [31:26]  0x25 DataAbortCurrentEL\n"));
+            assert!(text.contains("syndrome: DataAbortCurrentEL("));
+
+            let json = json(esr, 0x9600_0045);
+            assert!(json.starts_with("{\"register\":\"ESR_EL2\",\"value\":\"0x96000045\","));
+            assert!(json.contains("{\"name\":\"EC\",\"offset\":26,\"width\":6,\"value\":37,"));
+            assert!(json.ends_with(
+                "\"syndrome\":{\"class\":\"DataAbortCurrentEL\",\"isv\":null,\"vncr\":false,\
+                 \"set\":0,\"fnv\":false,\"ea\":false,\"cm\":false,\"s1ptw\":false,\"wnr\":true,\
+                 \"dfsc\":{\"type\":\"Translation\",\"level\":1}}}"
+            ));
+            assert_eq!(json_string("a\"b\\"), "\"a\\\"b\\\\\"");
+        }
+    }
+}
@@ -0,0 +1,330 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Exception vector tables.
+//!
+//! The [`exception_vectors!`](crate::exception_vectors) macro emits a 2KiB aligned vector table
+//! with the 16 architectural entries. Each entry saves the general purpose registers and the
+//! exception state of the target Exception level in a [`TrapFrame`] on the current stack, calls
+//! the Rust handler for the kind of exception, restores the (possibly modified) frame and returns
+//! with `ERET`.
+//!
+//! Handlers have the signature `extern "C" fn(&mut TrapFrame, ExceptionSource)`.
+//!
+//! # SIMD and floating-point state
+//!
+//! The vector entries do not save the SIMD&FP registers `q0` to `q31`, `FPCR` and `FPSR`, so
+//! handlers must not modify them. Code built for a target without SIMD&FP, e.g.
+//! `aarch64-unknown-none-softfloat`, does not use them. On other targets, the compiler is free to
+//! use them in any function, so handlers have to save and restore the state themselves, e.g. in an
+//! assembly wrapper around the Rust handler.
+//!
+//! # Example
+//!
+//! ```
+//! use aarch64_cpu::exception::{self, ExceptionSource, TrapFrame};
+//!
+//! extern "C" fn sync(frame: &mut TrapFrame, _source: ExceptionSource) {
+//!     // Skip the trapping instruction.
+//!     frame.elr += 4;
+//! }
+//!
+//! extern "C" fn irq(_frame: &mut TrapFrame, _source: ExceptionSource) {}
+//!
+//! extern "C" fn unhandled(frame: &mut TrapFrame, source: ExceptionSource) {
+//!     panic!("unhandled exception from {:?}: {:x?}", source, frame);
+//! }
+//!
+//! aarch64_cpu::exception_vectors!(fn vectors, el = 1, sync = sync, irq = irq, fiq = unhandled,
+//!     serror = unhandled);
+//!
+//! # fn install() {
+//! exception::install(vectors());
+//! # }
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{CurrentEL, VBAR_EL1, VBAR_EL2, VBAR_EL3},
+};
+use tock_registers::interfaces::{Readable, Writeable};
+
+/// Register state saved on exception entry.
+///
+/// Holds the general purpose registers only, the SIMD&FP registers are not saved.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TrapFrame {
+    /// General purpose registers `x0` to `x30`.
+    pub x: [u64; 31],
+    /// Exception Link Register of the target Exception level. Restored on return.
+    pub elr: u64,
+    /// Saved Program Status Register of the target Exception level. Restored on return.
+    pub spsr: u64,
+    /// Exception Syndrome Register of the target Exception level.
+    pub esr: u64,
+    /// Fault Address Register of the target Exception level.
+    pub far: u64,
+}
+
+// The vector entries reserve 288 bytes of stack for the frame, keeping SP 16 byte aligned.
+const _: () = assert!(core::mem::size_of::<TrapFrame>() == 280);
+
+/// Origin of an exception, selecting one of the four groups of entries of a vector table.
+#[repr(u64)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExceptionSource {
+    /// Current Exception level, using `SP_EL0`.
+    CurrentElSp0 = 0,
+    /// Current Exception level, using `SP_ELx`.
+    CurrentElSpx = 1,
+    /// Lower Exception level, where the next lower level is using AArch64.
+    LowerElAArch64 = 2,
+    /// Lower Exception level, where the next lower level is using AArch32.
+    LowerElAArch32 = 3,
+}
+
+/// Kind of an exception, selecting an entry within a group of a vector table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExceptionKind {
+    Synchronous,
+    Irq,
+    Fiq,
+    SError,
+}
+
+impl ExceptionKind {
+    /// Returns the kind and source of the vector table entry at `offset` from the vector base
+    /// address.
+    pub const fn from_vector_offset(offset: usize) -> Option<(ExceptionKind, ExceptionSource)> {
+        if offset >= 0x800 || !offset.is_multiple_of(0x80) {
+            return None;
+        }
+
+        let kind = match (offset >> 7) & 0b11 {
+            0 => ExceptionKind::Synchronous,
+            1 => ExceptionKind::Irq,
+            2 => ExceptionKind::Fiq,
+            _ => ExceptionKind::SError,
+        };
+        let source = match offset >> 9 {
+            0 => ExceptionSource::CurrentElSp0,
+            1 => ExceptionSource::CurrentElSpx,
+            2 => ExceptionSource::LowerElAArch64,
+            _ => ExceptionSource::LowerElAArch32,
+        };
+
+        Some((kind, source))
+    }
+}
+
+/// A vector table emitted by [`exception_vectors!`](crate::exception_vectors).
+#[repr(C, align(2048))]
+pub struct VectorTable([u32; 512]);
+
+/// Writes the address of `table` to the `VBAR_ELx` of the current Exception level.
+///
+/// Panics when executed at EL0.
+pub fn install(table: &'static VectorTable) {
+    let addr = table as *const VectorTable as u64;
+
+    match CurrentEL.read_as_enum(CurrentEL::EL) {
+        Some(CurrentEL::EL::Value::EL1) => VBAR_EL1.set(addr),
+        Some(CurrentEL::EL::Value::EL2) => VBAR_EL2.set(addr),
+        Some(CurrentEL::EL::Value::EL3) => VBAR_EL3.set(addr),
+        _ => panic!("cannot install a vector table at EL0"),
+    }
+    barrier::isb(barrier::SY);
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! __exception_vector_entry {
+    ($symbol:expr, $kind:literal, $source:literal) => {
+        concat!(
+            ".balign 0x80\n",
+            "sub sp, sp, #288\n",
+            "stp x0, x1, [sp, #0]\n",
+            "stp x2, x3, [sp, #16]\n",
+            "stp x4, x5, [sp, #32]\n",
+            "stp x6, x7, [sp, #48]\n",
+            "stp x8, x9, [sp, #64]\n",
+            "stp x10, x11, [sp, #80]\n",
+            "stp x12, x13, [sp, #96]\n",
+            "stp x14, x15, [sp, #112]\n",
+            "stp x16, x17, [sp, #128]\n",
+            "stp x18, x19, [sp, #144]\n",
+            "stp x20, x21, [sp, #160]\n",
+            "stp x22, x23, [sp, #176]\n",
+            "stp x24, x25, [sp, #192]\n",
+            "stp x26, x27, [sp, #208]\n",
+            "stp x28, x29, [sp, #224]\n",
+            "str x30, [sp, #240]\n",
+            "mov x1, #", $source, "\n",
+            "b ", $symbol, "_", $kind, "\n",
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! __exception_vector_dispatch {
+    ($symbol:expr, $kind:literal, $el:literal) => {
+        concat!(
+            $symbol, "_", $kind, ":\n",
+            "mrs x9, ELR_EL", $el, "\n",
+            "mrs x10, SPSR_EL", $el, "\n",
+            "stp x9, x10, [sp, #248]\n",
+            "mrs x9, ESR_EL", $el, "\n",
+            "mrs x10, FAR_EL", $el, "\n",
+            "stp x9, x10, [sp, #264]\n",
+            "mov x0, sp\n",
+            "bl {", $kind, "}\n",
+            "ldp x9, x10, [sp, #248]\n",
+            "msr ELR_EL", $el, ", x9\n",
+            "msr SPSR_EL", $el, ", x10\n",
+            "ldp x0, x1, [sp, #0]\n",
+            "ldp x2, x3, [sp, #16]\n",
+            "ldp x4, x5, [sp, #32]\n",
+            "ldp x6, x7, [sp, #48]\n",
+            "ldp x8, x9, [sp, #64]\n",
+            "ldp x10, x11, [sp, #80]\n",
+            "ldp x12, x13, [sp, #96]\n",
+            "ldp x14, x15, [sp, #112]\n",
+            "ldp x16, x17, [sp, #128]\n",
+            "ldp x18, x19, [sp, #144]\n",
+            "ldp x20, x21, [sp, #160]\n",
+            "ldp x22, x23, [sp, #176]\n",
+            "ldp x24, x25, [sp, #192]\n",
+            "ldp x26, x27, [sp, #208]\n",
+            "ldp x28, x29, [sp, #224]\n",
+            "ldr x30, [sp, #240]\n",
+            "add sp, sp, #288\n",
+            "eret\n",
+        )
+    };
+}
+
+/// Defines an exception vector table.
+///
+/// `fn $name` becomes a function returning the table, which can be passed to
+/// [`exception::install`](crate::exception::install). `el` is the Exception level (1, 2 or 3)
+/// whose `ELR`, `SPSR`, `ESR` and `FAR` registers are saved in the [`TrapFrame`]. The handlers
+/// for the four kinds of exceptions are shared by all four sources.
+///
+/// The SIMD&FP registers are not saved, see the [module documentation](crate::exception) for the
+/// resulting requirements on handlers.
+///
+/// [`TrapFrame`]: crate::exception::TrapFrame
+#[macro_export]
+macro_rules! exception_vectors {
+    (
+        $vis:vis fn $name:ident,
+        el = $el:literal,
+        sync = $sync:path,
+        irq = $irq:path,
+        fiq = $fiq:path,
+        serror = $serror:path $(,)?
+    ) => {
+        const _: [extern "C" fn(
+            &mut $crate::exception::TrapFrame,
+            $crate::exception::ExceptionSource,
+        ); 4] = [$sync, $irq, $fiq, $serror];
+
+        #[cfg(target_arch = "aarch64")]
+        core::arch::global_asm!(
+            ".pushsection .text.exception_vectors, \"ax\"",
+            ".balign 0x800",
+            concat!(".global __aarch64_cpu_", stringify!($name)),
+            concat!("__aarch64_cpu_", stringify!($name), ":"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "sync", "0"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "irq", "0"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "fiq", "0"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "serror", "0"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "sync", "1"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "irq", "1"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "fiq", "1"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "serror", "1"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "sync", "2"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "irq", "2"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "fiq", "2"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "serror", "2"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "sync", "3"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "irq", "3"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "fiq", "3"),
+            $crate::__exception_vector_entry!(concat!("__aarch64_cpu_", stringify!($name)), "serror", "3"),
+            ".balign 0x80",
+            $crate::__exception_vector_dispatch!(concat!("__aarch64_cpu_", stringify!($name)), "sync", $el),
+            $crate::__exception_vector_dispatch!(concat!("__aarch64_cpu_", stringify!($name)), "irq", $el),
+            $crate::__exception_vector_dispatch!(concat!("__aarch64_cpu_", stringify!($name)), "fiq", $el),
+            $crate::__exception_vector_dispatch!(concat!("__aarch64_cpu_", stringify!($name)), "serror", $el),
+            ".popsection",
+            sync = sym $sync,
+            irq = sym $irq,
+            fiq = sym $fiq,
+            serror = sym $serror,
+        );
+
+        $vis fn $name() -> &'static $crate::exception::VectorTable {
+            match () {
+                #[cfg(target_arch = "aarch64")]
+                () => {
+                    extern "C" {
+                        #[link_name = concat!("__aarch64_cpu_", stringify!($name))]
+                        static VECTORS: $crate::exception::VectorTable;
+                    }
+
+                    unsafe { &VECTORS }
+                }
+
+                #[cfg(not(target_arch = "aarch64"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern "C" fn handler(frame: &mut TrapFrame, _source: ExceptionSource) {
+        frame.elr += 4;
+    }
+
+    crate::exception_vectors!(fn vectors, el = 2, sync = handler, irq = handler, fiq = handler,
+        serror = handler);
+
+    #[test]
+    fn frame_layout() {
+        assert_eq!(core::mem::size_of::<TrapFrame>(), 35 * 8);
+        assert_eq!(core::mem::offset_of!(TrapFrame, elr), 248);
+        assert_eq!(core::mem::offset_of!(TrapFrame, esr), 264);
+        assert_eq!(core::mem::align_of::<VectorTable>(), 0x800);
+
+        let mut frame = TrapFrame::default();
+        handler(&mut frame, ExceptionSource::CurrentElSpx);
+        assert_eq!(frame.elr, 4);
+        let _ = vectors as fn() -> &'static VectorTable;
+    }
+
+    #[test]
+    fn vector_offsets() {
+        assert_eq!(
+            ExceptionKind::from_vector_offset(0x280),
+            Some((ExceptionKind::Irq, ExceptionSource::CurrentElSpx))
+        );
+        assert_eq!(
+            ExceptionKind::from_vector_offset(0x780),
+            Some((ExceptionKind::SError, ExceptionSource::LowerElAArch32))
+        );
+        assert_eq!(ExceptionKind::from_vector_offset(0x410), None);
+        assert_eq!(ExceptionKind::from_vector_offset(0x800), None);
+    }
+}
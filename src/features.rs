@@ -0,0 +1,591 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! CPU feature detection.
+//!
+//! [`CpuFeatures`] is a snapshot of the AArch64 ID registers that answers queries for
+//! architectural features, named after their `FEAT_*` identifiers. ID register fields are
+//! versioned: a feature is reported as implemented if its field holds at least the value that
+//! introduced it, so later versions of a feature imply the earlier ones.
+//!
+//! # Example
+//!
+//! ```
+//! use aarch64_cpu::features::{CpuFeatures, Feature};
+//!
+//! // On hardware: `let features = CpuFeatures::read();`
+//! let features = CpuFeatures {
+//!     isar0: 0x0000_0000_0020_0000,
+//!     ..Default::default()
+//! };
+//!
+//! assert!(features.has(Feature::LSE));
+//! assert!(!features.has(Feature::LSE128));
+//! ```
+
+use crate::registers::{
+    ID_AA64DFR0_EL1, ID_AA64DFR1_EL1, ID_AA64ISAR0_EL1, ID_AA64ISAR1_EL1, ID_AA64ISAR2_EL1,
+    ID_AA64MMFR0_EL1, ID_AA64MMFR1_EL1, ID_AA64MMFR2_EL1, ID_AA64PFR0_EL1, ID_AA64PFR1_EL1,
+    ID_AA64SMFR0_EL1, ID_AA64ZFR0_EL1,
+};
+use tock_registers::{fields::Field, interfaces::Readable, RegisterLongName};
+
+/// Architectural features that can be queried with [`CpuFeatures::has`].
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Feature {
+    // ID_AA64ISAR0_EL1
+    AES,
+    PMULL,
+    SHA1,
+    SHA256,
+    SHA512,
+    SHA3,
+    SM3,
+    SM4,
+    CRC32,
+    LSE,
+    LSE128,
+    TME,
+    RDM,
+    DotProd,
+    FHM,
+    FlagM,
+    FlagM2,
+    TLBIOS,
+    TLBIRANGE,
+    RNG,
+
+    // ID_AA64ISAR1_EL1
+    DPB,
+    DPB2,
+    JSCVT,
+    FCMA,
+    LRCPC,
+    LRCPC2,
+    LRCPC3,
+    FRINTTS,
+    SB,
+    SPECRES,
+    BF16,
+    EBF16,
+    DGH,
+    I8MM,
+    XS,
+    LS64,
+    LS64_V,
+    LS64_ACCDATA,
+    /// Address or generic authentication with any of the QARMA5, QARMA3 or IMPLEMENTATION
+    /// DEFINED algorithms.
+    PAuth,
+    PAuth2,
+    FPAC,
+
+    // ID_AA64ISAR2_EL1
+    WFxT,
+    RPRES,
+    MOPS,
+    HBC,
+    CLRBHB,
+    CSSC,
+    RPRFM,
+
+    // ID_AA64PFR0_EL1
+    FP,
+    FP16,
+    AdvSIMD,
+    SVE,
+    RAS,
+    RASv1p1,
+    GICv3,
+    GICv4p1,
+    SEL2,
+    MPAM,
+    AMUv1,
+    AMUv1p1,
+    DIT,
+    RME,
+    CSV2,
+    CSV3,
+
+    // ID_AA64PFR1_EL1
+    BTI,
+    SSBS,
+    SSBS2,
+    MTE,
+    MTE2,
+    MTE3,
+    SME,
+    SME2,
+    NMI,
+    GCS,
+
+    // ID_AA64MMFR0_EL1
+    LPA,
+    LPA2,
+    ExS,
+    FGT,
+    FGT2,
+    ECV,
+    ECV_POFF,
+
+    // ID_AA64MMFR1_EL1
+    HAFDBS,
+    VMID16,
+    VHE,
+    HPDS,
+    HPDS2,
+    LOR,
+    PAN,
+    PAN2,
+    PAN3,
+    XNX,
+    TWED,
+    ETS2,
+    HCX,
+    AFP,
+    nTLBPA,
+    TIDCP1,
+    CMOW,
+
+    // ID_AA64MMFR2_EL1
+    TTCNP,
+    UAO,
+    LSMAOC,
+    IESB,
+    LVA,
+    CCIDX,
+    NV,
+    NV2,
+    TTST,
+    LSE2,
+    IDST,
+    S2FWB,
+    TTL,
+    BBM,
+    EVT,
+    E0PD,
+
+    // ID_AA64DFR0_EL1
+    PMUv3,
+    SPE,
+    TRBE,
+    MTPMU,
+    BRBE,
+
+    // ID_AA64ZFR0_EL1
+    SVE2,
+    SVE2p1,
+    SVE_AES,
+    SVE_PMULL128,
+    SVE_BitPerm,
+    SVE_SHA3,
+    SVE_SM4,
+    F32MM,
+    F64MM,
+
+    // ID_AA64SMFR0_EL1
+    SME_FA64,
+    SME_F64F64,
+    SME_I16I64,
+}
+
+/// A snapshot of the AArch64 ID registers.
+///
+/// The fields hold the raw register values. They can be filled in by hand, e.g. to describe a
+/// specific CPU in host-side tests.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CpuFeatures {
+    pub pfr0: u64,
+    pub pfr1: u64,
+    pub isar0: u64,
+    pub isar1: u64,
+    pub isar2: u64,
+    pub mmfr0: u64,
+    pub mmfr1: u64,
+    pub mmfr2: u64,
+    pub dfr0: u64,
+    pub dfr1: u64,
+    pub zfr0: u64,
+    pub smfr0: u64,
+}
+
+/// Whether the unsigned ID register `field` of `value` is at least `min`.
+#[inline(always)]
+fn at_least<R: RegisterLongName>(value: u64, field: Field<u64, R>, min: u64) -> bool {
+    field.read(value) >= min
+}
+
+/// Whether the signed ID register `field` of `value` is at least `min`.
+#[inline(always)]
+fn at_least_signed<R: RegisterLongName>(value: u64, field: Field<u64, R>, min: i64) -> bool {
+    let v = field.read(value) as i64;
+    let v = if v & 0b1000 != 0 { v - 16 } else { v };
+
+    v >= min
+}
+
+// ID registers, as bits of the mask passed to `CpuFeatures::read_registers`.
+const PFR0: u16 = 1 << 0;
+const PFR1: u16 = 1 << 1;
+const ISAR0: u16 = 1 << 2;
+const ISAR1: u16 = 1 << 3;
+const ISAR2: u16 = 1 << 4;
+const MMFR0: u16 = 1 << 5;
+const MMFR1: u16 = 1 << 6;
+const MMFR2: u16 = 1 << 7;
+const DFR0: u16 = 1 << 8;
+const DFR1: u16 = 1 << 9;
+const ZFR0: u16 = 1 << 10;
+const SMFR0: u16 = 1 << 11;
+const ALL: u16 = (1 << 12) - 1;
+
+/// Reads `register` if it is selected by `mask`, else returns 0.
+macro_rules! read_if {
+    ($mask:expr, $bit:expr, $register:expr) => {
+        if $mask & $bit != 0 {
+            $register.get()
+        } else {
+            0
+        }
+    };
+}
+
+impl Feature {
+    /// Returns the ID registers, as a mask, whose fields report the feature.
+    fn id_registers(self) -> u16 {
+        use Feature::*;
+
+        match self {
+            AES | PMULL | SHA1 | SHA256 | SHA512 | SHA3 | SM3 | SM4 | CRC32 | LSE | LSE128
+            | TME | RDM | DotProd | FHM | FlagM | FlagM2 | TLBIOS | TLBIRANGE | RNG => ISAR0,
+
+            DPB | DPB2 | JSCVT | FCMA | LRCPC | LRCPC2 | LRCPC3 | FRINTTS | SB | SPECRES | BF16
+            | EBF16 | DGH | I8MM | XS | LS64 | LS64_V | LS64_ACCDATA => ISAR1,
+            PAuth | PAuth2 | FPAC => ISAR1 | ISAR2,
+
+            WFxT | RPRES | MOPS | HBC | CLRBHB | CSSC | RPRFM => ISAR2,
+
+            FP | FP16 | AdvSIMD | SVE | RAS | RASv1p1 | GICv3 | GICv4p1 | SEL2 | MPAM | AMUv1
+            | AMUv1p1 | DIT | RME | CSV2 | CSV3 => PFR0,
+
+            BTI | SSBS | SSBS2 | MTE | MTE2 | MTE3 | SME | SME2 | NMI | GCS => PFR1,
+
+            LPA | LPA2 | ExS | FGT | FGT2 | ECV | ECV_POFF => MMFR0,
+
+            HAFDBS | VMID16 | VHE | HPDS | HPDS2 | LOR | PAN | PAN2 | PAN3 | XNX | TWED | ETS2
+            | HCX | AFP | nTLBPA | TIDCP1 | CMOW => MMFR1,
+
+            TTCNP | UAO | LSMAOC | IESB | LVA | CCIDX | NV | NV2 | TTST | LSE2 | IDST | S2FWB
+            | TTL | BBM | EVT | E0PD => MMFR2,
+
+            PMUv3 | SPE | TRBE | MTPMU | BRBE => DFR0,
+
+            SVE2 | SVE2p1 | SVE_AES | SVE_PMULL128 | SVE_BitPerm | SVE_SHA3 | SVE_SM4 | F32MM
+            | F64MM => PFR0 | ZFR0,
+
+            SME_FA64 | SME_F64F64 | SME_I16I64 => PFR1 | SMFR0,
+        }
+    }
+
+    /// Whether the feature is implemented by the executing PE.
+    ///
+    /// Unlike [`CpuFeatures::read`], this only reads the ID registers reporting the feature,
+    /// usually a single one.
+    #[inline(always)]
+    pub fn is_present_on_current_cpu(self) -> bool {
+        CpuFeatures::read_registers(self.id_registers()).has(self)
+    }
+}
+
+impl CpuFeatures {
+    /// Reads the ID registers of the executing PE.
+    pub fn read() -> Self {
+        Self::read_registers(ALL)
+    }
+
+    /// Reads the ID registers selected by `mask`, leaving the other fields zero.
+    #[inline(always)]
+    fn read_registers(mask: u16) -> Self {
+        CpuFeatures {
+            pfr0: read_if!(mask, PFR0, ID_AA64PFR0_EL1),
+            pfr1: read_if!(mask, PFR1, ID_AA64PFR1_EL1),
+            isar0: read_if!(mask, ISAR0, ID_AA64ISAR0_EL1),
+            isar1: read_if!(mask, ISAR1, ID_AA64ISAR1_EL1),
+            isar2: read_if!(mask, ISAR2, ID_AA64ISAR2_EL1),
+            mmfr0: read_if!(mask, MMFR0, ID_AA64MMFR0_EL1),
+            mmfr1: read_if!(mask, MMFR1, ID_AA64MMFR1_EL1),
+            mmfr2: read_if!(mask, MMFR2, ID_AA64MMFR2_EL1),
+            dfr0: read_if!(mask, DFR0, ID_AA64DFR0_EL1),
+            dfr1: read_if!(mask, DFR1, ID_AA64DFR1_EL1),
+            zfr0: read_if!(mask, ZFR0, ID_AA64ZFR0_EL1),
+            smfr0: read_if!(mask, SMFR0, ID_AA64SMFR0_EL1),
+        }
+    }
+
+    /// Whether `feature` is implemented.
+    pub fn has(&self, feature: Feature) -> bool {
+        use Feature::*;
+
+        let isar0 = self.isar0;
+        let isar1 = self.isar1;
+        let isar2 = self.isar2;
+        let pfr0 = self.pfr0;
+        let pfr1 = self.pfr1;
+        let mmfr0 = self.mmfr0;
+        let mmfr1 = self.mmfr1;
+        let mmfr2 = self.mmfr2;
+        let dfr0 = self.dfr0;
+        let zfr0 = self.zfr0;
+        let smfr0 = self.smfr0;
+
+        match feature {
+            AES => at_least(isar0, ID_AA64ISAR0_EL1::AES, 1),
+            PMULL => at_least(isar0, ID_AA64ISAR0_EL1::AES, 2),
+            SHA1 => at_least(isar0, ID_AA64ISAR0_EL1::SHA1, 1),
+            SHA256 => at_least(isar0, ID_AA64ISAR0_EL1::SHA2, 1),
+            SHA512 => at_least(isar0, ID_AA64ISAR0_EL1::SHA2, 2),
+            SHA3 => at_least(isar0, ID_AA64ISAR0_EL1::SHA3, 1),
+            SM3 => at_least(isar0, ID_AA64ISAR0_EL1::SM3, 1),
+            SM4 => at_least(isar0, ID_AA64ISAR0_EL1::SM4, 1),
+            CRC32 => at_least(isar0, ID_AA64ISAR0_EL1::CRC32, 1),
+            LSE => at_least(isar0, ID_AA64ISAR0_EL1::Atomic, 2),
+            LSE128 => at_least(isar0, ID_AA64ISAR0_EL1::Atomic, 3),
+            TME => at_least(isar0, ID_AA64ISAR0_EL1::TME, 1),
+            RDM => at_least(isar0, ID_AA64ISAR0_EL1::RDM, 1),
+            DotProd => at_least(isar0, ID_AA64ISAR0_EL1::DP, 1),
+            FHM => at_least(isar0, ID_AA64ISAR0_EL1::FHM, 1),
+            FlagM => at_least(isar0, ID_AA64ISAR0_EL1::TS, 1),
+            FlagM2 => at_least(isar0, ID_AA64ISAR0_EL1::TS, 2),
+            TLBIOS => at_least(isar0, ID_AA64ISAR0_EL1::TLB, 1),
+            TLBIRANGE => at_least(isar0, ID_AA64ISAR0_EL1::TLB, 2),
+            RNG => at_least(isar0, ID_AA64ISAR0_EL1::RNDR, 1),
+
+            DPB => at_least(isar1, ID_AA64ISAR1_EL1::DPB, 1),
+            DPB2 => at_least(isar1, ID_AA64ISAR1_EL1::DPB, 2),
+            JSCVT => at_least(isar1, ID_AA64ISAR1_EL1::JSCVT, 1),
+            FCMA => at_least(isar1, ID_AA64ISAR1_EL1::FCMA, 1),
+            LRCPC => at_least(isar1, ID_AA64ISAR1_EL1::LRCPC, 1),
+            LRCPC2 => at_least(isar1, ID_AA64ISAR1_EL1::LRCPC, 2),
+            LRCPC3 => at_least(isar1, ID_AA64ISAR1_EL1::LRCPC, 3),
+            FRINTTS => at_least(isar1, ID_AA64ISAR1_EL1::FRINTTS, 1),
+            SB => at_least(isar1, ID_AA64ISAR1_EL1::SB, 1),
+            SPECRES => at_least(isar1, ID_AA64ISAR1_EL1::SPECRES, 1),
+            BF16 => at_least(isar1, ID_AA64ISAR1_EL1::BF16, 1),
+            EBF16 => at_least(isar1, ID_AA64ISAR1_EL1::BF16, 2),
+            DGH => at_least(isar1, ID_AA64ISAR1_EL1::DGH, 1),
+            I8MM => at_least(isar1, ID_AA64ISAR1_EL1::I8MM, 1),
+            XS => at_least(isar1, ID_AA64ISAR1_EL1::XS, 1),
+            LS64 => at_least(isar1, ID_AA64ISAR1_EL1::LS64, 1),
+            LS64_V => at_least(isar1, ID_AA64ISAR1_EL1::LS64, 2),
+            LS64_ACCDATA => at_least(isar1, ID_AA64ISAR1_EL1::LS64, 3),
+            PAuth => self.pauth_level(1),
+            PAuth2 => self.pauth_level(3),
+            FPAC => self.pauth_level(4),
+
+            WFxT => at_least(isar2, ID_AA64ISAR2_EL1::WFxT, 2),
+            RPRES => at_least(isar2, ID_AA64ISAR2_EL1::RPRES, 1),
+            MOPS => at_least(isar2, ID_AA64ISAR2_EL1::MOPS, 1),
+            HBC => at_least(isar2, ID_AA64ISAR2_EL1::BC, 1),
+            CLRBHB => at_least(isar2, ID_AA64ISAR2_EL1::CLRBHB, 1),
+            CSSC => at_least(isar2, ID_AA64ISAR2_EL1::CSSC, 1),
+            RPRFM => at_least(isar2, ID_AA64ISAR2_EL1::RPRFM, 1),
+
+            FP => at_least_signed(pfr0, ID_AA64PFR0_EL1::FP, 0),
+            FP16 => at_least_signed(pfr0, ID_AA64PFR0_EL1::FP, 1),
+            AdvSIMD => at_least_signed(pfr0, ID_AA64PFR0_EL1::AdvSIMD, 0),
+            SVE => at_least(pfr0, ID_AA64PFR0_EL1::SVE, 1),
+            RAS => at_least(pfr0, ID_AA64PFR0_EL1::RAS, 1),
+            RASv1p1 => at_least(pfr0, ID_AA64PFR0_EL1::RAS, 2),
+            GICv3 => at_least(pfr0, ID_AA64PFR0_EL1::GIC, 1),
+            GICv4p1 => at_least(pfr0, ID_AA64PFR0_EL1::GIC, 3),
+            SEL2 => at_least(pfr0, ID_AA64PFR0_EL1::SEL2, 1),
+            MPAM => at_least(pfr0, ID_AA64PFR0_EL1::MPAM, 1),
+            AMUv1 => at_least(pfr0, ID_AA64PFR0_EL1::AMU, 1),
+            AMUv1p1 => at_least(pfr0, ID_AA64PFR0_EL1::AMU, 2),
+            DIT => at_least(pfr0, ID_AA64PFR0_EL1::DIT, 1),
+            RME => at_least(pfr0, ID_AA64PFR0_EL1::RME, 1),
+            CSV2 => at_least(pfr0, ID_AA64PFR0_EL1::CSV2, 1),
+            CSV3 => at_least(pfr0, ID_AA64PFR0_EL1::CSV3, 1),
+
+            BTI => at_least(pfr1, ID_AA64PFR1_EL1::BT, 1),
+            SSBS => at_least(pfr1, ID_AA64PFR1_EL1::SSBS, 1),
+            SSBS2 => at_least(pfr1, ID_AA64PFR1_EL1::SSBS, 2),
+            MTE => at_least(pfr1, ID_AA64PFR1_EL1::MTE, 1),
+            MTE2 => at_least(pfr1, ID_AA64PFR1_EL1::MTE, 2),
+            MTE3 => at_least(pfr1, ID_AA64PFR1_EL1::MTE, 3),
+            SME => at_least(pfr1, ID_AA64PFR1_EL1::SME, 1),
+            SME2 => at_least(pfr1, ID_AA64PFR1_EL1::SME, 2),
+            NMI => at_least(pfr1, ID_AA64PFR1_EL1::NMI, 1),
+            GCS => at_least(pfr1, ID_AA64PFR1_EL1::GCS, 1),
+
+            LPA => at_least(mmfr0, ID_AA64MMFR0_EL1::PARange, 0b0110),
+            LPA2 => {
+                at_least_signed(mmfr0, ID_AA64MMFR0_EL1::TGran4, 1)
+                    || at_least(mmfr0, ID_AA64MMFR0_EL1::TGran16, 2)
+            }
+            ExS => at_least(mmfr0, ID_AA64MMFR0_EL1::ExS, 1),
+            FGT => at_least(mmfr0, ID_AA64MMFR0_EL1::FGT, 1),
+            FGT2 => at_least(mmfr0, ID_AA64MMFR0_EL1::FGT, 2),
+            ECV => at_least(mmfr0, ID_AA64MMFR0_EL1::ECV, 1),
+            ECV_POFF => at_least(mmfr0, ID_AA64MMFR0_EL1::ECV, 2),
+
+            HAFDBS => at_least(mmfr1, ID_AA64MMFR1_EL1::HAFDBS, 1),
+            VMID16 => at_least(mmfr1, ID_AA64MMFR1_EL1::VMIDBits, 2),
+            VHE => at_least(mmfr1, ID_AA64MMFR1_EL1::VH, 1),
+            HPDS => at_least(mmfr1, ID_AA64MMFR1_EL1::HPDS, 1),
+            HPDS2 => at_least(mmfr1, ID_AA64MMFR1_EL1::HPDS, 2),
+            LOR => at_least(mmfr1, ID_AA64MMFR1_EL1::LO, 1),
+            PAN => at_least(mmfr1, ID_AA64MMFR1_EL1::PAN, 1),
+            PAN2 => at_least(mmfr1, ID_AA64MMFR1_EL1::PAN, 2),
+            PAN3 => at_least(mmfr1, ID_AA64MMFR1_EL1::PAN, 3),
+            XNX => at_least(mmfr1, ID_AA64MMFR1_EL1::XNX, 1),
+            TWED => at_least(mmfr1, ID_AA64MMFR1_EL1::TWED, 1),
+            ETS2 => at_least(mmfr1, ID_AA64MMFR1_EL1::ETS, 2),
+            HCX => at_least(mmfr1, ID_AA64MMFR1_EL1::HCX, 1),
+            AFP => at_least(mmfr1, ID_AA64MMFR1_EL1::AFP, 1),
+            nTLBPA => at_least(mmfr1, ID_AA64MMFR1_EL1::nTLBPA, 1),
+            TIDCP1 => at_least(mmfr1, ID_AA64MMFR1_EL1::TIDCP1, 1),
+            CMOW => at_least(mmfr1, ID_AA64MMFR1_EL1::CMOW, 1),
+
+            TTCNP => at_least(mmfr2, ID_AA64MMFR2_EL1::CnP, 1),
+            UAO => at_least(mmfr2, ID_AA64MMFR2_EL1::UAO, 1),
+            LSMAOC => at_least(mmfr2, ID_AA64MMFR2_EL1::LSM, 1),
+            IESB => at_least(mmfr2, ID_AA64MMFR2_EL1::IESB, 1),
+            LVA => at_least(mmfr2, ID_AA64MMFR2_EL1::VARange, 1),
+            CCIDX => at_least(mmfr2, ID_AA64MMFR2_EL1::CCIDX, 1),
+            NV => at_least(mmfr2, ID_AA64MMFR2_EL1::NV, 1),
+            NV2 => at_least(mmfr2, ID_AA64MMFR2_EL1::NV, 2),
+            TTST => at_least(mmfr2, ID_AA64MMFR2_EL1::ST, 1),
+            LSE2 => at_least(mmfr2, ID_AA64MMFR2_EL1::AT, 1),
+            IDST => at_least(mmfr2, ID_AA64MMFR2_EL1::IDS, 1),
+            S2FWB => at_least(mmfr2, ID_AA64MMFR2_EL1::FWB, 1),
+            TTL => at_least(mmfr2, ID_AA64MMFR2_EL1::TTL, 1),
+            BBM => at_least(mmfr2, ID_AA64MMFR2_EL1::BBM, 1),
+            EVT => at_least(mmfr2, ID_AA64MMFR2_EL1::EVT, 1),
+            E0PD => at_least(mmfr2, ID_AA64MMFR2_EL1::E0PD, 1),
+
+            PMUv3 => {
+                at_least(dfr0, ID_AA64DFR0_EL1::PMUVer, 1)
+                    && ID_AA64DFR0_EL1::PMUVer.read(dfr0) != 0b1111
+            }
+            SPE => at_least(dfr0, ID_AA64DFR0_EL1::PMSVer, 1),
+            TRBE => at_least(dfr0, ID_AA64DFR0_EL1::TraceBuffer, 1),
+            MTPMU => at_least_signed(dfr0, ID_AA64DFR0_EL1::MTPMU, 1),
+            BRBE => at_least(dfr0, ID_AA64DFR0_EL1::BRBE, 1),
+
+            SVE2 => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::SVEver, 1),
+            SVE2p1 => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::SVEver, 2),
+            SVE_AES => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::AES, 1),
+            SVE_PMULL128 => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::AES, 2),
+            SVE_BitPerm => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::BitPerm, 1),
+            SVE_SHA3 => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::SHA3, 1),
+            SVE_SM4 => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::SM4, 1),
+            F32MM => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::F32MM, 1),
+            F64MM => self.has(SVE) && at_least(zfr0, ID_AA64ZFR0_EL1::F64MM, 1),
+
+            SME_FA64 => self.has(SME) && at_least(smfr0, ID_AA64SMFR0_EL1::FA64, 1),
+            SME_F64F64 => self.has(SME) && at_least(smfr0, ID_AA64SMFR0_EL1::F64F64, 1),
+            SME_I16I64 => self.has(SME) && ID_AA64SMFR0_EL1::I16I64.read(smfr0) == 0b1111,
+        }
+    }
+
+    /// Whether any of the address or generic authentication algorithms reports at least
+    /// `level`.
+    fn pauth_level(&self, level: u64) -> bool {
+        at_least(self.isar1, ID_AA64ISAR1_EL1::APA, level)
+            || at_least(self.isar1, ID_AA64ISAR1_EL1::API, level)
+            || at_least(self.isar2, ID_AA64ISAR2_EL1::APA3, level)
+            || (level == 1
+                && (at_least(self.isar1, ID_AA64ISAR1_EL1::GPA, 1)
+                    || at_least(self.isar1, ID_AA64ISAR1_EL1::GPI, 1)
+                    || at_least(self.isar2, ID_AA64ISAR2_EL1::GPA3, 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versioned_fields() {
+        // Atomic = 0b0011, SHA2 = 0b0001, AES = 0b0010.
+        let f = CpuFeatures {
+            isar0: (0b0011 << 20) | (0b0001 << 12) | (0b0010 << 4),
+            ..Default::default()
+        };
+        assert!(f.has(Feature::LSE) && f.has(Feature::LSE128));
+        assert!(f.has(Feature::SHA256) && !f.has(Feature::SHA512));
+        assert!(f.has(Feature::AES) && f.has(Feature::PMULL));
+        assert!(!f.has(Feature::CRC32));
+
+        // ECV = 0b0001.
+        let f = CpuFeatures {
+            mmfr0: 0b0001 << 60,
+            ..Default::default()
+        };
+        assert!(f.has(Feature::ECV) && !f.has(Feature::ECV_POFF));
+    }
+
+    #[test]
+    fn signed_fields() {
+        // FP and AdvSIMD not implemented, 4KiB granule with 52-bit addresses.
+        let f = CpuFeatures {
+            pfr0: (0b1111 << 20) | (0b1111 << 16),
+            mmfr0: 0b0001 << 28,
+            ..Default::default()
+        };
+        assert!(!f.has(Feature::FP) && !f.has(Feature::AdvSIMD));
+        assert!(f.has(Feature::LPA2));
+
+        // FP16, 4KiB granule not implemented.
+        let f = CpuFeatures {
+            pfr0: 0b0001 << 16,
+            mmfr0: 0b1111 << 28,
+            ..Default::default()
+        };
+        assert!(f.has(Feature::FP) && f.has(Feature::FP16));
+        assert!(!f.has(Feature::LPA2));
+    }
+
+    #[test]
+    fn dependent_features() {
+        // SVEver = 1 without SVE.
+        let mut f = CpuFeatures {
+            zfr0: 1,
+            isar1: 0b0101 << 4,
+            ..Default::default()
+        };
+        assert!(!f.has(Feature::SVE2));
+        f.pfr0 = 1 << 32;
+        assert!(f.has(Feature::SVE2));
+        assert!(f.has(Feature::PAuth) && f.has(Feature::PAuth2) && f.has(Feature::FPAC));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn current_cpu() {
+        use crate::registers::mock;
+
+        mock::clear();
+        mock::set_reset_value("ID_AA64MMFR2_EL1", 1 << 20);
+        mock::set_reset_value("ID_AA64ISAR0_EL1", 1 << 60);
+
+        assert!(Feature::CCIDX.is_present_on_current_cpu());
+        assert!(Feature::RNG.is_present_on_current_cpu());
+        assert!(!Feature::SVE2.is_present_on_current_cpu());
+
+        // Only the ID register reporting the feature is read.
+        mock::clear();
+        mock::set_reset_value("ID_AA64MMFR2_EL1", 1 << 20);
+        Feature::CCIDX.is_present_on_current_cpu();
+        assert_eq!(
+            mock::accesses(),
+            [mock::Access::Read("ID_AA64MMFR2_EL1", 1 << 20)]
+        );
+    }
+}
@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt masking.
+//!
+//! [`MaskGuard`] masks the exceptions selected by its `MASK`, a combination of
+//! [`DAIF_D`], [`DAIF_A`], [`DAIF_I`] and [`DAIF_F`], and restores their previous state when it is
+//! dropped. Guards can be nested. Masking and unmasking use the `daifset` and `daifclr`
+//! immediates.
+//!
+//! With the `critical-section-single-core` feature, this module also provides a
+//! [`critical-section`](https://docs.rs/critical-section) implementation that masks IRQs and FIQs.
+//! It is only sound on single-core systems.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::interrupt::{self, IrqGuard, MaskGuard, DAIF_F, DAIF_I};
+//!
+//! let counter = interrupt::with_irqs_masked(|| 42);
+//!
+//! {
+//!     let _guard = MaskGuard::<{ DAIF_I | DAIF_F }>::new();
+//!     // IRQs and FIQs are masked until the end of the scope.
+//! }
+//!
+//! let guard = IrqGuard::new();
+//! drop(guard);
+//! ```
+
+pub use crate::asm::pstate::{DAIF_A, DAIF_ALL, DAIF_D, DAIF_F, DAIF_I};
+
+use crate::{
+    asm::pstate::{daif_clear, daif_set},
+    registers::DAIF,
+};
+use core::marker::PhantomData;
+use tock_registers::interfaces::Readable;
+
+/// Masks the exceptions selected by `MASK` until it is dropped.
+///
+/// The guard is bound to the core it was created on, so it is neither `Send` nor `Sync`.
+#[must_use = "the exceptions are unmasked again when the guard is dropped"]
+pub struct MaskGuard<const MASK: u8> {
+    saved: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+/// Masks IRQs until it is dropped.
+pub type IrqGuard = MaskGuard<DAIF_I>;
+
+/// Masks all of D, A, I and F until it is dropped.
+pub type DaifGuard = MaskGuard<DAIF_ALL>;
+
+impl<const MASK: u8> MaskGuard<MASK> {
+    /// Masks the exceptions selected by `MASK`.
+    #[inline(always)]
+    pub fn new() -> Self {
+        let saved = DAIF.get();
+        daif_set::<MASK>();
+
+        MaskGuard {
+            saved,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl<const MASK: u8> Default for MaskGuard<MASK> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const MASK: u8> Drop for MaskGuard<MASK> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        restore::<MASK>(self.saved);
+    }
+}
+
+/// Restores the exceptions selected by `MASK` to their state in the `DAIF` value `saved`.
+#[inline(always)]
+fn restore<const MASK: u8>(saved: u64) {
+    let mask = ((MASK & DAIF_ALL) as u64) << 6;
+
+    match saved & mask {
+        0 => daif_clear::<MASK>(),
+        masked if masked == mask => {}
+        // Only some of the exceptions were masked before.
+        masked => {
+            let value = (DAIF.get() & !mask) | masked;
+
+            match () {
+                // Unlike `DAIF.set()`, the write is not `nomem`, so that memory accesses of the
+                // critical section are not moved past it.
+                #[cfg(all(target_arch = "aarch64", not(feature = "mock")))]
+                () => unsafe {
+                    core::arch::asm!("msr daif, {x}", x = in(reg) value, options(nostack))
+                },
+
+                #[cfg(not(all(target_arch = "aarch64", not(feature = "mock"))))]
+                () => {
+                    use tock_registers::interfaces::Writeable;
+
+                    DAIF.set(value)
+                }
+            }
+        }
+    }
+}
+
+/// Calls `f` with the exceptions selected by `MASK` masked.
+#[inline(always)]
+pub fn with_masked<const MASK: u8, R>(f: impl FnOnce() -> R) -> R {
+    let _guard = MaskGuard::<MASK>::new();
+
+    f()
+}
+
+/// Calls `f` with IRQs masked.
+#[inline(always)]
+pub fn with_irqs_masked<R>(f: impl FnOnce() -> R) -> R {
+    with_masked::<DAIF_I, R>(f)
+}
+
+/// Returns whether IRQs are masked.
+#[inline(always)]
+pub fn irqs_masked() -> bool {
+    DAIF.is_set(DAIF::I)
+}
+
+#[cfg(feature = "critical-section-single-core")]
+mod single_core {
+    use super::{restore, DAIF_F, DAIF_I};
+    use crate::{asm::pstate::daif_set, registers::DAIF};
+    use critical_section::RawRestoreState;
+    use tock_registers::interfaces::Readable;
+
+    struct SingleCoreCriticalSection;
+    critical_section::set_impl!(SingleCoreCriticalSection);
+
+    unsafe impl critical_section::Impl for SingleCoreCriticalSection {
+        unsafe fn acquire() -> RawRestoreState {
+            let saved = DAIF.get();
+            daif_set::<{ DAIF_I | DAIF_F }>();
+
+            saved
+        }
+
+        unsafe fn release(saved: RawRestoreState) {
+            restore::<{ DAIF_I | DAIF_F }>(saved);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::registers::mock;
+
+    #[test]
+    fn nested() {
+        mock::clear();
+
+        with_irqs_masked(|| {
+            assert!(irqs_masked());
+
+            let guard = MaskGuard::<{ DAIF_I | DAIF_F }>::new();
+            assert_eq!(mock::peek("DAIF"), 0b0011 << 6);
+            drop(guard);
+
+            // IRQs stay masked, FIQs are unmasked again.
+            assert_eq!(mock::peek("DAIF"), 0b0010 << 6);
+        });
+        assert_eq!(mock::peek("DAIF"), 0);
+
+        mock::poke("DAIF", (DAIF_ALL as u64) << 6);
+        drop(DaifGuard::new());
+        assert_eq!(mock::peek("DAIF"), 0b1111 << 6);
+    }
+
+    #[cfg(feature = "critical-section-single-core")]
+    #[test]
+    fn critical_section() {
+        mock::clear();
+        mock::set_reset_value("DAIF", (DAIF_A as u64) << 6);
+
+        critical_section::with(|_| {
+            assert_eq!(mock::peek("DAIF"), 0b0111 << 6);
+        });
+        assert_eq!(mock::peek("DAIF"), 0b0100 << 6);
+    }
+}
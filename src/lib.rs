@@ -56,4 +56,12 @@
 #![no_std]
 
 pub mod asm;
+pub mod exception;
+pub mod features;
+pub mod interrupt;
+pub mod paging;
+pub mod pmu;
 pub mod registers;
+pub mod smccc;
+pub mod timer;
+pub mod vgic;
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! VMSAv8-64 translation tables.
+
+pub mod descriptors;
+pub mod table;
+
+/// Translation granule size.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Granule {
+    Size4KiB,
+    Size16KiB,
+    Size64KiB,
+}
+
+impl Granule {
+    /// Log2 of the granule size in bytes.
+    pub const fn shift(self) -> u32 {
+        match self {
+            Granule::Size4KiB => 12,
+            Granule::Size16KiB => 14,
+            Granule::Size64KiB => 16,
+        }
+    }
+
+    /// The granule size in bytes.
+    pub const fn size(self) -> usize {
+        1 << self.shift()
+    }
+}
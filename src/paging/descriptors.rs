@@ -0,0 +1,513 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Translation table descriptors
+//!
+//! Formats of the VMSAv8-64 table, block and page descriptors for stage 1 and stage 2
+//! translations. Output and next-level table addresses are provided in one field per granule
+//! size. For block descriptors, the address bits below the block size are RES0 and must be
+//! zero.
+//!
+//! For 52-bit output addresses, the upper address bits are held in `*_51_48` (64KiB granule,
+//! FEAT_LPA) or `*_51_50` and `*_49_48` (4KiB and 16KiB granules, FEAT_LPA2 with `TCR_ELx.DS`
+//! set) fields. The `*_51_50` fields replace the `SH` field, whose value is taken from `TCR_ELx`
+//! instead.
+//!
+//! # Example
+//!
+//! ```
+//! use aarch64_cpu::paging::descriptors::STAGE1_PAGE_DESCRIPTOR;
+//! use tock_registers::{interfaces::Readable, LocalRegisterCopy};
+//!
+//! let desc: LocalRegisterCopy<u64, STAGE1_PAGE_DESCRIPTOR::Register> = LocalRegisterCopy::new(
+//!     (STAGE1_PAGE_DESCRIPTOR::UXN::SET
+//!         + STAGE1_PAGE_DESCRIPTOR::OUTPUT_ADDR_4KiB.val(0x4008_0000 >> 12)
+//!         + STAGE1_PAGE_DESCRIPTOR::AF::SET
+//!         + STAGE1_PAGE_DESCRIPTOR::SH::InnerShareable
+//!         + STAGE1_PAGE_DESCRIPTOR::AP::RO_EL1
+//!         + STAGE1_PAGE_DESCRIPTOR::AttrIndx.val(1)
+//!         + STAGE1_PAGE_DESCRIPTOR::TYPE::Page
+//!         + STAGE1_PAGE_DESCRIPTOR::VALID::SET)
+//!         .value,
+//! );
+//!
+//! assert_eq!(desc.get(), 0x0040_0000_4008_0787);
+//! ```
+
+use tock_registers::register_bitfields;
+
+register_bitfields! {u64,
+    /// Table descriptor for levels 0 to 2 of a stage 1 or stage 2 translation.
+    ///
+    /// The hierarchical attributes `NSTable`, `APTable`, `UXNTable` and `PXNTable` only apply to
+    /// stage 1 translations. They are RES0 for stage 2 translations.
+    pub TABLE_DESCRIPTOR [
+        /// For memory accesses from Secure state, specifies the Security state for subsequent
+        /// levels of lookup.
+        NSTable OFFSET(63) NUMBITS(1) [],
+
+        /// Access permissions limit for subsequent levels of lookup.
+        APTable OFFSET(61) NUMBITS(2) [
+            /// No effect on permissions in subsequent levels of lookup.
+            NoEffect = 0b00,
+
+            /// Access at EL0 not permitted, regardless of permissions in subsequent levels of
+            /// lookup.
+            PrivilegedOnly = 0b01,
+
+            /// Write access not permitted, at any Exception level, regardless of permissions in
+            /// subsequent levels of lookup.
+            ReadOnly = 0b10,
+
+            /// Regardless of permissions in subsequent levels of lookup, write access is not
+            /// permitted at any Exception level, and read access is not permitted at EL0.
+            PrivilegedReadOnly = 0b11
+        ],
+
+        /// Unprivileged execute-never limit for subsequent levels of lookup. For translation
+        /// regimes that only support a single privilege level, this is the `XNTable` bit.
+        UXNTable OFFSET(60) NUMBITS(1) [],
+
+        /// Privileged execute-never limit for subsequent levels of lookup.
+        PXNTable OFFSET(59) NUMBITS(1) [],
+
+        /// Physical address of the next table, when using the 4KiB granule.
+        NEXT_LEVEL_TABLE_ADDR_4KiB OFFSET(12) NUMBITS(36) [], // [47:12]
+
+        /// Physical address of the next table, when using the 16KiB granule.
+        NEXT_LEVEL_TABLE_ADDR_16KiB OFFSET(14) NUMBITS(34) [], // [47:14]
+
+        /// Physical address of the next table, when using the 64KiB granule.
+        NEXT_LEVEL_TABLE_ADDR_64KiB OFFSET(16) NUMBITS(32) [], // [47:16]
+
+        /// Bits[51:48] of the physical address of the next table, when using the 64KiB granule
+        /// and FEAT_LPA is implemented.
+        NEXT_LEVEL_TABLE_ADDR_64KiB_51_48 OFFSET(12) NUMBITS(4) [],
+
+        /// Bits[51:50] of the physical address of the next table, when using the 4KiB or 16KiB
+        /// granule and `TCR_ELx.DS` is set.
+        NEXT_LEVEL_TABLE_ADDR_51_50 OFFSET(8) NUMBITS(2) [],
+
+        /// Bits[49:48] of the physical address of the next table, when using the 4KiB or 16KiB
+        /// granule and `TCR_ELx.DS` is set.
+        NEXT_LEVEL_TABLE_ADDR_49_48 OFFSET(48) NUMBITS(2) [],
+
+        /// Descriptor type.
+        TYPE OFFSET(1) NUMBITS(1) [
+            Block = 0,
+            Table = 1
+        ],
+
+        /// Identifies whether the descriptor is valid.
+        VALID OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+register_bitfields! {u64,
+    /// Block descriptor for levels 1 and 2 of a stage 1 translation.
+    pub STAGE1_BLOCK_DESCRIPTOR [
+        /// Reserved for software use.
+        SW OFFSET(55) NUMBITS(4) [],
+
+        /// Unprivileged execute-never. For translation regimes that only support a single
+        /// privilege level, this is the `XN` bit.
+        UXN OFFSET(54) NUMBITS(1) [],
+
+        /// Privileged execute-never.
+        PXN OFFSET(53) NUMBITS(1) [],
+
+        /// Indicates that the descriptor is one of a contiguous set of entries that might be
+        /// cached in a single TLB entry.
+        Contiguous OFFSET(52) NUMBITS(1) [],
+
+        /// Dirty Bit Modifier. Requires FEAT_HAFDBS.
+        DBM OFFSET(51) NUMBITS(1) [],
+
+        /// Guarded Page. Indicates whether the block is a guarded page for the Branch Target
+        /// Identification mechanism. Requires FEAT_BTI.
+        GP OFFSET(50) NUMBITS(1) [],
+
+        /// Output address of the block, when using the 4KiB granule.
+        OUTPUT_ADDR_4KiB OFFSET(12) NUMBITS(36) [], // [47:12]
+
+        /// Output address of the block, when using the 16KiB granule.
+        OUTPUT_ADDR_16KiB OFFSET(14) NUMBITS(34) [], // [47:14]
+
+        /// Output address of the block, when using the 64KiB granule.
+        OUTPUT_ADDR_64KiB OFFSET(16) NUMBITS(32) [], // [47:16]
+
+        /// Bits[51:48] of the output address, when using the 64KiB granule and FEAT_LPA is
+        /// implemented.
+        OUTPUT_ADDR_64KiB_51_48 OFFSET(12) NUMBITS(4) [],
+
+        /// Block translation entry. Requires FEAT_BBM.
+        nT OFFSET(16) NUMBITS(1) [],
+
+        /// Not global. Determines whether the translation is specific to the current ASID.
+        nG OFFSET(11) NUMBITS(1) [],
+
+        /// Access flag.
+        AF OFFSET(10) NUMBITS(1) [],
+
+        /// Shareability field.
+        SH OFFSET(8) NUMBITS(2) [
+            NonShareable = 0b00,
+            OuterShareable = 0b10,
+            InnerShareable = 0b11
+        ],
+
+        /// Bits[51:50] of the output address, when using the 4KiB or 16KiB granule and
+        /// `TCR_ELx.DS` is set.
+        OUTPUT_ADDR_51_50 OFFSET(8) NUMBITS(2) [],
+
+        /// Bits[49:48] of the output address, when using the 4KiB or 16KiB granule and
+        /// `TCR_ELx.DS` is set.
+        OUTPUT_ADDR_49_48 OFFSET(48) NUMBITS(2) [],
+
+        /// Data access permissions.
+        AP OFFSET(6) NUMBITS(2) [
+            RW_EL1 = 0b00,
+            RW_EL1_EL0 = 0b01,
+            RO_EL1 = 0b10,
+            RO_EL1_EL0 = 0b11
+        ],
+
+        /// Non-secure bit. For memory accesses from Secure state, specifies whether the output
+        /// address is in the Secure or Non-secure address map.
+        NS OFFSET(5) NUMBITS(1) [],
+
+        /// Stage 1 memory attributes index field, for the `MAIR_ELx`.
+        AttrIndx OFFSET(2) NUMBITS(3) [],
+
+        /// Descriptor type.
+        TYPE OFFSET(1) NUMBITS(1) [
+            Block = 0,
+            Table = 1
+        ],
+
+        /// Identifies whether the descriptor is valid.
+        VALID OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+register_bitfields! {u64,
+    /// Page descriptor for level 3 of a stage 1 translation.
+    pub STAGE1_PAGE_DESCRIPTOR [
+        /// Reserved for software use.
+        SW OFFSET(55) NUMBITS(4) [],
+
+        /// Unprivileged execute-never. For translation regimes that only support a single
+        /// privilege level, this is the `XN` bit.
+        UXN OFFSET(54) NUMBITS(1) [],
+
+        /// Privileged execute-never.
+        PXN OFFSET(53) NUMBITS(1) [],
+
+        /// Indicates that the descriptor is one of a contiguous set of entries that might be
+        /// cached in a single TLB entry.
+        Contiguous OFFSET(52) NUMBITS(1) [],
+
+        /// Dirty Bit Modifier. Requires FEAT_HAFDBS.
+        DBM OFFSET(51) NUMBITS(1) [],
+
+        /// Guarded Page. Indicates whether the page is a guarded page for the Branch Target
+        /// Identification mechanism. Requires FEAT_BTI.
+        GP OFFSET(50) NUMBITS(1) [],
+
+        /// Output address of the page, when using the 4KiB granule.
+        OUTPUT_ADDR_4KiB OFFSET(12) NUMBITS(36) [], // [47:12]
+
+        /// Output address of the page, when using the 16KiB granule.
+        OUTPUT_ADDR_16KiB OFFSET(14) NUMBITS(34) [], // [47:14]
+
+        /// Output address of the page, when using the 64KiB granule.
+        OUTPUT_ADDR_64KiB OFFSET(16) NUMBITS(32) [], // [47:16]
+
+        /// Bits[51:48] of the output address, when using the 64KiB granule and FEAT_LPA is
+        /// implemented.
+        OUTPUT_ADDR_64KiB_51_48 OFFSET(12) NUMBITS(4) [],
+
+        /// Not global. Determines whether the translation is specific to the current ASID.
+        nG OFFSET(11) NUMBITS(1) [],
+
+        /// Access flag.
+        AF OFFSET(10) NUMBITS(1) [],
+
+        /// Shareability field.
+        SH OFFSET(8) NUMBITS(2) [
+            NonShareable = 0b00,
+            OuterShareable = 0b10,
+            InnerShareable = 0b11
+        ],
+
+        /// Bits[51:50] of the output address, when using the 4KiB or 16KiB granule and
+        /// `TCR_ELx.DS` is set.
+        OUTPUT_ADDR_51_50 OFFSET(8) NUMBITS(2) [],
+
+        /// Bits[49:48] of the output address, when using the 4KiB or 16KiB granule and
+        /// `TCR_ELx.DS` is set.
+        OUTPUT_ADDR_49_48 OFFSET(48) NUMBITS(2) [],
+
+        /// Data access permissions.
+        AP OFFSET(6) NUMBITS(2) [
+            RW_EL1 = 0b00,
+            RW_EL1_EL0 = 0b01,
+            RO_EL1 = 0b10,
+            RO_EL1_EL0 = 0b11
+        ],
+
+        /// Non-secure bit. For memory accesses from Secure state, specifies whether the output
+        /// address is in the Secure or Non-secure address map.
+        NS OFFSET(5) NUMBITS(1) [],
+
+        /// Stage 1 memory attributes index field, for the `MAIR_ELx`.
+        AttrIndx OFFSET(2) NUMBITS(3) [],
+
+        /// Descriptor type. Must be `Page` for a valid level 3 descriptor.
+        TYPE OFFSET(1) NUMBITS(1) [
+            Reserved_Invalid = 0,
+            Page = 1
+        ],
+
+        /// Identifies whether the descriptor is valid.
+        VALID OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+register_bitfields! {u64,
+    /// Block descriptor for levels 1 and 2 of a stage 2 translation.
+    pub STAGE2_BLOCK_DESCRIPTOR [
+        /// Reserved for software use.
+        SW OFFSET(55) NUMBITS(4) [],
+
+        /// Execute-never control. If FEAT_XNX is not implemented, only bit[54] is used and
+        /// bit[53] is RES0.
+        XN OFFSET(53) NUMBITS(2) [
+            /// Execution permitted at EL1 and EL0.
+            ExecuteEL1EL0 = 0b00,
+
+            /// Execution permitted at EL0 only. Requires FEAT_XNX.
+            ExecuteEL0 = 0b01,
+
+            /// Execution not permitted.
+            ExecuteNever = 0b10,
+
+            /// Execution permitted at EL1 only. Requires FEAT_XNX.
+            ExecuteEL1 = 0b11
+        ],
+
+        /// Indicates that the descriptor is one of a contiguous set of entries that might be
+        /// cached in a single TLB entry.
+        Contiguous OFFSET(52) NUMBITS(1) [],
+
+        /// Dirty Bit Modifier. Requires FEAT_HAFDBS.
+        DBM OFFSET(51) NUMBITS(1) [],
+
+        /// Output address of the block, when using the 4KiB granule.
+        OUTPUT_ADDR_4KiB OFFSET(12) NUMBITS(36) [], // [47:12]
+
+        /// Output address of the block, when using the 16KiB granule.
+        OUTPUT_ADDR_16KiB OFFSET(14) NUMBITS(34) [], // [47:14]
+
+        /// Output address of the block, when using the 64KiB granule.
+        OUTPUT_ADDR_64KiB OFFSET(16) NUMBITS(32) [], // [47:16]
+
+        /// Bits[51:48] of the output address, when using the 64KiB granule and FEAT_LPA is
+        /// implemented.
+        OUTPUT_ADDR_64KiB_51_48 OFFSET(12) NUMBITS(4) [],
+
+        /// Block translation entry. Requires FEAT_BBM.
+        nT OFFSET(16) NUMBITS(1) [],
+
+        /// Access flag.
+        AF OFFSET(10) NUMBITS(1) [],
+
+        /// Shareability field.
+        SH OFFSET(8) NUMBITS(2) [
+            NonShareable = 0b00,
+            OuterShareable = 0b10,
+            InnerShareable = 0b11
+        ],
+
+        /// Bits[51:50] of the output address, when using the 4KiB or 16KiB granule and
+        /// `VTCR_EL2.DS` is set.
+        OUTPUT_ADDR_51_50 OFFSET(8) NUMBITS(2) [],
+
+        /// Bits[49:48] of the output address, when using the 4KiB or 16KiB granule and
+        /// `VTCR_EL2.DS` is set.
+        OUTPUT_ADDR_49_48 OFFSET(48) NUMBITS(2) [],
+
+        /// Stage 2 data access permissions.
+        S2AP OFFSET(6) NUMBITS(2) [
+            None = 0b00,
+            ReadOnly = 0b01,
+            WriteOnly = 0b10,
+            ReadWrite = 0b11
+        ],
+
+        /// Stage 2 memory attributes, when `HCR_EL2.FWB` is 0.
+        MemAttr OFFSET(2) NUMBITS(4) [
+            Device_nGnRnE = 0b0000,
+            Device_nGnRE = 0b0001,
+            Device_nGRE = 0b0010,
+            Device_GRE = 0b0011,
+            Normal_OuterNonCacheable_InnerNonCacheable = 0b0101,
+            Normal_OuterWriteThrough_InnerWriteThrough = 0b1010,
+            Normal_OuterWriteBack_InnerWriteBack = 0b1111
+        ],
+
+        /// Descriptor type.
+        TYPE OFFSET(1) NUMBITS(1) [
+            Block = 0,
+            Table = 1
+        ],
+
+        /// Identifies whether the descriptor is valid.
+        VALID OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+register_bitfields! {u64,
+    /// Page descriptor for level 3 of a stage 2 translation.
+    pub STAGE2_PAGE_DESCRIPTOR [
+        /// Reserved for software use.
+        SW OFFSET(55) NUMBITS(4) [],
+
+        /// Execute-never control. If FEAT_XNX is not implemented, only bit[54] is used and
+        /// bit[53] is RES0.
+        XN OFFSET(53) NUMBITS(2) [
+            /// Execution permitted at EL1 and EL0.
+            ExecuteEL1EL0 = 0b00,
+
+            /// Execution permitted at EL0 only. Requires FEAT_XNX.
+            ExecuteEL0 = 0b01,
+
+            /// Execution not permitted.
+            ExecuteNever = 0b10,
+
+            /// Execution permitted at EL1 only. Requires FEAT_XNX.
+            ExecuteEL1 = 0b11
+        ],
+
+        /// Indicates that the descriptor is one of a contiguous set of entries that might be
+        /// cached in a single TLB entry.
+        Contiguous OFFSET(52) NUMBITS(1) [],
+
+        /// Dirty Bit Modifier. Requires FEAT_HAFDBS.
+        DBM OFFSET(51) NUMBITS(1) [],
+
+        /// Output address of the page, when using the 4KiB granule.
+        OUTPUT_ADDR_4KiB OFFSET(12) NUMBITS(36) [], // [47:12]
+
+        /// Output address of the page, when using the 16KiB granule.
+        OUTPUT_ADDR_16KiB OFFSET(14) NUMBITS(34) [], // [47:14]
+
+        /// Output address of the page, when using the 64KiB granule.
+        OUTPUT_ADDR_64KiB OFFSET(16) NUMBITS(32) [], // [47:16]
+
+        /// Bits[51:48] of the output address, when using the 64KiB granule and FEAT_LPA is
+        /// implemented.
+        OUTPUT_ADDR_64KiB_51_48 OFFSET(12) NUMBITS(4) [],
+
+        /// Access flag.
+        AF OFFSET(10) NUMBITS(1) [],
+
+        /// Shareability field.
+        SH OFFSET(8) NUMBITS(2) [
+            NonShareable = 0b00,
+            OuterShareable = 0b10,
+            InnerShareable = 0b11
+        ],
+
+        /// Bits[51:50] of the output address, when using the 4KiB or 16KiB granule and
+        /// `VTCR_EL2.DS` is set.
+        OUTPUT_ADDR_51_50 OFFSET(8) NUMBITS(2) [],
+
+        /// Bits[49:48] of the output address, when using the 4KiB or 16KiB granule and
+        /// `VTCR_EL2.DS` is set.
+        OUTPUT_ADDR_49_48 OFFSET(48) NUMBITS(2) [],
+
+        /// Stage 2 data access permissions.
+        S2AP OFFSET(6) NUMBITS(2) [
+            None = 0b00,
+            ReadOnly = 0b01,
+            WriteOnly = 0b10,
+            ReadWrite = 0b11
+        ],
+
+        /// Stage 2 memory attributes, when `HCR_EL2.FWB` is 0.
+        MemAttr OFFSET(2) NUMBITS(4) [
+            Device_nGnRnE = 0b0000,
+            Device_nGnRE = 0b0001,
+            Device_nGRE = 0b0010,
+            Device_GRE = 0b0011,
+            Normal_OuterNonCacheable_InnerNonCacheable = 0b0101,
+            Normal_OuterWriteThrough_InnerWriteThrough = 0b1010,
+            Normal_OuterWriteBack_InnerWriteBack = 0b1111
+        ],
+
+        /// Descriptor type. Must be `Page` for a valid level 3 descriptor.
+        TYPE OFFSET(1) NUMBITS(1) [
+            Reserved_Invalid = 0,
+            Page = 1
+        ],
+
+        /// Identifies whether the descriptor is valid.
+        VALID OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tock_registers::LocalRegisterCopy;
+
+    #[test]
+    fn lpa2_output_address() {
+        // 52-bit output address, 16KiB aligned.
+        let pa: u64 = 0x000f_abcd_e123_4000;
+
+        let desc: LocalRegisterCopy<u64, STAGE1_PAGE_DESCRIPTOR::Register> = LocalRegisterCopy::new(
+            (STAGE1_PAGE_DESCRIPTOR::OUTPUT_ADDR_51_50.val(pa >> 50)
+                + STAGE1_PAGE_DESCRIPTOR::OUTPUT_ADDR_49_48.val((pa >> 48) & 0b11)
+                + STAGE1_PAGE_DESCRIPTOR::OUTPUT_ADDR_4KiB.val((pa >> 12) & 0xf_ffff_ffff)
+                + STAGE1_PAGE_DESCRIPTOR::TYPE::Page
+                + STAGE1_PAGE_DESCRIPTOR::VALID::SET)
+                .value,
+        );
+        assert_eq!(desc.get(), 0x0003_abcd_e123_4303);
+        assert_eq!(
+            (desc.read(STAGE1_PAGE_DESCRIPTOR::OUTPUT_ADDR_51_50) << 50)
+                | (desc.read(STAGE1_PAGE_DESCRIPTOR::OUTPUT_ADDR_49_48) << 48)
+                | (desc.read(STAGE1_PAGE_DESCRIPTOR::OUTPUT_ADDR_4KiB) << 12),
+            pa
+        );
+
+        let desc: LocalRegisterCopy<u64, TABLE_DESCRIPTOR::Register> = LocalRegisterCopy::new(
+            (TABLE_DESCRIPTOR::NEXT_LEVEL_TABLE_ADDR_51_50.val(pa >> 50)
+                + TABLE_DESCRIPTOR::NEXT_LEVEL_TABLE_ADDR_49_48.val((pa >> 48) & 0b11)
+                + TABLE_DESCRIPTOR::NEXT_LEVEL_TABLE_ADDR_16KiB.val((pa >> 14) & 0x3_ffff_ffff)
+                + TABLE_DESCRIPTOR::TYPE::Table
+                + TABLE_DESCRIPTOR::VALID::SET)
+                .value,
+        );
+        assert_eq!(
+            (desc.read(TABLE_DESCRIPTOR::NEXT_LEVEL_TABLE_ADDR_51_50) << 50)
+                | (desc.read(TABLE_DESCRIPTOR::NEXT_LEVEL_TABLE_ADDR_49_48) << 48)
+                | (desc.read(TABLE_DESCRIPTOR::NEXT_LEVEL_TABLE_ADDR_16KiB) << 14),
+            pa
+        );
+
+        let desc: LocalRegisterCopy<u64, STAGE2_BLOCK_DESCRIPTOR::Register> =
+            LocalRegisterCopy::new(
+                (STAGE2_BLOCK_DESCRIPTOR::OUTPUT_ADDR_51_50.val(pa >> 50)
+                    + STAGE2_BLOCK_DESCRIPTOR::OUTPUT_ADDR_49_48.val((pa >> 48) & 0b11))
+                .value,
+            );
+        assert_eq!(desc.get(), 0x0003_0000_0000_0300);
+    }
+}
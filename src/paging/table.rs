@@ -0,0 +1,724 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Stage 1 translation table builder and walker.
+//!
+//! [`PageTable`] maintains the translation tables of a lower (`TTBR0_ELx`) virtual address range
+//! in memory provided by the caller through the [`TableMemory`] trait. It does not allocate and
+//! does not touch any system register, so it can be run on the host over a plain byte buffer (see
+//! [`BufferMemory`]).
+//!
+//! Output addresses are limited to 48 bits. Blocks are used automatically wherever the virtual
+//! and physical addresses and the size of a range allow it.
+//!
+//! The tables are only modified in memory. Changing or removing a live mapping requires the
+//! caller to follow the break-before-make sequence and to perform the required barriers and TLB
+//! maintenance (see [`crate::asm::tlb`]) for the range passed to [`PageTable::map`],
+//! [`PageTable::unmap`] or [`PageTable::protect`]. Blocks that extend beyond the range are split
+//! through [`TableMemory::split_block`], which performs break-before-make for live tables.
+//!
+//! # Example
+//!
+//! ```
+//! use aarch64_cpu::paging::{
+//!     descriptors::STAGE1_PAGE_DESCRIPTOR,
+//!     table::{BufferMemory, PageTable},
+//!     Granule,
+//! };
+//!
+//! #[repr(C, align(4096))]
+//! struct Tables([u8; 4 * 4096]);
+//!
+//! let mut tables = Tables([0; 4 * 4096]);
+//! let base = tables.0.as_ptr() as u64;
+//! let memory = BufferMemory::new(&mut tables.0, base);
+//!
+//! let mut pt = PageTable::new(memory, Granule::Size4KiB, 39).unwrap();
+//!
+//! // Identity map the first GiB as normal memory with a single level 1 block.
+//! pt.map(
+//!     0,
+//!     0,
+//!     1 << 30,
+//!     STAGE1_PAGE_DESCRIPTOR::AF::SET
+//!         + STAGE1_PAGE_DESCRIPTOR::SH::InnerShareable
+//!         + STAGE1_PAGE_DESCRIPTOR::AttrIndx.val(0),
+//! )
+//! .unwrap();
+//!
+//! let leaf = pt.walk(0x1234_5678).unwrap();
+//! assert_eq!((leaf.level, leaf.pa), (1, 0x1234_5678));
+//!
+//! // TTBR0_EL1.set_baddr(pt.root());
+//! // TCR_EL1.modify(pt.tcr_el1(ID_AA64MMFR0_EL1.read(ID_AA64MMFR0_EL1::PARange)));
+//! ```
+
+use crate::{
+    paging::{
+        descriptors::{STAGE1_BLOCK_DESCRIPTOR, STAGE1_PAGE_DESCRIPTOR, TABLE_DESCRIPTOR},
+        Granule,
+    },
+    registers::TCR_EL1,
+};
+use core::convert::TryFrom;
+use tock_registers::fields::FieldValue;
+
+/// Bits [47:12] of a descriptor, holding the output or next-level table address.
+const ADDR_MASK: u64 = 0x0000_ffff_ffff_f000;
+
+/// Upper (bits [63:50]) and lower (bits [11:2]) attributes of a block or page descriptor.
+const ATTR_MASK: u64 = (0x3fff << 50) | 0xffc;
+
+/// Errors reported by [`PageTable`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The requested number of virtual address bits is not supported.
+    InvalidConfig,
+    /// An address or size is not aligned to the translation granule.
+    Unaligned,
+    /// A virtual or physical address is outside of the supported range.
+    OutOfRange,
+    /// The [`TableMemory`] could not provide a new table.
+    OutOfMemory,
+    /// A table descriptor holds the address of a table that is not part of the [`TableMemory`].
+    InvalidTable,
+}
+
+/// Memory holding translation tables.
+///
+/// Tables are identified by their physical address, which is the address that ends up in the
+/// table descriptors and in `TTBR0_ELx`.
+pub trait TableMemory {
+    /// Allocates a zeroed table of `size` bytes, aligned to `size`, and returns its physical
+    /// address.
+    fn alloc_table(&mut self, size: usize) -> Option<u64>;
+
+    /// Reads the descriptor at `index` of the table at physical address `table`.
+    ///
+    /// Returns `None` if `table` is not a table of this memory, e.g. because its address has been
+    /// read from a corrupt table descriptor.
+    fn read_entry(&self, table: u64, index: usize) -> Option<u64>;
+
+    /// Writes the descriptor at `index` of the table at physical address `table`.
+    ///
+    /// `table` has been returned by [`alloc_table`](Self::alloc_table), or has been read from
+    /// successfully.
+    fn write_entry(&mut self, table: u64, index: usize, value: u64);
+
+    /// Replaces the valid block descriptor at `index` of the table at physical address `table`
+    /// with the table descriptor `value`, which maps the same memory with smaller blocks or pages.
+    /// The block maps the virtual address `va` at lookup level `level`.
+    ///
+    /// Changing the block size of a live mapping requires the break-before-make sequence: write
+    /// an invalid descriptor, `DSB`, invalidate the TLB entries of `va`, e.g. with `TLBI VAE1IS`,
+    /// `DSB`, and then write `value`. The default implementation writes `value` directly, which is
+    /// only correct for tables that are not in use.
+    fn split_block(&mut self, table: u64, index: usize, va: u64, level: u8, value: u64) {
+        let _ = (va, level);
+        self.write_entry(table, index, value)
+    }
+}
+
+/// A [`TableMemory`] that hands out tables from a byte buffer.
+///
+/// `base` is the physical address of the first byte of the buffer. On hardware this is usually
+/// the address of a statically allocated and suitably aligned buffer; on the host it can be any
+/// value.
+///
+/// Descriptors are accessed with aligned 64-bit loads and stores, which are single-copy atomic, so
+/// that the table walker never observes a partially written descriptor.
+pub struct BufferMemory<'a> {
+    buf: &'a mut [u8],
+    base: u64,
+    next: usize,
+}
+
+impl<'a> BufferMemory<'a> {
+    /// Creates a table memory over `buf`, which is located at physical address `base`.
+    pub fn new(buf: &'a mut [u8], base: u64) -> Self {
+        BufferMemory { buf, base, next: 0 }
+    }
+
+    /// Number of bytes of the buffer that have been handed out, including alignment padding.
+    pub fn used(&self) -> usize {
+        self.next
+    }
+
+    /// Offset of the descriptor at `index` of the table at physical address `table`, if it is
+    /// within the allocated part of the buffer and aligned.
+    fn offset(&self, table: u64, index: usize) -> Option<usize> {
+        let offset = usize::try_from(table.checked_sub(self.base)?)
+            .ok()?
+            .checked_add(index.checked_mul(8)?)?;
+
+        if offset.checked_add(8)? > self.next || (self.buf.as_ptr() as usize + offset) & 7 != 0 {
+            return None;
+        }
+
+        Some(offset)
+    }
+}
+
+impl TableMemory for BufferMemory<'_> {
+    fn alloc_table(&mut self, size: usize) -> Option<u64> {
+        let misalignment = (self.base as usize).wrapping_add(self.next) & (size - 1);
+        let start = self.next + ((size - misalignment) & (size - 1));
+        let end = start.checked_add(size)?;
+
+        let table = self.buf.get_mut(start..end)?;
+        if table.as_ptr() as usize & 7 != 0 {
+            return None;
+        }
+        table.fill(0);
+        self.next = end;
+
+        Some(self.base + start as u64)
+    }
+
+    fn read_entry(&self, table: u64, index: usize) -> Option<u64> {
+        let offset = self.offset(table, index)?;
+        let entry = self.buf[offset..offset + 8].as_ptr() as *const u64;
+
+        // The entry is in bounds and aligned, as checked by `offset()`.
+        Some(unsafe { entry.read_volatile() })
+    }
+
+    fn write_entry(&mut self, table: u64, index: usize, value: u64) {
+        let offset = self
+            .offset(table, index)
+            .expect("table is not part of the buffer");
+        let entry = self.buf[offset..offset + 8].as_mut_ptr() as *mut u64;
+
+        // The entry is in bounds and aligned, as checked by `offset()`.
+        unsafe { entry.write_volatile(value) }
+    }
+}
+
+/// The leaf descriptor that translates a virtual address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Leaf {
+    /// Lookup level of the descriptor.
+    pub level: u8,
+    /// The raw block or page descriptor.
+    pub descriptor: u64,
+    /// Size in bytes of the memory region mapped by the descriptor.
+    pub size: u64,
+    /// The output address of the translated virtual address.
+    pub pa: u64,
+}
+
+/// How the hardware interprets a descriptor at a lookup level.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Kind {
+    /// Translation faults, including reserved encodings.
+    Invalid,
+    Table,
+    /// A block or page descriptor.
+    Leaf,
+}
+
+#[derive(Copy, Clone)]
+enum Op {
+    /// Map to the physical address `va + offset` (wrapping).
+    Map {
+        offset: u64,
+        attrs: u64,
+    },
+    Unmap,
+    Protect {
+        attrs: u64,
+    },
+}
+
+/// Stage 1 translation tables for a lower virtual address range.
+pub struct PageTable<M> {
+    memory: M,
+    granule: Granule,
+    va_bits: u32,
+    start_level: u8,
+    root: u64,
+}
+
+impl<M: TableMemory> PageTable<M> {
+    /// Creates empty translation tables for a `va_bits` wide virtual address range, using
+    /// `granule`. `va_bits` must be in the range 25 to 48.
+    pub fn new(mut memory: M, granule: Granule, va_bits: u32) -> Result<Self, Error> {
+        if !(25..=48).contains(&va_bits) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let bits_per_level = granule.shift() - 3;
+        let levels = (va_bits - granule.shift()).div_ceil(bits_per_level);
+        let root = memory
+            .alloc_table(granule.size())
+            .ok_or(Error::OutOfMemory)?;
+
+        Ok(PageTable {
+            memory,
+            granule,
+            va_bits,
+            start_level: (4 - levels) as u8,
+            root,
+        })
+    }
+
+    /// Physical address of the root table, to be programmed into `TTBR0_ELx`.
+    pub fn root(&self) -> u64 {
+        self.root
+    }
+
+    /// The initial lookup level.
+    pub fn start_level(&self) -> u8 {
+        self.start_level
+    }
+
+    /// The underlying table memory.
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    /// Consumes the page table and returns the underlying table memory.
+    pub fn into_memory(self) -> M {
+        self.memory
+    }
+
+    /// Computes the `T0SZ`, `TG0` and `IPS` fields of `TCR_EL1` for these tables.
+    ///
+    /// `pa_range` is the value of `ID_AA64MMFR0_EL1::PARange`. Since output addresses are limited
+    /// to 48 bits, `IPS` never exceeds 48 bits.
+    pub fn tcr_el1(&self, pa_range: u64) -> FieldValue<u64, TCR_EL1::Register> {
+        let tg0 = match self.granule {
+            Granule::Size4KiB => TCR_EL1::TG0::KiB_4,
+            Granule::Size16KiB => TCR_EL1::TG0::KiB_16,
+            Granule::Size64KiB => TCR_EL1::TG0::KiB_64,
+        };
+        // PARange and IPS share the same encoding.
+        let ips = pa_range.min(TCR_EL1::IPS::Bits_48.read(TCR_EL1::IPS));
+
+        TCR_EL1::T0SZ.val(64 - self.va_bits as u64) + tg0 + TCR_EL1::IPS.val(ips)
+    }
+
+    /// Maps `size` bytes at `va` to `pa`, replacing any existing mappings in the range.
+    ///
+    /// `attrs` provides the upper and lower attributes of the block and page descriptors. The
+    /// output address, `TYPE` and `VALID` fields are filled in automatically.
+    ///
+    /// If the table memory runs out, the range may be partially mapped.
+    pub fn map(
+        &mut self,
+        va: u64,
+        pa: u64,
+        size: u64,
+        attrs: FieldValue<u64, STAGE1_PAGE_DESCRIPTOR::Register>,
+    ) -> Result<(), Error> {
+        self.check_range(va, size)?;
+        if pa & (self.granule.size() as u64 - 1) != 0 {
+            return Err(Error::Unaligned);
+        }
+        if pa.checked_add(size).is_none_or(|end| end > 1 << 48) {
+            return Err(Error::OutOfRange);
+        }
+
+        let op = Op::Map {
+            offset: pa.wrapping_sub(va),
+            attrs: attrs.value & ATTR_MASK,
+        };
+        self.update(self.root, self.start_level, va, va + size, op)
+    }
+
+    /// Removes all mappings of the `size` bytes at `va`. Blocks that are partially covered by the
+    /// range are split. Tables that become empty are not freed.
+    pub fn unmap(&mut self, va: u64, size: u64) -> Result<(), Error> {
+        self.check_range(va, size)?;
+        self.update(self.root, self.start_level, va, va + size, Op::Unmap)
+    }
+
+    /// Replaces the attributes of all mappings of the `size` bytes at `va` with `attrs`. Unmapped
+    /// parts of the range are skipped.
+    pub fn protect(
+        &mut self,
+        va: u64,
+        size: u64,
+        attrs: FieldValue<u64, STAGE1_PAGE_DESCRIPTOR::Register>,
+    ) -> Result<(), Error> {
+        self.check_range(va, size)?;
+        let op = Op::Protect {
+            attrs: attrs.value & ATTR_MASK,
+        };
+        self.update(self.root, self.start_level, va, va + size, op)
+    }
+
+    /// Walks the tables and returns the leaf descriptor that translates `va`, if any.
+    ///
+    /// Like the hardware, the walk faults on level 3 descriptors with the reserved `TYPE` encoding
+    /// and on block descriptors at levels that do not support blocks for the granule.
+    pub fn walk(&self, va: u64) -> Option<Leaf> {
+        if va >> self.va_bits != 0 {
+            return None;
+        }
+
+        let mut table = self.root;
+        for level in self.start_level..=3 {
+            let desc = self.memory.read_entry(table, self.index(va, level))?;
+            match self.kind(desc, level) {
+                Kind::Invalid => return None,
+                Kind::Table => {
+                    table = desc & ADDR_MASK;
+                    continue;
+                }
+                Kind::Leaf => {}
+            }
+
+            let size = 1 << self.shift(level);
+            return Some(Leaf {
+                level,
+                descriptor: desc,
+                size,
+                pa: (desc & ADDR_MASK & !(size - 1)) | (va & (size - 1)),
+            });
+        }
+
+        None
+    }
+
+    fn check_range(&self, va: u64, size: u64) -> Result<(), Error> {
+        let mask = self.granule.size() as u64 - 1;
+        if va & mask != 0 || size & mask != 0 {
+            return Err(Error::Unaligned);
+        }
+        if va
+            .checked_add(size)
+            .is_none_or(|end| end > 1 << self.va_bits)
+        {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(())
+    }
+
+    /// Bit position of the lowest virtual address bit resolved at `level`.
+    fn shift(&self, level: u8) -> u32 {
+        self.granule.shift() + (3 - level as u32) * (self.granule.shift() - 3)
+    }
+
+    fn index(&self, va: u64, level: u8) -> usize {
+        let bits = if level == self.start_level {
+            self.va_bits - self.shift(level)
+        } else {
+            self.granule.shift() - 3
+        };
+
+        ((va >> self.shift(level)) & ((1 << bits) - 1)) as usize
+    }
+
+    /// Whether a block or page descriptor can be used at `level`.
+    fn leaf_allowed(&self, level: u8) -> bool {
+        match self.granule {
+            Granule::Size4KiB => level >= 1,
+            Granule::Size16KiB | Granule::Size64KiB => level >= 2,
+        }
+    }
+
+    fn kind(&self, desc: u64, level: u8) -> Kind {
+        let valid = desc & TABLE_DESCRIPTOR::VALID::SET.value != 0;
+        // Table descriptors at levels 0 to 2, page descriptors at level 3.
+        let table_or_page = desc & TABLE_DESCRIPTOR::TYPE::Table.value != 0;
+
+        match (valid, table_or_page) {
+            (false, _) => Kind::Invalid,
+            (true, true) if level < 3 => Kind::Table,
+            (true, true) => Kind::Leaf,
+            (true, false) if level < 3 && self.leaf_allowed(level) => Kind::Leaf,
+            (true, false) => Kind::Invalid,
+        }
+    }
+
+    fn leaf(&self, level: u8, pa: u64, attrs: u64) -> u64 {
+        let kind = if level == 3 {
+            STAGE1_PAGE_DESCRIPTOR::TYPE::Page.value
+        } else {
+            STAGE1_BLOCK_DESCRIPTOR::TYPE::Block.value
+        };
+
+        kind | STAGE1_PAGE_DESCRIPTOR::VALID::SET.value | attrs | (pa & ADDR_MASK)
+    }
+
+    fn table_descriptor(table: u64) -> u64 {
+        (TABLE_DESCRIPTOR::TYPE::Table + TABLE_DESCRIPTOR::VALID::SET).value | (table & ADDR_MASK)
+    }
+
+    /// Returns the next-level table referenced by entry `index`, which translates `va`,
+    /// allocating a new table for an invalid entry and splitting a block entry.
+    fn next_table(&mut self, table: u64, index: usize, va: u64, level: u8) -> Result<u64, Error> {
+        let desc = self
+            .memory
+            .read_entry(table, index)
+            .ok_or(Error::InvalidTable)?;
+        let kind = self.kind(desc, level);
+        if kind == Kind::Table {
+            return Ok(desc & ADDR_MASK);
+        }
+
+        let next = self
+            .memory
+            .alloc_table(self.granule.size())
+            .ok_or(Error::OutOfMemory)?;
+
+        if kind == Kind::Leaf {
+            let child_size = 1u64 << self.shift(level + 1);
+            let base = desc & ADDR_MASK & !((1 << self.shift(level)) - 1);
+            let attrs = desc & ATTR_MASK;
+
+            for i in 0..1 << (self.granule.shift() - 3) {
+                let child = self.leaf(level + 1, base + i as u64 * child_size, attrs);
+                self.memory.write_entry(next, i, child);
+            }
+
+            self.memory
+                .split_block(table, index, va, level, Self::table_descriptor(next));
+        } else {
+            self.memory
+                .write_entry(table, index, Self::table_descriptor(next));
+        }
+
+        Ok(next)
+    }
+
+    fn update(&mut self, table: u64, level: u8, start: u64, end: u64, op: Op) -> Result<(), Error> {
+        let size = 1u64 << self.shift(level);
+        let mut va = start;
+
+        while va < end {
+            let index = self.index(va, level);
+            let entry_end = ((va & !(size - 1)) + size).min(end);
+            let covers = va & (size - 1) == 0 && entry_end - va == size;
+            let desc = self
+                .memory
+                .read_entry(table, index)
+                .ok_or(Error::InvalidTable)?;
+            let kind = self.kind(desc, level);
+            let leaf = kind == Kind::Leaf;
+
+            match op {
+                Op::Map { offset, attrs } => {
+                    let pa = va.wrapping_add(offset);
+                    let descend = kind == Kind::Table;
+
+                    if covers && !descend && self.leaf_allowed(level) && pa & (size - 1) == 0 {
+                        let leaf = self.leaf(level, pa, attrs);
+                        self.memory.write_entry(table, index, leaf);
+                    } else {
+                        let next = self.next_table(table, index, va, level)?;
+                        self.update(next, level + 1, va, entry_end, op)?;
+                    }
+                }
+                Op::Unmap | Op::Protect { .. } if kind == Kind::Invalid => {}
+                Op::Unmap if covers && leaf => self.memory.write_entry(table, index, 0),
+                Op::Protect { attrs } if covers && leaf => {
+                    let desc = (desc & !ATTR_MASK) | attrs;
+                    self.memory.write_entry(table, index, desc);
+                }
+                Op::Unmap | Op::Protect { .. } => {
+                    let next = self.next_table(table, index, va, level)?;
+                    self.update(next, level + 1, va, entry_end, op)?;
+                }
+            }
+
+            va = entry_end;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+    use std::vec;
+
+    fn attrs() -> FieldValue<u64, STAGE1_PAGE_DESCRIPTOR::Register> {
+        STAGE1_PAGE_DESCRIPTOR::AF::SET + STAGE1_PAGE_DESCRIPTOR::AttrIndx.val(1)
+    }
+
+    #[test]
+    fn levels_and_tcr() {
+        let mut buf = vec![0u8; 0x10000];
+        let cases = [
+            (Granule::Size4KiB, 48, 0),
+            (Granule::Size4KiB, 39, 1),
+            (Granule::Size16KiB, 48, 0),
+            (Granule::Size16KiB, 47, 1),
+            (Granule::Size64KiB, 48, 1),
+            (Granule::Size64KiB, 42, 2),
+        ];
+        for (granule, va_bits, level) in cases {
+            let pt = PageTable::new(BufferMemory::new(&mut buf, 0), granule, va_bits).unwrap();
+            assert_eq!(pt.start_level(), level);
+        }
+
+        let pt = PageTable::new(BufferMemory::new(&mut buf, 0), Granule::Size64KiB, 42).unwrap();
+        let tcr = pt.tcr_el1(0b0110);
+        assert_eq!(tcr.read(TCR_EL1::T0SZ), 22);
+        assert_eq!(tcr.read(TCR_EL1::TG0), 0b01);
+        assert_eq!(tcr.read(TCR_EL1::IPS), 0b101);
+        assert_eq!(pt.tcr_el1(0b0010).read(TCR_EL1::IPS), 0b010);
+
+        assert!(PageTable::new(BufferMemory::new(&mut buf, 0), Granule::Size4KiB, 49).is_err());
+    }
+
+    #[test]
+    fn map_selects_blocks() {
+        let mut buf = vec![0u8; 0x10000];
+        let base = 0x8000_0000;
+        let mut pt =
+            PageTable::new(BufferMemory::new(&mut buf, base), Granule::Size4KiB, 39).unwrap();
+
+        // 4KiB page, 2MiB blocks, 4KiB page.
+        let va = 0x4000_0000 - 0x1000;
+        pt.map(va, 0x1_0000_0000 - 0x1000, 0x40_2000, attrs())
+            .unwrap();
+
+        let leaf = pt.walk(va).unwrap();
+        assert_eq!((leaf.level, leaf.pa), (3, 0xffff_f000));
+        let leaf = pt.walk(0x4012_3456).unwrap();
+        assert_eq!(
+            (leaf.level, leaf.size, leaf.pa),
+            (2, 0x20_0000, 0x1_0012_3456)
+        );
+        assert_eq!(leaf.descriptor & 0b11, 0b01);
+        assert_eq!(leaf.descriptor & ATTR_MASK, attrs().value);
+        let leaf = pt.walk(0x4040_0000).unwrap();
+        assert_eq!((leaf.level, leaf.pa), (3, 0x1_0040_0000));
+        assert_eq!(pt.walk(0x4040_1000), None);
+
+        // Root, two level 2 and two level 3 tables.
+        assert_eq!(pt.memory().used(), 5 * 0x1000);
+        assert_eq!(pt.root(), base);
+
+        assert_eq!(pt.map(0x800, 0, 0x1000, attrs()), Err(Error::Unaligned));
+        assert_eq!(pt.map(0, 0, 1 << 40, attrs()), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn unmap_and_protect_split_blocks() {
+        let mut buf = vec![0u8; 0x10000];
+        let mut pt = PageTable::new(BufferMemory::new(&mut buf, 0), Granule::Size4KiB, 39).unwrap();
+        pt.map(0, 0, 1 << 30, attrs()).unwrap();
+        assert_eq!(pt.walk(0x20_0000).unwrap().level, 1);
+
+        pt.unmap(0x20_1000, 0x1000).unwrap();
+        assert_eq!(pt.walk(0x20_1000), None);
+        assert_eq!(pt.walk(0x20_2000).unwrap().level, 3);
+        assert_eq!(pt.walk(0x20_2000).unwrap().pa, 0x20_2000);
+        assert_eq!(pt.walk(0x40_0000).unwrap().level, 2);
+
+        let ro = attrs() + STAGE1_PAGE_DESCRIPTOR::AP::RO_EL1;
+        pt.protect(0x20_0000, 0x20_0000, ro).unwrap();
+        assert_eq!(pt.walk(0x20_1000), None);
+        let leaf = pt.walk(0x20_0000).unwrap();
+        assert_eq!(leaf.descriptor & ATTR_MASK, ro.value);
+        assert_eq!(
+            pt.walk(0x40_0000).unwrap().descriptor & ATTR_MASK,
+            attrs().value
+        );
+    }
+
+    /// Records the blocks that are split.
+    struct SplitLog<'a> {
+        memory: BufferMemory<'a>,
+        splits: std::vec::Vec<(u64, u8)>,
+    }
+
+    impl TableMemory for SplitLog<'_> {
+        fn alloc_table(&mut self, size: usize) -> Option<u64> {
+            self.memory.alloc_table(size)
+        }
+
+        fn read_entry(&self, table: u64, index: usize) -> Option<u64> {
+            self.memory.read_entry(table, index)
+        }
+
+        fn write_entry(&mut self, table: u64, index: usize, value: u64) {
+            self.memory.write_entry(table, index, value)
+        }
+
+        fn split_block(&mut self, table: u64, index: usize, va: u64, level: u8, value: u64) {
+            self.splits.push((va, level));
+            self.memory.write_entry(table, index, value)
+        }
+    }
+
+    #[test]
+    fn split_block_hook() {
+        let mut buf = vec![0u8; 0x10000];
+        let memory = SplitLog {
+            memory: BufferMemory::new(&mut buf, 0),
+            splits: vec![],
+        };
+        let mut pt = PageTable::new(memory, Granule::Size4KiB, 39).unwrap();
+
+        // Mapping into empty entries does not split anything.
+        pt.map(0, 0, 1 << 30, attrs()).unwrap();
+        pt.map(1 << 30, 1 << 30, 0x1000, attrs()).unwrap();
+        assert!(pt.memory().splits.is_empty());
+
+        pt.unmap(0x20_1000, 0x1000).unwrap();
+        assert_eq!(pt.memory().splits, [(0x20_1000, 1), (0x20_1000, 2)]);
+    }
+
+    #[test]
+    fn out_of_memory() {
+        let mut buf = vec![0u8; 0x1000];
+        let mut pt = PageTable::new(BufferMemory::new(&mut buf, 0), Granule::Size4KiB, 39).unwrap();
+        assert_eq!(pt.map(0, 0, 0x1000, attrs()), Err(Error::OutOfMemory));
+
+        // Tables must be 8-byte aligned in the buffer for single-copy atomic accesses.
+        let mut buf = vec![0u64; 0x201];
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut u8, 0x1008) };
+        let mut memory = BufferMemory::new(&mut bytes[1..], 0);
+        assert_eq!(memory.alloc_table(0x1000), None);
+    }
+    #[test]
+    fn reserved_descriptors() {
+        let mut buf = vec![0u8; 0x10000];
+        let mut pt = PageTable::new(BufferMemory::new(&mut buf, 0), Granule::Size4KiB, 39).unwrap();
+        pt.map(0, 0, 0x1000, attrs()).unwrap();
+
+        // Level 3 descriptor with the reserved TYPE encoding.
+        let l3 = pt.walk(0).unwrap().descriptor;
+        let table = pt.memory().used() as u64 - 0x1000;
+        pt.memory.write_entry(table, 1, l3 & !0b10);
+        assert_eq!(pt.walk(0x1000), None);
+        pt.protect(0x1000, 0x1000, attrs()).unwrap();
+        assert_eq!(pt.memory().read_entry(table, 1), Some(l3 & !0b10));
+
+        // Level 0 blocks are not supported for the 4KiB granule.
+        let mut pt = PageTable::new(BufferMemory::new(&mut buf, 0), Granule::Size4KiB, 48).unwrap();
+        pt.memory.write_entry(pt.root(), 0, 0b01 | attrs().value);
+        assert_eq!(pt.walk(0), None);
+        pt.map(0, 0, 0x1000, attrs()).unwrap();
+        assert_eq!(pt.walk(0).unwrap().level, 3);
+        assert_eq!(pt.walk(0x1000), None);
+    }
+
+    #[test]
+    fn invalid_tables() {
+        let mut buf = vec![0u8; 0x10000];
+        let mut pt = PageTable::new(BufferMemory::new(&mut buf, 0), Granule::Size4KiB, 39).unwrap();
+        pt.map(0, 0, 0x1000, attrs()).unwrap();
+        let root = pt.root();
+        for table in [0x4000, 0x1_0000_0000, ADDR_MASK] {
+            let desc = PageTable::<BufferMemory>::table_descriptor(table);
+            pt.memory.write_entry(root, 0, desc);
+            assert_eq!(pt.walk(0), None);
+            assert_eq!(pt.unmap(0, 0x1000), Err(Error::InvalidTable));
+        }
+        assert_eq!(pt.memory().read_entry(root, 0x4000 / 8), None);
+        assert_eq!(pt.memory().read_entry(root + 4, 0), None);
+    }
+}
@@ -0,0 +1,455 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Extension.
+//!
+//! [`CycleCounter`] and [`EventCounter`] wrap the cycle counter `PMCCNTR_EL0` and the event
+//! counters `PMEVCNTR<n>_EL0`, which are enabled together with [`enable`]. Register writes only
+//! take effect after a context synchronization event, so an `isb` is needed before the region of
+//! code to be measured.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::{
+//!     asm::barrier,
+//!     pmu::{self, CycleCounter, Event, EventCounter, Filter},
+//! };
+//!
+//! pmu::enable();
+//!
+//! let cycles = CycleCounter::new(Filter::ALL);
+//! let refills = EventCounter::new(0).expect("no event counters");
+//! refills.configure(Event::L1DCacheRefill, Filter::ALL);
+//! cycles.enable();
+//! refills.enable();
+//! barrier::isb(barrier::SY);
+//!
+//! // Code to be measured.
+//!
+//! let (cycles, refills) = (cycles.read(), refills.read());
+//! ```
+
+use crate::registers::{
+    pmevcntr_el0, pmevtyper_el0, PMCCFILTR_EL0, PMCCNTR_EL0, PMCNTENCLR_EL0, PMCNTENSET_EL0,
+    PMCR_EL0, PMEVTYPER_EL0, PMINTENCLR_EL1, PMINTENSET_EL1, PMOVSCLR_EL0,
+};
+use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
+
+/// Common architectural and microarchitectural events.
+///
+/// Events compare equal if their event numbers are equal, so that `Event::Other(0x11)` equals
+/// `Event::CpuCycles`.
+#[derive(Copy, Clone, Debug)]
+pub enum Event {
+    /// Instruction architecturally executed, condition code check pass, software increment.
+    SwIncr,
+    /// Level 1 instruction cache refill.
+    L1ICacheRefill,
+    /// Attributable Level 1 instruction TLB refill.
+    L1ITlbRefill,
+    /// Level 1 data cache refill.
+    L1DCacheRefill,
+    /// Level 1 data cache access.
+    L1DCache,
+    /// Attributable Level 1 data TLB refill.
+    L1DTlbRefill,
+    /// Instruction architecturally executed, condition code check pass, load.
+    LdRetired,
+    /// Instruction architecturally executed, condition code check pass, store.
+    StRetired,
+    /// Instruction architecturally executed.
+    InstRetired,
+    /// Exception taken.
+    ExcTaken,
+    /// Instruction architecturally executed, condition code check pass, exception return.
+    ExcReturn,
+    /// Instruction architecturally executed, condition code check pass, write to `CONTEXTIDR`.
+    CidWriteRetired,
+    /// Instruction architecturally executed, condition code check pass, software change of the
+    /// PC.
+    PcWriteRetired,
+    /// Instruction architecturally executed, immediate branch.
+    BrImmedRetired,
+    /// Instruction architecturally executed, condition code check pass, procedure return.
+    BrReturnRetired,
+    /// Instruction architecturally executed, condition code check pass, unaligned load or store.
+    UnalignedLdStRetired,
+    /// Mispredicted or not predicted branch speculatively executed.
+    BrMisPred,
+    /// Cycle.
+    CpuCycles,
+    /// Predictable branch speculatively executed.
+    BrPred,
+    /// Data memory access.
+    MemAccess,
+    /// Level 1 instruction cache access.
+    L1ICache,
+    /// Level 1 data cache write-back.
+    L1DCacheWb,
+    /// Level 2 data cache access.
+    L2DCache,
+    /// Level 2 data cache refill.
+    L2DCacheRefill,
+    /// Level 2 data cache write-back.
+    L2DCacheWb,
+    /// Bus access.
+    BusAccess,
+    /// Local memory error.
+    MemoryError,
+    /// Operation speculatively executed.
+    InstSpec,
+    /// Instruction architecturally executed, condition code check pass, write to `TTBR`.
+    TtbrWriteRetired,
+    /// Bus cycle.
+    BusCycles,
+    /// For an odd-numbered counter, increments when the preceding even-numbered counter
+    /// overflows.
+    Chain,
+    /// Level 1 data cache allocation without refill.
+    L1DCacheAllocate,
+    /// Level 2 data cache allocation without refill.
+    L2DCacheAllocate,
+    /// Instruction architecturally executed, branch.
+    BrRetired,
+    /// Instruction architecturally executed, mispredicted branch.
+    BrMisPredRetired,
+    /// No operation sent for execution due to the frontend.
+    StallFrontend,
+    /// No operation sent for execution due to the backend.
+    StallBackend,
+    /// Any other, e.g. IMPLEMENTATION DEFINED, event number.
+    Other(u16),
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool {
+        self.number() == other.number()
+    }
+}
+
+impl Eq for Event {}
+
+impl From<u16> for Event {
+    fn from(n: u16) -> Self {
+        Event::from_number(n)
+    }
+}
+
+impl Event {
+    /// The event number, as programmed into `PMEVTYPER<n>_EL0.evtCount`.
+    pub const fn number(self) -> u16 {
+        match self {
+            Event::SwIncr => 0x00,
+            Event::L1ICacheRefill => 0x01,
+            Event::L1ITlbRefill => 0x02,
+            Event::L1DCacheRefill => 0x03,
+            Event::L1DCache => 0x04,
+            Event::L1DTlbRefill => 0x05,
+            Event::LdRetired => 0x06,
+            Event::StRetired => 0x07,
+            Event::InstRetired => 0x08,
+            Event::ExcTaken => 0x09,
+            Event::ExcReturn => 0x0a,
+            Event::CidWriteRetired => 0x0b,
+            Event::PcWriteRetired => 0x0c,
+            Event::BrImmedRetired => 0x0d,
+            Event::BrReturnRetired => 0x0e,
+            Event::UnalignedLdStRetired => 0x0f,
+            Event::BrMisPred => 0x10,
+            Event::CpuCycles => 0x11,
+            Event::BrPred => 0x12,
+            Event::MemAccess => 0x13,
+            Event::L1ICache => 0x14,
+            Event::L1DCacheWb => 0x15,
+            Event::L2DCache => 0x16,
+            Event::L2DCacheRefill => 0x17,
+            Event::L2DCacheWb => 0x18,
+            Event::BusAccess => 0x19,
+            Event::MemoryError => 0x1a,
+            Event::InstSpec => 0x1b,
+            Event::TtbrWriteRetired => 0x1c,
+            Event::BusCycles => 0x1d,
+            Event::Chain => 0x1e,
+            Event::L1DCacheAllocate => 0x1f,
+            Event::L2DCacheAllocate => 0x20,
+            Event::BrRetired => 0x21,
+            Event::BrMisPredRetired => 0x22,
+            Event::StallFrontend => 0x23,
+            Event::StallBackend => 0x24,
+            Event::Other(n) => n,
+        }
+    }
+
+    /// Returns the event with the event number `n`.
+    pub const fn from_number(n: u16) -> Self {
+        match n {
+            0x00 => Event::SwIncr,
+            0x01 => Event::L1ICacheRefill,
+            0x02 => Event::L1ITlbRefill,
+            0x03 => Event::L1DCacheRefill,
+            0x04 => Event::L1DCache,
+            0x05 => Event::L1DTlbRefill,
+            0x06 => Event::LdRetired,
+            0x07 => Event::StRetired,
+            0x08 => Event::InstRetired,
+            0x09 => Event::ExcTaken,
+            0x0a => Event::ExcReturn,
+            0x0b => Event::CidWriteRetired,
+            0x0c => Event::PcWriteRetired,
+            0x0d => Event::BrImmedRetired,
+            0x0e => Event::BrReturnRetired,
+            0x0f => Event::UnalignedLdStRetired,
+            0x10 => Event::BrMisPred,
+            0x11 => Event::CpuCycles,
+            0x12 => Event::BrPred,
+            0x13 => Event::MemAccess,
+            0x14 => Event::L1ICache,
+            0x15 => Event::L1DCacheWb,
+            0x16 => Event::L2DCache,
+            0x17 => Event::L2DCacheRefill,
+            0x18 => Event::L2DCacheWb,
+            0x19 => Event::BusAccess,
+            0x1a => Event::MemoryError,
+            0x1b => Event::InstSpec,
+            0x1c => Event::TtbrWriteRetired,
+            0x1d => Event::BusCycles,
+            0x1e => Event::Chain,
+            0x1f => Event::L1DCacheAllocate,
+            0x20 => Event::L2DCacheAllocate,
+            0x21 => Event::BrRetired,
+            0x22 => Event::BrMisPredRetired,
+            0x23 => Event::StallFrontend,
+            0x24 => Event::StallBackend,
+            n => Event::Other(n),
+        }
+    }
+}
+
+/// The Exception levels a counter counts in, for the current Security state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    /// Count events at EL0.
+    pub el0: bool,
+    /// Count events at EL1.
+    pub el1: bool,
+    /// Count events at EL2.
+    pub el2: bool,
+}
+
+impl Filter {
+    /// Counts in all Exception levels.
+    pub const ALL: Filter = Filter {
+        el0: true,
+        el1: true,
+        el2: true,
+    };
+
+    /// The `P`, `U` and `NSH` bits, which share their positions between `PMEVTYPER<n>_EL0` and
+    /// `PMCCFILTR_EL0`.
+    const fn bits(self) -> u64 {
+        ((!self.el1 as u64) << 31) | ((!self.el0 as u64) << 30) | ((self.el2 as u64) << 27)
+    }
+}
+
+/// Returns the number of implemented event counters, `PMCR_EL0.N`.
+pub fn num_counters() -> u8 {
+    PMCR_EL0.read(PMCR_EL0::N) as u8
+}
+
+/// Resets all counters and enables counting with 64-bit cycle counter overflow.
+///
+/// Individual counters still need to be enabled.
+pub fn enable() {
+    PMCR_EL0.modify(PMCR_EL0::LC::SET + PMCR_EL0::C::SET + PMCR_EL0::P::SET + PMCR_EL0::E::SET);
+}
+
+/// Disables all counters.
+pub fn disable() {
+    PMCR_EL0.modify(PMCR_EL0::E::CLEAR);
+}
+
+/// The cycle counter, `PMCCNTR_EL0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CycleCounter(());
+
+impl CycleCounter {
+    /// Sets up the cycle counter to count in the Exception levels given by `filter`.
+    pub fn new(filter: Filter) -> Self {
+        PMCCFILTR_EL0.set(filter.bits());
+
+        CycleCounter(())
+    }
+
+    /// Starts counting.
+    pub fn enable(&self) {
+        PMCNTENSET_EL0.write(PMCNTENSET_EL0::C::SET);
+    }
+
+    /// Stops counting.
+    pub fn disable(&self) {
+        PMCNTENCLR_EL0.write(PMCNTENCLR_EL0::C::SET);
+    }
+
+    /// Returns the current cycle count.
+    pub fn read(&self) -> u64 {
+        PMCCNTR_EL0.get()
+    }
+
+    /// Sets the cycle count to `value`.
+    pub fn write(&self, value: u64) {
+        PMCCNTR_EL0.set(value);
+    }
+
+    /// Returns whether the counter overflowed, and clears the overflow flag.
+    pub fn take_overflow(&self) -> bool {
+        let overflow = PMOVSCLR_EL0.is_set(PMOVSCLR_EL0::C);
+        if overflow {
+            PMOVSCLR_EL0.write(PMOVSCLR_EL0::C::SET);
+        }
+
+        overflow
+    }
+
+    /// Enables or disables the overflow interrupt request.
+    pub fn set_interrupt(&self, enable: bool) {
+        if enable {
+            PMINTENSET_EL1.write(PMINTENSET_EL1::C::SET);
+        } else {
+            PMINTENCLR_EL1.write(PMINTENCLR_EL1::C::SET);
+        }
+    }
+}
+
+/// An event counter, `PMEVCNTR<n>_EL0`, together with its event type register.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EventCounter {
+    index: u8,
+}
+
+impl EventCounter {
+    /// Returns event counter `index`, or `None` if it is not implemented.
+    pub fn new(index: u8) -> Option<Self> {
+        if index < num_counters().min(31) {
+            Some(EventCounter { index })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index `<n>` of the counter.
+    pub const fn index(&self) -> u8 {
+        self.index
+    }
+
+    fn mask(&self) -> u64 {
+        1 << self.index
+    }
+
+    /// Sets up the counter to count `event` in the Exception levels given by `filter`.
+    pub fn configure(&self, event: Event, filter: Filter) {
+        pmevtyper_el0(self.index)
+            .set(filter.bits() | PMEVTYPER_EL0::evtCount.val(event.number() as u64).value);
+    }
+
+    /// Returns the event the counter is set up to count.
+    pub fn event(&self) -> Event {
+        Event::from_number(pmevtyper_el0(self.index).read(PMEVTYPER_EL0::evtCount) as u16)
+    }
+
+    /// Starts counting.
+    pub fn enable(&self) {
+        PMCNTENSET_EL0.set(self.mask());
+    }
+
+    /// Stops counting.
+    pub fn disable(&self) {
+        PMCNTENCLR_EL0.set(self.mask());
+    }
+
+    /// Returns the current event count.
+    pub fn read(&self) -> u64 {
+        pmevcntr_el0(self.index).get()
+    }
+
+    /// Sets the event count to `value`.
+    pub fn write(&self, value: u64) {
+        pmevcntr_el0(self.index).set(value);
+    }
+
+    /// Returns whether the counter overflowed, and clears the overflow flag.
+    pub fn take_overflow(&self) -> bool {
+        let overflow = PMOVSCLR_EL0.get() & self.mask() != 0;
+        if overflow {
+            PMOVSCLR_EL0.set(self.mask());
+        }
+
+        overflow
+    }
+
+    /// Enables or disables the overflow interrupt request.
+    pub fn set_interrupt(&self, enable: bool) {
+        if enable {
+            PMINTENSET_EL1.set(self.mask());
+        } else {
+            PMINTENCLR_EL1.set(self.mask());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events() {
+        assert_eq!(Event::CpuCycles.number(), 0x11);
+        assert_eq!(Event::from_number(0x24), Event::StallBackend);
+        assert_eq!(Event::from_number(0x4004), Event::Other(0x4004));
+        assert_eq!(Event::Other(0x11), Event::CpuCycles);
+        assert_ne!(Event::Other(0x12), Event::CpuCycles);
+        assert!(matches!(Event::from(0x11), Event::CpuCycles));
+
+        for n in 0..=0x30 {
+            assert_eq!(Event::from_number(n).number(), n);
+        }
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(Filter::ALL.bits(), 1 << 27);
+        let el0 = Filter {
+            el0: true,
+            el1: false,
+            el2: false,
+        };
+        assert_eq!(el0.bits(), 1 << 31);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn event_counters() {
+        use crate::registers::mock;
+
+        mock::clear();
+        mock::set_reset_value("PMCR_EL0", 6 << 11);
+
+        assert!(EventCounter::new(6).is_none());
+        let counter = EventCounter::new(5).unwrap();
+        counter.configure(Event::InstRetired, Filter::ALL);
+        counter.enable();
+        counter.write(42);
+
+        assert_eq!(mock::peek("PMEVTYPER5_EL0"), (1 << 27) | 0x08);
+        assert_eq!(mock::peek("PMCNTENSET_EL0"), 1 << 5);
+        assert_eq!(counter.event(), Event::InstRetired);
+        assert_eq!(counter.read(), 42);
+
+        mock::poke("PMOVSCLR_EL0", 1 << 5);
+        assert!(counter.take_overflow());
+    }
+}
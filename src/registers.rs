@@ -5,9 +5,18 @@
 #[macro_use]
 mod macros;
 
+#[macro_use]
+pub mod esr;
+pub mod metadata;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod pstate;
+mod sys_reg;
+
 mod actlr_el1;
 mod actlr_el2;
 mod actlr_el3;
+mod allint;
 mod apdakeyhi_el1;
 mod apdakeylo_el1;
 mod apdbkeyhi_el1;
@@ -23,65 +32,93 @@ mod clidr_el1;
 mod cntfrq_el0;
 mod cnthctl_el2;
 mod cnthp_ctl_el2;
+mod cnthp_cval_el2;
+mod cnthp_tval_el2;
+mod cnthv_ctl_el2;
+mod cnthv_cval_el2;
+mod cnthv_tval_el2;
+mod cntiscale_el2;
 mod cntkctl_el1;
+mod cntkctl_el12;
 mod cntp_ctl_el0;
+mod cntp_ctl_el02;
 mod cntp_cval_el0;
+mod cntp_cval_el02;
 mod cntp_tval_el0;
+mod cntp_tval_el02;
 mod cntpct_el0;
+mod cntpctss_el0;
 mod cntpoff_el2;
+mod cntps_ctl_el1;
+mod cntps_cval_el1;
+mod cntps_tval_el1;
+mod cntscale_el2;
 mod cntv_ctl_el0;
+mod cntv_ctl_el02;
 mod cntv_cval_el0;
+mod cntv_cval_el02;
 mod cntv_tval_el0;
+mod cntv_tval_el02;
 mod cntvct_el0;
+mod cntvctss_el0;
+mod cntvfrq_el2;
 mod cntvoff_el2;
+mod contextidr_el1;
+mod contextidr_el12;
+mod contextidr_el2;
 mod cpacr_el1;
+mod cpacr_el12;
 mod cptr_el2;
 mod csselr_el1;
+mod ctr_el0;
 mod currentel;
 mod dacr32_el2;
 mod daif;
 mod dbgdtr_el0;
 mod dbgdtrrx_el0;
 mod dbgdtrtx_el0;
+mod dit;
 mod elr_el1;
+mod elr_el12;
 mod elr_el2;
 mod elr_el3;
 mod esr_el1;
+mod esr_el12;
 mod esr_el2;
 mod esr_el3;
 mod far_el1;
+mod far_el12;
 mod far_el2;
 mod far_el3;
 mod fp;
 mod hcr_el2;
+mod hcrx_el2;
 mod hpfar_el2;
+mod icc_asgi1r_el1;
+mod icc_bpr0_el1;
+mod icc_bpr1_el1;
 mod icc_ctlr_el1;
+mod icc_ctlr_el3;
+mod icc_dir_el1;
+mod icc_eoir0_el1;
+mod icc_eoir1_el1;
+mod icc_hppir0_el1;
+mod icc_hppir1_el1;
+mod icc_iar0_el1;
+mod icc_iar1_el1;
+mod icc_igrpen0_el1;
+mod icc_igrpen1_el1;
+mod icc_nmiar1_el1;
+mod icc_pmr_el1;
+mod icc_rpr_el1;
+mod icc_sgi0r_el1;
+mod icc_sgi1r_el1;
+mod icc_sre_el1;
 mod icc_sre_el2;
-mod ich_ap0r0_el2;
-mod ich_ap0r1_el2;
-mod ich_ap0r2_el2;
-mod ich_ap0r3_el2;
-mod ich_ap1r0_el2;
-mod ich_ap1r1_el2;
-mod ich_ap1r2_el2;
-mod ich_ap1r3_el2;
+mod icc_sre_el3;
+mod ich_apr_el2;
 mod ich_hcr_el2;
-mod ich_lr0_el2;
-mod ich_lr10_el2;
-mod ich_lr11_el2;
-mod ich_lr12_el2;
-mod ich_lr13_el2;
-mod ich_lr14_el2;
-mod ich_lr15_el2;
-mod ich_lr1_el2;
-mod ich_lr2_el2;
-mod ich_lr3_el2;
-mod ich_lr4_el2;
-mod ich_lr5_el2;
-mod ich_lr6_el2;
-mod ich_lr7_el2;
-mod ich_lr8_el2;
-mod ich_lr9_el2;
+mod ich_lr_el2;
 mod ich_misr_el2;
 mod ich_vmcr_el2;
 mod ich_vtr_el2;
@@ -91,24 +128,44 @@ mod id_aa64dfr0_el1;
 mod id_aa64dfr1_el1;
 mod id_aa64isar0_el1;
 mod id_aa64isar1_el1;
+mod id_aa64isar2_el1;
 mod id_aa64mmfr0_el1;
 mod id_aa64mmfr1_el1;
 mod id_aa64mmfr2_el1;
 mod id_aa64pfr0_el1;
 mod id_aa64pfr1_el1;
+mod id_aa64smfr0_el1;
+mod id_aa64zfr0_el1;
 mod lr;
 mod mair_el1;
+mod mair_el12;
 mod mair_el2;
 mod mdccsr_el0;
 mod midr_el1;
 mod mpidr_el1;
+mod nzcv;
 mod oslar_el1;
+mod pan;
 mod par_el1;
+mod pmccfiltr_el0;
+mod pmccntr_el0;
+mod pmcntenclr_el0;
+mod pmcntenset_el0;
+mod pmcr_el0;
+mod pmevcntr_el0;
+mod pmevtyper_el0;
+mod pmintenclr_el1;
+mod pmintenset_el1;
+mod pmovsclr_el0;
+mod pmovsset_el0;
+mod pmselr_el0;
+mod pmuserenr_el0;
 mod rvbar_el1;
 mod rvbar_el2;
 mod rvbar_el3;
 mod scr_el3;
 mod sctlr_el1;
+mod sctlr_el12;
 mod sctlr_el2;
 mod sctlr_el3;
 mod sp;
@@ -116,18 +173,28 @@ mod sp_el0;
 mod sp_el1;
 mod spsel;
 mod spsr_el1;
+mod spsr_el12;
 mod spsr_el2;
 mod spsr_el3;
+mod ssbs;
+mod svcr;
+mod tco;
 mod tcr_el1;
+mod tcr_el12;
 mod tcr_el2;
 mod tpidr_el0;
 mod tpidr_el1;
 mod tpidr_el2;
 mod tpidrro_el0;
 mod ttbr0_el1;
+mod ttbr0_el12;
 mod ttbr0_el2;
 mod ttbr1_el1;
+mod ttbr1_el12;
+mod ttbr1_el2;
+mod uao;
 mod vbar_el1;
+mod vbar_el12;
 mod vbar_el2;
 mod vbar_el3;
 mod vtcr_el2;
@@ -136,6 +203,7 @@ mod vttbr_el2;
 pub use actlr_el1::ACTLR_EL1;
 pub use actlr_el2::ACTLR_EL2;
 pub use actlr_el3::ACTLR_EL3;
+pub use allint::ALLINT;
 pub use apdakeyhi_el1::APDAKEYHI_EL1;
 pub use apdakeylo_el1::APDAKEYLO_EL1;
 pub use apdbkeyhi_el1::APDBKEYHI_EL1;
@@ -151,65 +219,100 @@ pub use clidr_el1::CLIDR_EL1;
 pub use cntfrq_el0::CNTFRQ_EL0;
 pub use cnthctl_el2::CNTHCTL_EL2;
 pub use cnthp_ctl_el2::CNTHP_CTL_EL2;
+pub use cnthp_cval_el2::CNTHP_CVAL_EL2;
+pub use cnthp_tval_el2::CNTHP_TVAL_EL2;
+pub use cnthv_ctl_el2::CNTHV_CTL_EL2;
+pub use cnthv_cval_el2::CNTHV_CVAL_EL2;
+pub use cnthv_tval_el2::CNTHV_TVAL_EL2;
+pub use cntiscale_el2::CNTISCALE_EL2;
 pub use cntkctl_el1::CNTKCTL_EL1;
+pub use cntkctl_el12::CNTKCTL_EL12;
 pub use cntp_ctl_el0::CNTP_CTL_EL0;
+pub use cntp_ctl_el02::CNTP_CTL_EL02;
 pub use cntp_cval_el0::CNTP_CVAL_EL0;
+pub use cntp_cval_el02::CNTP_CVAL_EL02;
 pub use cntp_tval_el0::CNTP_TVAL_EL0;
+pub use cntp_tval_el02::CNTP_TVAL_EL02;
 pub use cntpct_el0::CNTPCT_EL0;
+pub use cntpctss_el0::CNTPCTSS_EL0;
 pub use cntpoff_el2::CNTPOFF_EL2;
+pub use cntps_ctl_el1::CNTPS_CTL_EL1;
+pub use cntps_cval_el1::CNTPS_CVAL_EL1;
+pub use cntps_tval_el1::CNTPS_TVAL_EL1;
+pub use cntscale_el2::CNTSCALE_EL2;
 pub use cntv_ctl_el0::CNTV_CTL_EL0;
+pub use cntv_ctl_el02::CNTV_CTL_EL02;
 pub use cntv_cval_el0::CNTV_CVAL_EL0;
+pub use cntv_cval_el02::CNTV_CVAL_EL02;
 pub use cntv_tval_el0::CNTV_TVAL_EL0;
+pub use cntv_tval_el02::CNTV_TVAL_EL02;
 pub use cntvct_el0::CNTVCT_EL0;
+pub use cntvctss_el0::CNTVCTSS_EL0;
+pub use cntvfrq_el2::CNTVFRQ_EL2;
 pub use cntvoff_el2::CNTVOFF_EL2;
+pub use contextidr_el1::CONTEXTIDR_EL1;
+pub use contextidr_el12::CONTEXTIDR_EL12;
+pub use contextidr_el2::CONTEXTIDR_EL2;
 pub use cpacr_el1::CPACR_EL1;
+pub use cpacr_el12::CPACR_EL12;
 pub use cptr_el2::CPTR_EL2;
 pub use csselr_el1::CSSELR_EL1;
+pub use ctr_el0::CTR_EL0;
 pub use currentel::CurrentEL;
 pub use dacr32_el2::DACR32_EL2;
 pub use daif::DAIF;
 pub use dbgdtr_el0::DBGDTR_EL0;
 pub use dbgdtrrx_el0::DBGDTRRX_EL0;
 pub use dbgdtrtx_el0::DBGDTRTX_EL0;
+pub use dit::DIT;
 pub use elr_el1::ELR_EL1;
+pub use elr_el12::ELR_EL12;
 pub use elr_el2::ELR_EL2;
 pub use elr_el3::ELR_EL3;
 pub use esr_el1::ESR_EL1;
+pub use esr_el12::ESR_EL12;
 pub use esr_el2::ESR_EL2;
 pub use esr_el3::ESR_EL3;
 pub use far_el1::FAR_EL1;
+pub use far_el12::FAR_EL12;
 pub use far_el2::FAR_EL2;
 pub use far_el3::FAR_EL3;
 pub use fp::FP;
 pub use hcr_el2::HCR_EL2;
+pub use hcrx_el2::HCRX_EL2;
 pub use hpfar_el2::HPFAR_EL2;
+pub use icc_asgi1r_el1::ICC_ASGI1R_EL1;
+pub use icc_bpr0_el1::ICC_BPR0_EL1;
+pub use icc_bpr1_el1::ICC_BPR1_EL1;
 pub use icc_ctlr_el1::ICC_CTLR_EL1;
+pub use icc_ctlr_el3::ICC_CTLR_EL3;
+pub use icc_dir_el1::ICC_DIR_EL1;
+pub use icc_eoir0_el1::ICC_EOIR0_EL1;
+pub use icc_eoir1_el1::ICC_EOIR1_EL1;
+pub use icc_hppir0_el1::ICC_HPPIR0_EL1;
+pub use icc_hppir1_el1::ICC_HPPIR1_EL1;
+pub use icc_iar0_el1::ICC_IAR0_EL1;
+pub use icc_iar1_el1::ICC_IAR1_EL1;
+pub use icc_igrpen0_el1::ICC_IGRPEN0_EL1;
+pub use icc_igrpen1_el1::ICC_IGRPEN1_EL1;
+pub use icc_nmiar1_el1::ICC_NMIAR1_EL1;
+pub use icc_pmr_el1::ICC_PMR_EL1;
+pub use icc_rpr_el1::ICC_RPR_EL1;
+pub use icc_sgi0r_el1::ICC_SGI0R_EL1;
+pub use icc_sgi1r_el1::ICC_SGI1R_EL1;
+pub use icc_sre_el1::ICC_SRE_EL1;
 pub use icc_sre_el2::ICC_SRE_EL2;
-pub use ich_ap0r0_el2::ICH_AP0R0_EL2;
-pub use ich_ap0r1_el2::ICH_AP0R1_EL2;
-pub use ich_ap0r2_el2::ICH_AP0R2_EL2;
-pub use ich_ap0r3_el2::ICH_AP0R3_EL2;
-pub use ich_ap1r0_el2::ICH_AP1R0_EL2;
-pub use ich_ap1r1_el2::ICH_AP1R1_EL2;
-pub use ich_ap1r2_el2::ICH_AP1R2_EL2;
-pub use ich_ap1r3_el2::ICH_AP1R3_EL2;
+pub use icc_sre_el3::ICC_SRE_EL3;
+pub use ich_apr_el2::{
+    ichap0r, ichap1r, ICH_AP0R0_EL2, ICH_AP0R1_EL2, ICH_AP0R2_EL2, ICH_AP0R3_EL2, ICH_AP1R0_EL2,
+    ICH_AP1R1_EL2, ICH_AP1R2_EL2, ICH_AP1R3_EL2, ICH_APR_EL2,
+};
 pub use ich_hcr_el2::ICH_HCR_EL2;
-pub use ich_lr0_el2::ICH_LR0_EL2;
-pub use ich_lr10_el2::ICH_LR10_EL2;
-pub use ich_lr11_el2::ICH_LR11_EL2;
-pub use ich_lr12_el2::ICH_LR12_EL2;
-pub use ich_lr13_el2::ICH_LR13_EL2;
-pub use ich_lr14_el2::ICH_LR14_EL2;
-pub use ich_lr15_el2::ICH_LR15_EL2;
-pub use ich_lr1_el2::ICH_LR1_EL2;
-pub use ich_lr2_el2::ICH_LR2_EL2;
-pub use ich_lr3_el2::ICH_LR3_EL2;
-pub use ich_lr4_el2::ICH_LR4_EL2;
-pub use ich_lr5_el2::ICH_LR5_EL2;
-pub use ich_lr6_el2::ICH_LR6_EL2;
-pub use ich_lr7_el2::ICH_LR7_EL2;
-pub use ich_lr8_el2::ICH_LR8_EL2;
-pub use ich_lr9_el2::ICH_LR9_EL2;
+pub use ich_lr_el2::{
+    ichlr, ICH_LR0_EL2, ICH_LR10_EL2, ICH_LR11_EL2, ICH_LR12_EL2, ICH_LR13_EL2, ICH_LR14_EL2,
+    ICH_LR15_EL2, ICH_LR1_EL2, ICH_LR2_EL2, ICH_LR3_EL2, ICH_LR4_EL2, ICH_LR5_EL2, ICH_LR6_EL2,
+    ICH_LR7_EL2, ICH_LR8_EL2, ICH_LR9_EL2, ICH_LR_EL2,
+};
 pub use ich_misr_el2::ICH_MISR_EL2;
 pub use ich_vmcr_el2::ICH_VMCR_EL2;
 pub use ich_vtr_el2::ICH_VTR_EL2;
@@ -219,24 +322,44 @@ pub use id_aa64dfr0_el1::ID_AA64DFR0_EL1;
 pub use id_aa64dfr1_el1::ID_AA64DFR1_EL1;
 pub use id_aa64isar0_el1::ID_AA64ISAR0_EL1;
 pub use id_aa64isar1_el1::ID_AA64ISAR1_EL1;
+pub use id_aa64isar2_el1::ID_AA64ISAR2_EL1;
 pub use id_aa64mmfr0_el1::ID_AA64MMFR0_EL1;
 pub use id_aa64mmfr1_el1::ID_AA64MMFR1_EL1;
 pub use id_aa64mmfr2_el1::ID_AA64MMFR2_EL1;
 pub use id_aa64pfr0_el1::ID_AA64PFR0_EL1;
 pub use id_aa64pfr1_el1::ID_AA64PFR1_EL1;
+pub use id_aa64smfr0_el1::ID_AA64SMFR0_EL1;
+pub use id_aa64zfr0_el1::ID_AA64ZFR0_EL1;
 pub use lr::LR;
 pub use mair_el1::MAIR_EL1;
+pub use mair_el12::MAIR_EL12;
 pub use mair_el2::MAIR_EL2;
 pub use mdccsr_el0::MDCCSR_EL0;
 pub use midr_el1::MIDR_EL1;
 pub use mpidr_el1::MPIDR_EL1;
+pub use nzcv::NZCV;
 pub use oslar_el1::OSLAR_EL1;
+pub use pan::PAN;
 pub use par_el1::PAR_EL1;
+pub use pmccfiltr_el0::PMCCFILTR_EL0;
+pub use pmccntr_el0::PMCCNTR_EL0;
+pub use pmcntenclr_el0::PMCNTENCLR_EL0;
+pub use pmcntenset_el0::PMCNTENSET_EL0;
+pub use pmcr_el0::PMCR_EL0;
+pub use pmevcntr_el0::pmevcntr_el0;
+pub use pmevtyper_el0::{pmevtyper_el0, PMEVTYPER_EL0};
+pub use pmintenclr_el1::PMINTENCLR_EL1;
+pub use pmintenset_el1::PMINTENSET_EL1;
+pub use pmovsclr_el0::PMOVSCLR_EL0;
+pub use pmovsset_el0::PMOVSSET_EL0;
+pub use pmselr_el0::PMSELR_EL0;
+pub use pmuserenr_el0::PMUSERENR_EL0;
 pub use rvbar_el1::RVBAR_EL1;
 pub use rvbar_el2::RVBAR_EL2;
 pub use rvbar_el3::RVBAR_EL3;
 pub use scr_el3::SCR_EL3;
 pub use sctlr_el1::SCTLR_EL1;
+pub use sctlr_el12::SCTLR_EL12;
 pub use sctlr_el2::SCTLR_EL2;
 pub use sctlr_el3::SCTLR_EL3;
 pub use sp::SP;
@@ -244,22 +367,37 @@ pub use sp_el0::SP_EL0;
 pub use sp_el1::SP_EL1;
 pub use spsel::SPSel;
 pub use spsr_el1::SPSR_EL1;
+pub use spsr_el12::SPSR_EL12;
 pub use spsr_el2::SPSR_EL2;
 pub use spsr_el3::SPSR_EL3;
+pub use ssbs::SSBS;
+pub use svcr::SVCR;
+pub use tco::TCO;
 pub use tcr_el1::TCR_EL1;
+pub use tcr_el12::TCR_EL12;
 pub use tcr_el2::TCR_EL2;
 pub use tpidr_el0::TPIDR_EL0;
 pub use tpidr_el1::TPIDR_EL1;
 pub use tpidr_el2::TPIDR_EL2;
 pub use tpidrro_el0::TPIDRRO_EL0;
 pub use ttbr0_el1::TTBR0_EL1;
+pub use ttbr0_el12::TTBR0_EL12;
 pub use ttbr0_el2::TTBR0_EL2;
 pub use ttbr1_el1::TTBR1_EL1;
+pub use ttbr1_el12::TTBR1_EL12;
+pub use ttbr1_el2::TTBR1_EL2;
+pub use uao::UAO;
 pub use vbar_el1::VBAR_EL1;
+pub use vbar_el12::VBAR_EL12;
 pub use vbar_el2::VBAR_EL2;
 pub use vbar_el3::VBAR_EL3;
 pub use vtcr_el2::VTCR_EL2;
 pub use vttbr_el2::VTTBR_EL2;
 
+pub use sys_reg::SysReg;
+
 #[doc(inline)]
 pub use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
+
+#[doc(hidden)]
+pub use tock_registers::register_bitfields as __register_bitfields;
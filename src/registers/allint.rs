@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! All Interrupt Mask Bit
+//!
+//! Allows access to the all interrupt mask bit. Requires FEAT_NMI.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub ALLINT [
+        /// All IRQ or FIQ interrupts mask bit. When set, all IRQ and FIQ interrupts to the current
+        /// Exception level are masked, including those with Superpriority.
+        ALLINT OFFSET(13) NUMBITS(1) [
+            Unmasked = 0,
+            Masked = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ALLINT::Register;
+
+    sys_coproc_read_raw!(u64, "ALLINT" = "S3_0_C4_C3_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ALLINT::Register;
+
+    sys_coproc_write_raw!(u64, "ALLINT" = "S3_0_C4_C3_0", "x");
+}
+
+pub const ALLINT: Reg = Reg {};
@@ -9,12 +9,9 @@
 //!
 //! Provides information about the architecture of the currently selected cache.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CCSIDR_EL1 [
         /// Number of sets in cache.
         ///
@@ -53,9 +50,7 @@ register_bitfields! {u64,
 
 #[inline(always)]
 fn has_feature_ccidx() -> bool {
-    use crate::registers::ID_AA64MMFR2_EL1;
-
-    ID_AA64MMFR2_EL1.read(ID_AA64MMFR2_EL1::CCIDX) != 0
+    crate::features::Feature::CCIDX.is_present_on_current_cpu()
 }
 
 pub struct Reg;
@@ -12,12 +12,9 @@
 //! up to a maximum of seven levels. Also identifies the Level of Coherence (LoC) and Level
 //! of Unification (LoU) for the cache hierarchy.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CLIDR_EL1 [
         /// **When FEAT_MTE2 is implemented:**
         ///
@@ -11,17 +11,80 @@
 //! access from Non-secure EL1 to the physical counter and the Non-secure EL1
 //! physical timer.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
 // When HCR_EL2.E2H == 0:
 // TODO: Figure out how we can differentiate depending on HCR_EL2.E2H state
 //
 // For now, implement the HCR_EL2.E2H == 0 version
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CNTHCTL_EL2 [
+        /// Controls the scale of the generation of the event stream. Requires FEAT_ECV.
+        ///
+        /// 0 EVNTI selects a bit of CNTPCT_EL0[15:0] as the trigger.
+        ///
+        /// 1 EVNTI selects a bit of CNTPCT_EL0[23:8] as the trigger.
+        EVNTIS OFFSET(17) NUMBITS(1) [],
+
+        /// Traps EL1 accesses to CNTV_CTL_EL02 and CNTV_CVAL_EL02 to EL2, when HCR_EL2.NV is 1.
+        /// Requires FEAT_ECV.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 The accesses are trapped to EL2.
+        EL1NVVCT OFFSET(16) NUMBITS(1) [],
+
+        /// Traps EL1 accesses to CNTP_CTL_EL02 and CNTP_CVAL_EL02 to EL2, when HCR_EL2.NV is 1.
+        /// Requires FEAT_ECV.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 The accesses are trapped to EL2.
+        EL1NVPCT OFFSET(15) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 accesses to the virtual counter registers CNTVCT_EL0 and
+        /// CNTVCTSS_EL0 to EL2. Requires FEAT_ECV.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 The accesses are trapped to EL2, unless they are trapped by CNTKCTL_EL1.
+        EL1TVCT OFFSET(14) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 accesses to the virtual timer registers CNTV_CTL_EL0, CNTV_CVAL_EL0
+        /// and CNTV_TVAL_EL0 to EL2. Requires FEAT_ECV.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 The accesses are trapped to EL2, unless they are trapped by CNTKCTL_EL1.
+        EL1TVT OFFSET(13) NUMBITS(1) [],
+
+        /// Enables the physical counter offset CNTPOFF_EL2. Requires FEAT_ECV_POFF, i.e.
+        /// ID_AA64MMFR0_EL1.ECV is 0b0010.
+        ///
+        /// 0 CNTPOFF_EL2 is not applied.
+        ///
+        /// 1 The physical count seen by EL1 and EL0 is offset by CNTPOFF_EL2, when HCR_EL2.{E2H,
+        ///   TGE} is not {1, 1}.
+        ECV OFFSET(12) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ],
+
+        /// Selects which bit of CNTPCT_EL0, as seen from EL2, is the trigger for the event stream.
+        EVNTI OFFSET(4) NUMBITS(4) [],
+
+        /// Controls which transition of the trigger bit selected by EVNTI generates an event.
+        EVNTDIR OFFSET(3) NUMBITS(1) [
+            ZeroToOne = 0,
+            OneToZero = 1
+        ],
+
+        /// Enables the generation of an event stream from CNTPCT_EL0, as seen from EL2.
+        EVNTEN OFFSET(2) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ],
+
         /// Traps Non-secure EL0 and EL1 accesses to the physical timer registers to EL2.
         ///
         /// 0 From AArch64 state: Non-secure EL0 and EL1 accesses to the CNTP_CTL_EL0,
@@ -9,12 +9,9 @@
 //!
 //! Control register for the EL2 physical timer.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CNTHP_CTL_EL2 [
         /// The status of the timer. This bit indicates whether the timer condition is met:
         ///
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Hypervisor Virtual Timer TimerValue register - EL2
+//!
+//! Holds the timer value for the EL2 virtual timer. Requires FEAT_VHE.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "CNTHV_TVAL_EL2" = "S3_4_C14_C3_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "CNTHV_TVAL_EL2" = "S3_4_C14_C3_0", "x");
+}
+
+pub const CNTHV_TVAL_EL2: Reg = Reg {};
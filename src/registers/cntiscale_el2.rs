@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Inverse Scale register - EL2
+//!
+//! Holds the inverse of the scaling factor in CNTSCALE_EL2, which is used to convert the compare
+//! values of the EL1 timers back to the physical count. Requires FEAT_ECV2.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "CNTISCALE_EL2" = "S3_4_C14_C0_5", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "CNTISCALE_EL2" = "S3_4_C14_C0_5", "x");
+}
+
+pub const CNTISCALE_EL2: Reg = Reg {};
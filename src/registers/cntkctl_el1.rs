@@ -16,18 +16,17 @@
 //! controls the generation of an event stream from the virtual counter, and access from EL0 to the
 //! physical counter, virtual counter, EL1 physical timers, and the virtual timer.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CNTKCTL_EL1 [
         /// Controls the scale of the generation of the event stream.
         ///
+        /// ```text
         ///     0b0 The CNTKCTL_EL1.EVNTI field applies to CNTVCT_EL0[15:0].
         ///
         ///     0b1 The CNTKCTL_EL1.EVNTI field applies to CNTVCT_EL0[23:8].
+        /// ```
         ///
         /// This control applies regardless of the value of the CNTHCTL_EL2.ECV bit.
         ///
@@ -44,16 +43,20 @@ register_bitfields! {u64,
         /// - In AArch64 state, the following registers are trapped, reported using EC syndrome value
         ///   0x18:
         ///
+        /// ```text
         ///     — CNTP_CTL_EL0, CNTP_CVAL_EL0, and CNTP_TVAL_EL0.
+        /// ```
         ///
         /// - In AArch32 state, MRC and MCR accesses to the following registers are trapped, reported
         /// using EC syndrome value 0x03, MRRC and MCRR accesses are trapped, reported using EC
         /// syndrome value 0x04:
         ///     — CNTP_CTL, CNTP_CVAL, CNTP_TVAL.
         ///
+        /// ```text
         ///     0b0 EL0 accesses to the physical timer registers are trapped to EL1.
         ///
         ///     0b1 This control does not cause any instructions to be trapped.
+        /// ```
         ///
         /// When FEAT_VHE is implemented and HCR_EL2.{E2H, TGE} is {1, 1}, this control does not
         /// cause any instructions to be trapped.
@@ -71,17 +74,21 @@ register_bitfields! {u64,
         /// • In AArch64 state, accesses to the following registers are trapped, reported using EC
         /// syndrome value 0x18:
         ///
+        /// ```text
         ///     — CNTV_CTL_EL0, CNTV_CVAL_EL0, and CNTV_TVAL_EL0.
+        /// ```
         ///
         /// • In AArch32 state, MRC and MCR accesses to the following registers are trapped and
         /// reported using EC syndrome value 0x03, MRRC and MCRR accesses are trapped using EC
         /// syndrome value 0x04:
         ///
+        /// ```text
         ///     — CNTV_CTL, CNTV_CVAL, and CNTV_TVAL.
         ///
         ///     0b0 EL0 accesses to the virtual timer registers are trapped.
         ///
         ///     0b1 This control does not cause any instructions to be trapped.
+        /// ```
         ///
         /// When FEAT_VHE is implemented and HCR_EL2.{E2H, TGE} is {1, 1}, this control does not
         /// cause any instructions to be trapped.
@@ -108,9 +115,11 @@ register_bitfields! {u64,
         /// Controls which transition of the CNTVCT_EL0 trigger bit, as seen from EL1 and defined by
         /// EVNTI, generates an event when the event stream is enabled.
         ///
+        /// ```text
         ///     0b0 A 0 to 1 transition of the trigger bit triggers an event.
         ///
         ///     0b1 A 1 to 0 transition of the trigger bit triggers an event.
+        /// ```
         ///
         /// The reset behavior of this field is:
         ///     - On a Warm reset, this field resets to an architecturally UNKNOWN value.
@@ -122,9 +131,11 @@ register_bitfields! {u64,
         /// When FEAT_VHE is not implemented, or when HCR_EL2.{E2H, TGE} is not {1, 1}, enables the
         /// generation of an event stream from CNTVCT_EL0 as seen from EL1.
         ///
+        /// ```text
         ///     0b0 Disables the event stream.
         ///
         ///     0b1 Enables the event stream.
+        /// ```
         ///
         /// When FEAT_VHE is implemented and HCR_EL2.{E2H, TGE} is {1, 1}, this control does not
         /// enable the event stream.
@@ -142,17 +153,21 @@ register_bitfields! {u64,
         /// - In AArch64 state, accesses to the following registers are trapped and reported using EC
         /// syndrome value 0x18:
         ///
+        /// ```text
         ///     — CNTVCT_EL0 and if CNTKCTL_EL1.EL0PCTEN is 0, CNTFRQ_EL0.
+        /// ```
         ///
         /// - In AArch32 state, MRC and MCR accesses to the following registers are trapped and
         /// reported using EC syndrome value 0x03, MRRC and MCRR accesses are trapped and
         /// reported using EC syndrome value 0x04:
         ///
+        /// ```text
         ///     — CNTVCT and if CNTKCTL_EL1.EL0PCTEN is 0, CNTFRQ.
         ///
         ///     0b0 EL0 accesses to the frequency register and virtual counter registers are trapped.
         ///
         ///     0b1 This control does not cause any instructions to be trapped.
+        /// ```
         ///
         /// When FEAT_VHE is implemented and HCR_EL2.{E2H, TGE} is {1, 1}, this control does not
         /// cause any instructions to be trapped.
@@ -170,17 +185,21 @@ register_bitfields! {u64,
         /// - In AArch64 state, the following registers are trapped, reported using EC syndrome value
         /// 0x18:
         ///
+        /// ```text
         ///     — CNTPCT_EL0 and if CNTKCTL_EL1.EL0VCTEN is 0, CNTFRQ_EL0.
+        /// ```
         ///
         /// - In AArch32 state, MCR or MRC accesses the following registers are trapped, reported using
         /// EC syndrome value 0x03, MCRR or MRRC accesses are trapped and reported using EC
         /// syndrome value 0x04:
         ///
+        /// ```text
         ///     — CNTPCT and if CNTKCTL_EL1.EL0VCTEN is 0, CNTFRQ.
         ///
         ///     0b0 EL0 accesses to the frequency register and physical counter register are trapped.
         ///
         ///     0b1 This control does not cause any instructions to be trapped.
+        /// ```
         ///
         /// When FEAT_VHE is implemented and HCR_EL2.{E2H, TGE} is {1, 1}, this control does not
         /// cause any instructions to be trapped.
@@ -19,14 +19,14 @@ impl Readable for Reg {
     type T = u64;
     type R = ();
 
-    sys_coproc_read_raw!(u64, "CNTPOFF_EL2", "x");
+    sys_coproc_read_raw!(u64, "CNTPOFF_EL2" = "S3_4_C14_C0_6", "x");
 }
 
 impl Writeable for Reg {
     type T = u64;
     type R = ();
 
-    sys_coproc_write_raw!(u64, "CNTPOFF_EL2", "x");
+    sys_coproc_write_raw!(u64, "CNTPOFF_EL2" = "S3_4_C14_C0_6", "x");
 }
 
 pub const CNTPOFF_EL2: Reg = Reg {};
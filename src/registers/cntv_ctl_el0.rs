@@ -10,12 +10,9 @@
 //!
 //! Control register for the virtual timer
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CNTV_CTL_EL0 [
         /// The status of the timer. This bit indicates whether the timer condition is met:
         ///
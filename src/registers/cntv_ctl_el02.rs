@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Virtual Timer Control register - EL02
+//!
+//! Accesses CNTV_CTL_EL0 from EL2 when HCR_EL2.E2H is 1, in which case CNTV_CTL_EL0 accesses its
+//! EL2 counterpart. Uses the CNTV_CTL_EL0 layout. Requires FEAT_VHE.
+
+use crate::registers::CNTV_CTL_EL0;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CNTV_CTL_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "CNTV_CTL_EL02" = "S3_5_C14_C3_1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = CNTV_CTL_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "CNTV_CTL_EL02" = "S3_5_C14_C3_1", "x");
+}
+
+pub const CNTV_CTL_EL02: Reg = Reg {};
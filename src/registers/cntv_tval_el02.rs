@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Virtual Timer TimerValue register - EL02
+//!
+//! Accesses CNTV_TVAL_EL0 from EL2 when HCR_EL2.E2H is 1, in which case CNTV_TVAL_EL0 accesses its
+//! EL2 counterpart. Requires FEAT_VHE.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "CNTV_TVAL_EL02" = "S3_5_C14_C3_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "CNTV_TVAL_EL02" = "S3_5_C14_C3_0", "x");
+}
+
+pub const CNTV_TVAL_EL02: Reg = Reg {};
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Self-Synchronized Virtual Count register - EL0
+//!
+//! Holds the 64-bit virtual count value. Unlike `CNTVCT_EL0`, reads are not speculated or
+//! reordered relative to other instructions, so no `ISB` is needed before them. Requires FEAT_ECV.
+
+use tock_registers::interfaces::Readable;
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "CNTVCTSS_EL0" = "S3_3_C14_C0_6", "x");
+}
+
+pub const CNTVCTSS_EL0: Reg = Reg {};
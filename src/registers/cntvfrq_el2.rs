@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Virtual Frequency register - EL2
+//!
+//! Holds the frequency of the scaled counter, which is returned by reads of CNTFRQ_EL0 from EL1 and
+//! EL0 when counter scaling is enabled. Requires FEAT_ECV2.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "CNTVFRQ_EL2" = "S3_4_C14_C0_7", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "CNTVFRQ_EL2" = "S3_4_C14_C0_7", "x");
+}
+
+pub const CNTVFRQ_EL2: Reg = Reg {};
@@ -9,12 +9,9 @@
 //!
 //! Controls access to trace, SVE, and Advanced SIMD and floating-point functionality.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CPACR_EL1 [
         /// Traps EL0 and EL1 System register accesses to all implemented trace
         /// registers from both Execution states to EL1, or to EL2 when it is
@@ -10,12 +10,9 @@
 //! Controls trapping to EL2 of accesses to CPACR, CPACR_EL1, trace, Activity Monitor, SME,
 //! Streaming SVE, SVE, and Advanced SIMD and floating-point functionality.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CPTR_EL2 [
         /// Trap Activity Monitor access. Traps EL1 and EL0 accesses to all Activity Monitor
         /// registers to EL2.
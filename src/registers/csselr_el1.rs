@@ -10,12 +10,9 @@
 //! Selects the current Cache Size ID Register, CCSIDR_EL1, by specifying the
 //! required cache level and the cache type (either instruction or data cache).
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CSSELR_EL1 [
         /// ** When `FEAT_MTE2` is implemented:**
         ///
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Cache Type Register - EL0
+//!
+//! Provides information about the architecture of the caches.
+
+use tock_registers::interfaces::Readable;
+
+described_bitfields! {u64,
+    pub CTR_EL0 [
+        /// Tag minimum Line. Log2 of the number of words covered by Allocation Tags in the
+        /// smallest cache line of all caches which can contain Allocation tags that are controlled
+        /// by the PE.
+        TminLine OFFSET(32) NUMBITS(6) [],
+
+        /// Reserved, RES1.
+        RES1 OFFSET(31) NUMBITS(1) [],
+
+        /// Instruction cache invalidation requirements for data to instruction coherence.
+        DIC OFFSET(29) NUMBITS(1) [
+            /// Instruction cache invalidation to the Point of Unification is required for data to
+            /// instruction coherence.
+            Required = 0,
+
+            /// Instruction cache invalidation to the Point of Unification is not required for data
+            /// to instruction coherence.
+            NotRequired = 1
+        ],
+
+        /// Data cache clean requirements for instruction to data coherence.
+        IDC OFFSET(28) NUMBITS(1) [
+            /// Data cache clean to the Point of Unification is required for instruction to data
+            /// coherence, unless CLIDR_EL1.LoC == 0b000 or (CLIDR_EL1.LoUIS == 0b000 &&
+            /// CLIDR_EL1.LoUU == 0b000).
+            Required = 0,
+
+            /// Data cache clean to the Point of Unification is not required for instruction to
+            /// data coherence.
+            NotRequired = 1
+        ],
+
+        /// Cache writeback granule. Log2 of the number of words of the maximum size of memory
+        /// that can be overwritten as a result of the eviction of a cache entry that has had a
+        /// memory location in it modified.
+        ///
+        /// A value of 0b0000 indicates that this register does not provide Cache writeback
+        /// granule information.
+        CWG OFFSET(24) NUMBITS(4) [],
+
+        /// Exclusives reservation granule. Log2 of the number of words of the maximum size of the
+        /// reservation granule that has been implemented for the Load-Exclusive and
+        /// Store-Exclusive instructions.
+        ///
+        /// A value of 0b0000 indicates that this register does not provide Exclusives reservation
+        /// granule information.
+        ERG OFFSET(20) NUMBITS(4) [],
+
+        /// Log2 of the number of words in the smallest cache line of all the data caches and
+        /// unified caches that are controlled by the PE.
+        DminLine OFFSET(16) NUMBITS(4) [],
+
+        /// Level 1 instruction cache policy. Indicates the indexing and tagging policy for the L1
+        /// instruction cache.
+        L1Ip OFFSET(14) NUMBITS(2) [
+            VPIPT = 0b00,
+            AIVIVT = 0b01,
+            VIPT = 0b10,
+            PIPT = 0b11
+        ],
+
+        /// Log2 of the number of words in the smallest cache line of all the instruction caches
+        /// that are controlled by the PE.
+        IminLine OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Reg {
+    /// Size in bytes of the smallest data or unified cache line, as indicated by `DminLine`.
+    #[inline(always)]
+    pub fn dcache_line_size(&self) -> usize {
+        4 << self.read(CTR_EL0::DminLine)
+    }
+
+    /// Size in bytes of the smallest instruction cache line, as indicated by `IminLine`.
+    #[inline(always)]
+    pub fn icache_line_size(&self) -> usize {
+        4 << self.read(CTR_EL0::IminLine)
+    }
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CTR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "CTR_EL0", "x");
+}
+
+pub const CTR_EL0: Reg = Reg {};
@@ -9,9 +9,9 @@
 //!
 //! Holds the current Exception level.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub CurrentEL [
         /// Current Exception level. Possible values of this field are:
         ///
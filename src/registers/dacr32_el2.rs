@@ -10,12 +10,9 @@
 //! Allows access to the AArch32 DACR register from AArch64 state only. Its value
 //! has no effect on execution in AArch64 state.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub DACR32_EL2 [
         /// Domain 15 access permission.
         ///
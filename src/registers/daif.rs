@@ -9,12 +9,9 @@
 //!
 //! Allows access to the interrupt mask bits.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub DAIF [
         /// Process state D mask. The possible values of this bit are:
         ///
@@ -10,12 +10,9 @@
 //! Transfers 64 bits of data between the PE and an external debugger. Can transfer both ways using
 //! only a single register.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub DBGDTR_EL0 [
         /// Writes to this register set DTRRX to the value in this field and do not change RXfull.
         ///
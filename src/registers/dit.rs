@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Data Independent Timing
+//!
+//! Allows access to the Data Independent Timing bit. Requires FEAT_DIT.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub DIT [
+        /// Data Independent Timing.
+        ///
+        /// When set, the timing of the instructions listed by the architecture is independent of the
+        /// values of the data supplied in any of their registers, and of the values of the NZCV flags.
+        DIT OFFSET(24) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = DIT::Register;
+
+    sys_coproc_read_raw!(u64, "DIT" = "S3_3_C4_C2_5", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = DIT::Register;
+
+    sys_coproc_write_raw!(u64, "DIT" = "S3_3_C4_C2_5", "x");
+}
+
+pub const DIT: Reg = Reg {};
@@ -0,0 +1,904 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Exception syndrome decoding
+//!
+//! Typed decoding of the values held by [`ESR_EL1`](const@crate::registers::ESR_EL1),
+//! [`ESR_EL2`](const@crate::registers::ESR_EL2) and
+//! [`ESR_EL3`](const@crate::registers::ESR_EL3). All three registers share the same layout, so a
+//! [`Syndrome`] can be built from the raw value of any of them.
+//!
+//! # Example
+//!
+//! ```
+//! use aarch64_cpu::registers::esr::{FaultStatus, Syndrome};
+//!
+//! match Syndrome::from_esr(0x9600_0045) {
+//!     Syndrome::DataAbortCurrentEL(abort) => {
+//!         assert!(abort.wnr);
+//!         assert_eq!(abort.dfsc, FaultStatus::Translation { level: 1 });
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+/// Invokes `$callback!` with `$args`, followed by the Exception Classes that are reported in all
+/// ESRs and those that are only reported in `ESR_EL3`, as two lists of `Name = value,` entries.
+macro_rules! with_exception_classes {
+    ($callback:ident!($($args:tt)*)) => {
+        $callback!(
+            $($args)*
+            [
+                /// Unknown reason.
+                Unknown = 0b00_0000,
+                /// Trapped WF* instruction execution.
+                TrappedWFIorWFE = 0b00_0001,
+                /// Trapped MCR or MRC access with (coproc==0b1111) that is not reported using EC
+                /// 0b000000.
+                TrappedMCRorMRC = 0b00_0011,
+                /// Trapped MCRR or MRRC access with (coproc==0b1111) that is not reported using EC
+                /// 0b000000.
+                TrappedMCRRorMRRC = 0b00_0100,
+                /// Trapped MCR or MRC access with (coproc==0b1110).
+                TrappedMCRorMRC2 = 0b00_0101,
+                /// Trapped LDC or STC access.
+                TrappedLDCorSTC = 0b00_0110,
+                /// Access to SME, SVE, Advanced SIMD or floating-point functionality trapped by
+                /// CPACR_EL1.FPEN, CPTR_EL2.FPEN, CPTR_EL2.TFP, or CPTR_EL3.TFP control.
+                TrappedFP = 0b00_0111,
+                /// Trapped use of a Pointer authentication instruction because HCR_EL2.API == 0 ||
+                /// SCR_EL3.API == 0.
+                TrappedPointerAuth = 0b00_1001,
+                /// Exception from an access to a LD64B or ST64B* instruction.
+                LD64BorST64B = 0b00_1010,
+                /// Trapped MRRC access with (coproc==0b1110).
+                TrappedMRRC = 0b00_1100,
+                /// Branch Target Exception.
+                BranchTarget = 0b00_1101,
+                /// Illegal Execution state.
+                IllegalExecutionState = 0b00_1110,
+                /// SVC instruction execution in AArch32 state.
+                SVC32 = 0b01_0001,
+                /// HVC instruction execution in AArch32 state, when HVC is not disabled.
+                HVC32 = 0b01_0010,
+                /// SMC instruction execution in AArch32 state, when SMC is not disabled.
+                SMC32 = 0b01_0011,
+                /// SVC instruction execution in AArch64 state.
+                SVC64 = 0b01_0101,
+                /// HVC instruction execution in AArch64 state, when HVC is not disabled.
+                HVC64 = 0b01_0110,
+                /// SMC instruction execution in AArch64 state, when SMC is not disabled.
+                SMC64 = 0b01_0111,
+                /// Trapped MSR, MRS or System instruction execution in AArch64 state, that is not
+                /// reported using EC 0b000000, 0b000001, or 0b000111.
+                TrappedMsrMrs = 0b01_1000,
+                /// Access to SVE functionality trapped as a result of CPACR_EL1.ZEN, CPTR_EL2.ZEN,
+                /// CPTR_EL2.TZ, or CPTR_EL3.EZ, that is not reported using EC 0b000000.
+                TrappedSve = 0b01_1001,
+                /// Trapped ERET, ERETAA, or ERETAB instruction execution.
+                TrappedERET = 0b01_1010,
+                /// Exception from an access to a TSTART instruction at EL0 when SCTLR_EL1.TME0 ==
+                /// 0, EL0 when SCTLR_EL2.TME0 == 0, at EL1 when SCTLR_EL1.TME == 0, at EL2 when
+                /// SCTLR_EL2.TME == 0 or at EL3 when SCTLR_EL3.TME == 0.
+                TSTART = 0b01_1011,
+                /// Exception from a Pointer Authentication instruction authentication failure.
+                PointerAuth = 0b01_1100,
+                /// Access to SME functionality trapped as a result of CPACR_EL1.SMEN,
+                /// CPTR_EL2.SMEN, CPTR_EL2.TSM, CPTR_EL3.ESM, or an attempted execution of an
+                /// instruction that is illegal because of the value of PSTATE.SM or PSTATE.ZA, that
+                /// is not reported using EC 0b000000.
+                TrappedSME = 0b01_1101,
+                /// Exception from a Granule Protection Check.
+                GranuleProtection = 0b01_1110,
+                /// Instruction Abort from a lower Exception level.
+                InstrAbortLowerEL = 0b10_0000,
+                /// Instruction Abort taken without a change in Exception level.
+                InstrAbortCurrentEL = 0b10_0001,
+                /// PC alignment fault exception.
+                PCAlignmentFault = 0b10_0010,
+                /// Data Abort exception from a lower Exception level.
+                DataAbortLowerEL = 0b10_0100,
+                /// Data Abort exception without a change in Exception level.
+                DataAbortCurrentEL = 0b10_0101,
+                /// SP alignment fault exception.
+                SPAlignmentFault = 0b10_0110,
+                /// Memory Operation Exception.
+                MemoryOperation = 0b10_0111,
+                /// Trapped floating-point exception taken from AArch32 state.
+                TrappedFP32 = 0b10_1000,
+                /// Trapped floating-point exception taken from AArch64 state.
+                TrappedFP64 = 0b10_1100,
+                /// GCS exception.
+                GuardedControlStack = 0b10_1101,
+                /// SError exception.
+                SError = 0b10_1111,
+                /// Breakpoint exception from a lower Exception level.
+                BreakpointLowerEL = 0b11_0000,
+                /// Breakpoint exception taken without a change in Exception level.
+                BreakpointCurrentEL = 0b11_0001,
+                /// Software Step exception from a lower Exception level.
+                SoftwareStepLowerEL = 0b11_0010,
+                /// Software Step exception taken without a change in Exception level.
+                SoftwareStepCurrentEL = 0b11_0011,
+                /// Watchpoint exception from a lower Exception level.
+                WatchpointLowerEL = 0b11_0100,
+                /// Watchpoint exception taken without a change in Exception level.
+                WatchpointCurrentEL = 0b11_0101,
+                /// BKPT instruction execution in AArch32 state.
+                Bkpt32 = 0b11_1000,
+                /// Vector Catch exception from AArch32 state.
+                VectorCatch32 = 0b11_1010,
+                /// BRK instruction execution in AArch64 state.
+                Brk64 = 0b11_1100,
+                /// Profiling exception.
+                Profiling = 0b11_1101,
+            ]
+            [
+                /// IMPLEMENTATION DEFINED exception to EL3.
+                ImplDefined = 0b01_1111,
+            ]
+        );
+    };
+}
+
+/// Defines the bitfields `$reg` of an ESR, consisting of `$fields` and an `EC` field documented by
+/// `$ec`. With `el3`, `EC` also lists the Exception Classes that are only reported in `ESR_EL3`.
+macro_rules! esr_bitfields {
+    (@define $reg:ident, [$(#[$ec:meta])*], [$($fields:tt)*], [$($classes:tt)*]) => {
+        described_bitfields! {u64,
+            pub $reg [
+                $(#[$ec])*
+                EC OFFSET(26) NUMBITS(6) [
+                    $($classes)*
+                ],
+
+                $($fields)*
+            ]
+        }
+    };
+    (el3 $reg:ident, $ec:tt, $fields:tt, [$($common:tt)*] [$($el3:tt)*]) => {
+        esr_bitfields!(@define $reg, $ec, $fields, [$($common)* $($el3)*]);
+    };
+    ($reg:ident, $ec:tt, $fields:tt, $common:tt $el3:tt) => {
+        esr_bitfields!(@define $reg, $ec, $fields, $common);
+    };
+}
+
+/// Defines [`ExceptionClass`] with all Exception Classes.
+macro_rules! exception_class {
+    ([$($common:tt)*] [$($el3:tt)*]) => {
+        exception_class!(@define $($common)* $($el3)*);
+    };
+    (@define $($(#[$doc:meta])* $name:ident = $value:literal,)*) => {
+        /// Exception Class. Indicates the reason for the exception that an ESR holds information
+        /// about.
+        ///
+        /// Unused values in the range 0b000000 - 0b101100 are reserved for future use for
+        /// synchronous exceptions. Unused values in the range 0b101101 - 0b111111 are reserved for
+        /// future use, and might be used for synchronous or asynchronous exceptions.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #[repr(u8)]
+        pub enum ExceptionClass {
+            $(
+                $(#[$doc])*
+                $name = $value,
+            )*
+        }
+
+        impl ExceptionClass {
+            /// Converts a 6-bit `EC` value. Returns `None` for reserved values.
+            pub const fn from_bits(ec: u8) -> Option<Self> {
+                match ec {
+                    $($value => Some(ExceptionClass::$name),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+with_exception_classes!(exception_class!());
+
+impl ExceptionClass {
+    /// Decodes the `EC` field of a raw ESR value. Returns `None` for reserved values.
+    pub const fn from_esr(esr: u64) -> Option<Self> {
+        Self::from_bits(((esr >> 26) & 0x3f) as u8)
+    }
+}
+
+/// Fault status code, as reported in the `DFSC` and `IFSC` fields of the ISS and in
+/// `PAR_EL1.FST`.
+///
+/// Translation table levels are reported as `-1..=3`. Level -1 is only used when FEAT_LPA2 is
+/// implemented.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FaultStatus {
+    /// Address size fault.
+    AddressSize { level: i8 },
+    /// Translation fault.
+    Translation { level: i8 },
+    /// Access flag fault.
+    AccessFlag { level: i8 },
+    /// Permission fault.
+    Permission { level: i8 },
+    /// Synchronous External abort, not on translation table walk or hardware update of
+    /// translation table.
+    SyncExternal,
+    /// Synchronous Tag Check Fault.
+    SyncTagCheck,
+    /// Synchronous External abort on translation table walk or hardware update of translation
+    /// table.
+    SyncExternalOnWalk { level: i8 },
+    /// Synchronous parity or ECC error on memory access, not on translation table walk.
+    SyncParity,
+    /// Synchronous parity or ECC error on memory access on translation table walk or hardware
+    /// update of translation table.
+    SyncParityOnWalk { level: i8 },
+    /// Alignment fault.
+    Alignment,
+    /// Debug exception.
+    Debug,
+    /// Granule Protection Fault on translation table walk or hardware update of translation
+    /// table.
+    GranuleProtectionOnWalk { level: i8 },
+    /// Granule Protection Fault, not on translation table walk or hardware update of translation
+    /// table.
+    GranuleProtection,
+    /// TLB conflict abort.
+    TlbConflict,
+    /// Unsupported atomic hardware update fault.
+    UnsupportedAtomicUpdate,
+    /// IMPLEMENTATION DEFINED fault (Lockdown).
+    ImplDefinedLockdown,
+    /// IMPLEMENTATION DEFINED fault (Unsupported Exclusive or Atomic access).
+    ImplDefinedAtomic,
+    /// Reserved or otherwise unrecognized fault status code.
+    Other(u8),
+}
+
+impl FaultStatus {
+    /// Decodes a 6-bit fault status code.
+    pub const fn from_bits(fsc: u8) -> Self {
+        use FaultStatus::*;
+
+        let fsc = fsc & 0x3f;
+        let level = (fsc & 0b11) as i8;
+
+        match fsc {
+            0b00_0000..=0b00_0011 => AddressSize { level },
+            0b10_1001 => AddressSize { level: -1 },
+            0b00_0100..=0b00_0111 => Translation { level },
+            0b10_1011 => Translation { level: -1 },
+            0b00_1000..=0b00_1011 => AccessFlag { level },
+            0b00_1100..=0b00_1111 => Permission { level },
+            0b01_0000 => SyncExternal,
+            0b01_0001 => SyncTagCheck,
+            0b01_0011 => SyncExternalOnWalk { level: -1 },
+            0b01_0100..=0b01_0111 => SyncExternalOnWalk { level },
+            0b01_1000 => SyncParity,
+            0b01_1011 => SyncParityOnWalk { level: -1 },
+            0b01_1100..=0b01_1111 => SyncParityOnWalk { level },
+            0b10_0001 => Alignment,
+            0b10_0010 => Debug,
+            0b10_0011 => GranuleProtectionOnWalk { level: -1 },
+            0b10_0100..=0b10_0111 => GranuleProtectionOnWalk { level },
+            0b10_1000 => GranuleProtection,
+            0b11_0000 => TlbConflict,
+            0b11_0001 => UnsupportedAtomicUpdate,
+            0b11_0100 => ImplDefinedLockdown,
+            0b11_0101 => ImplDefinedAtomic,
+            _ => Other(fsc),
+        }
+    }
+}
+
+/// Size of the access that caused a Data Abort.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AccessSize {
+    Byte,
+    Halfword,
+    Word,
+    Doubleword,
+}
+
+impl AccessSize {
+    /// Size of the access in bytes.
+    pub const fn bytes(self) -> usize {
+        match self {
+            AccessSize::Byte => 1,
+            AccessSize::Halfword => 2,
+            AccessSize::Word => 4,
+            AccessSize::Doubleword => 8,
+        }
+    }
+}
+
+/// Information about the instruction that caused a Data Abort. Only valid if `ISV` is set.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DataAbortInstr {
+    /// Syndrome Access Size. Indicates the size of the access attempted by the faulting operation.
+    pub sas: AccessSize,
+    /// Syndrome Sign Extend. Indicates whether the data item must be sign extended.
+    pub sse: bool,
+    /// Syndrome Register Transfer. The register number of the Wt/Xt/Rt operand of the faulting
+    /// instruction.
+    pub srt: u8,
+    /// Sixty Four bit general-purpose register transfer. Width of the register accessed by the
+    /// instruction is 64-bit.
+    pub sf: bool,
+    /// Acquire/Release. The instruction did have acquire/release semantics.
+    pub ar: bool,
+}
+
+/// ISS encoding for an exception from a Data Abort.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DataAbort {
+    /// Instruction syndrome, if `ISV` is set.
+    pub isv: Option<DataAbortInstr>,
+    /// The fault came from use of VNCR_EL2 register by EL1 code.
+    pub vncr: bool,
+    /// Synchronous Error Type, if the fault is a synchronous External abort or parity error and
+    /// FEAT_RAS is implemented.
+    pub set: u8,
+    /// FAR not Valid, for a synchronous External abort other than a synchronous External abort on
+    /// a translation table walk.
+    pub fnv: bool,
+    /// External abort type. Can provide an IMPLEMENTATION DEFINED classification of External
+    /// aborts.
+    pub ea: bool,
+    /// Cache maintenance. The Data Abort came from a cache maintenance or address translation
+    /// instruction.
+    pub cm: bool,
+    /// The fault was a stage 2 fault on an access made for a stage 1 translation table walk.
+    pub s1ptw: bool,
+    /// Write not Read. The abort was caused by an instruction writing to a memory location.
+    pub wnr: bool,
+    /// Data Fault Status Code.
+    pub dfsc: FaultStatus,
+}
+
+impl DataAbort {
+    /// Decodes a raw ISS value.
+    pub const fn from_iss(iss: u32) -> Self {
+        let isv = if bit(iss, 24) {
+            Some(DataAbortInstr {
+                sas: match (iss >> 22) & 0b11 {
+                    0b00 => AccessSize::Byte,
+                    0b01 => AccessSize::Halfword,
+                    0b10 => AccessSize::Word,
+                    _ => AccessSize::Doubleword,
+                },
+                sse: bit(iss, 21),
+                srt: ((iss >> 16) & 0x1f) as u8,
+                sf: bit(iss, 15),
+                ar: bit(iss, 14),
+            })
+        } else {
+            None
+        };
+
+        DataAbort {
+            isv,
+            vncr: bit(iss, 13),
+            set: ((iss >> 11) & 0b11) as u8,
+            fnv: bit(iss, 10),
+            ea: bit(iss, 9),
+            cm: bit(iss, 8),
+            s1ptw: bit(iss, 7),
+            wnr: bit(iss, 6),
+            dfsc: FaultStatus::from_bits(iss as u8),
+        }
+    }
+}
+
+/// ISS encoding for an exception from an Instruction Abort.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionAbort {
+    /// Synchronous Error Type, if the fault is a synchronous External abort or parity error and
+    /// FEAT_RAS is implemented.
+    pub set: u8,
+    /// FAR not Valid, for a synchronous External abort other than a synchronous External abort on
+    /// a translation table walk.
+    pub fnv: bool,
+    /// External abort type. Can provide an IMPLEMENTATION DEFINED classification of External
+    /// aborts.
+    pub ea: bool,
+    /// The fault was a stage 2 fault on an access made for a stage 1 translation table walk.
+    pub s1ptw: bool,
+    /// Instruction Fault Status Code.
+    pub ifsc: FaultStatus,
+}
+
+impl InstructionAbort {
+    /// Decodes a raw ISS value.
+    pub const fn from_iss(iss: u32) -> Self {
+        InstructionAbort {
+            set: ((iss >> 11) & 0b11) as u8,
+            fnv: bit(iss, 10),
+            ea: bit(iss, 9),
+            s1ptw: bit(iss, 7),
+            ifsc: FaultStatus::from_bits(iss as u8),
+        }
+    }
+}
+
+/// Direction of a trapped system register access.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Write to System register space. MSR instruction.
+    Write,
+    /// Read from System register space. MRS instruction.
+    Read,
+}
+
+/// ISS encoding for an exception from MSR, MRS, or System instruction execution in AArch64 state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MsrMrs {
+    /// The Op0 value from the issued instruction.
+    pub op0: u8,
+    /// The Op1 value from the issued instruction.
+    pub op1: u8,
+    /// The CRn value from the issued instruction.
+    pub crn: u8,
+    /// The CRm value from the issued instruction.
+    pub crm: u8,
+    /// The Op2 value from the issued instruction.
+    pub op2: u8,
+    /// The Rt value from the issued instruction, the general-purpose register used for the
+    /// transfer.
+    pub rt: u8,
+    /// Indicates the direction of the trapped instruction.
+    pub direction: Direction,
+}
+
+impl MsrMrs {
+    /// Decodes a raw ISS value.
+    pub const fn from_iss(iss: u32) -> Self {
+        MsrMrs {
+            op0: ((iss >> 20) & 0b11) as u8,
+            op2: ((iss >> 17) & 0b111) as u8,
+            op1: ((iss >> 14) & 0b111) as u8,
+            crn: ((iss >> 10) & 0xf) as u8,
+            rt: ((iss >> 5) & 0x1f) as u8,
+            crm: ((iss >> 1) & 0xf) as u8,
+            direction: if bit(iss, 0) {
+                Direction::Read
+            } else {
+                Direction::Write
+            },
+        }
+    }
+
+    /// The `(op0, op1, CRn, CRm, op2)` encoding of the accessed System register.
+    pub const fn encoding(&self) -> (u8, u8, u8, u8, u8) {
+        (self.op0, self.op1, self.crn, self.crm, self.op2)
+    }
+
+    /// The accessed System register, if it is known to this crate.
+    pub fn register(&self) -> Option<&'static super::metadata::RegisterInfo> {
+        super::metadata::lookup(self.encoding(), self.direction == Direction::Read)
+    }
+}
+
+/// Trapped WF* instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WfxInstr {
+    WFI,
+    WFE,
+    WFIT,
+    WFET,
+}
+
+/// ISS encoding for an exception from a WFI or WFE instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Wfx {
+    /// Condition code of the trapped instruction, if valid. Only used for exceptions taken from
+    /// AArch32 state.
+    pub cond: Option<u8>,
+    /// Register number of the timeout operand of a trapped WFIT or WFET, if valid.
+    pub rn: Option<u8>,
+    /// The trapped instruction.
+    pub ti: WfxInstr,
+}
+
+impl Wfx {
+    /// Decodes a raw ISS value.
+    pub const fn from_iss(iss: u32) -> Self {
+        Wfx {
+            cond: if bit(iss, 24) {
+                Some(((iss >> 20) & 0xf) as u8)
+            } else {
+                None
+            },
+            rn: if bit(iss, 2) {
+                Some(((iss >> 5) & 0x1f) as u8)
+            } else {
+                None
+            },
+            ti: match iss & 0b11 {
+                0b00 => WfxInstr::WFI,
+                0b01 => WfxInstr::WFE,
+                0b10 => WfxInstr::WFIT,
+                _ => WfxInstr::WFET,
+            },
+        }
+    }
+}
+
+/// Asynchronous Error Type of an SError.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorType {
+    /// Uncontainable (UC).
+    Uncontainable,
+    /// Unrecoverable state (UEU).
+    Unrecoverable,
+    /// Restartable state (UEO).
+    Restartable,
+    /// Recoverable state (UER).
+    Recoverable,
+    /// Corrected (CE).
+    Corrected,
+    /// Reserved value.
+    Reserved(u8),
+}
+
+/// ISS encoding for an SError exception.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SErrorSyndrome {
+    /// `ISS[23:0]` holds IMPLEMENTATION DEFINED syndrome information.
+    ImplDefined(u32),
+
+    /// Architecturally defined syndrome.
+    Architected {
+        /// Implicit error synchronization event.
+        iesb: bool,
+        /// Asynchronous Error Type.
+        aet: ErrorType,
+        /// External abort type.
+        ea: bool,
+        /// Data Fault Status Code. Either uncategorized (0b000000) or asynchronous SError
+        /// interrupt (0b010001).
+        dfsc: u8,
+    },
+}
+
+impl SErrorSyndrome {
+    /// Decodes a raw ISS value.
+    pub const fn from_iss(iss: u32) -> Self {
+        if bit(iss, 24) {
+            return SErrorSyndrome::ImplDefined(iss & 0x00ff_ffff);
+        }
+
+        SErrorSyndrome::Architected {
+            iesb: bit(iss, 13),
+            aet: match ((iss >> 10) & 0b111) as u8 {
+                0b000 => ErrorType::Uncontainable,
+                0b001 => ErrorType::Unrecoverable,
+                0b010 => ErrorType::Restartable,
+                0b011 => ErrorType::Recoverable,
+                0b110 => ErrorType::Corrected,
+                aet => ErrorType::Reserved(aet),
+            },
+            ea: bit(iss, 9),
+            dfsc: (iss & 0x3f) as u8,
+        }
+    }
+}
+
+/// ISS encoding for an exception from a trapped floating-point exception.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FpException {
+    /// Trapped Fault Valid bit. If clear, the remaining flags are UNKNOWN.
+    pub tfv: bool,
+    /// Input Denormal floating-point exception trapped.
+    pub idf: bool,
+    /// Inexact floating-point exception trapped.
+    pub ixf: bool,
+    /// Underflow floating-point exception trapped.
+    pub uff: bool,
+    /// Overflow floating-point exception trapped.
+    pub off: bool,
+    /// Divide by Zero floating-point exception trapped.
+    pub dzf: bool,
+    /// Invalid Operation floating-point exception trapped.
+    pub iof: bool,
+}
+
+impl FpException {
+    /// Decodes a raw ISS value.
+    pub const fn from_iss(iss: u32) -> Self {
+        FpException {
+            tfv: bit(iss, 23),
+            idf: bit(iss, 7),
+            ixf: bit(iss, 4),
+            uff: bit(iss, 3),
+            off: bit(iss, 2),
+            dzf: bit(iss, 1),
+            iof: bit(iss, 0),
+        }
+    }
+}
+
+/// ISS encoding for an exception from a Software Step exception.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SoftwareStep {
+    /// Exclusive operation, if the instruction syndrome is valid. If set, the instruction stepped
+    /// was a Load-Exclusive instruction.
+    pub ex: Option<bool>,
+    /// Instruction Fault Status Code. Always [`FaultStatus::Debug`].
+    pub ifsc: FaultStatus,
+}
+
+impl SoftwareStep {
+    /// Decodes a raw ISS value.
+    pub const fn from_iss(iss: u32) -> Self {
+        SoftwareStep {
+            ex: if bit(iss, 24) {
+                Some(bit(iss, 6))
+            } else {
+                None
+            },
+            ifsc: FaultStatus::from_bits(iss as u8),
+        }
+    }
+}
+
+/// ISS encoding for an exception from a Watchpoint exception.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Watchpoint {
+    /// The watchpoint was generated by use of VNCR_EL2 by EL1 code.
+    pub vncr: bool,
+    /// FAR not Valid.
+    pub fnv: bool,
+    /// The watchpoint came from a cache maintenance or address translation instruction.
+    pub cm: bool,
+    /// Write not Read. The watchpoint was caused by an instruction writing to memory.
+    pub wnr: bool,
+    /// Data Fault Status Code. Always [`FaultStatus::Debug`].
+    pub dfsc: FaultStatus,
+}
+
+impl Watchpoint {
+    /// Decodes a raw ISS value.
+    pub const fn from_iss(iss: u32) -> Self {
+        Watchpoint {
+            vncr: bit(iss, 13),
+            fnv: bit(iss, 10),
+            cm: bit(iss, 8),
+            wnr: bit(iss, 6),
+            dfsc: FaultStatus::from_bits(iss as u8),
+        }
+    }
+}
+
+/// Decoded exception syndrome.
+///
+/// Variants are named after the [`ExceptionClass`] they are decoded from. Exception classes
+/// without a decoded ISS encoding are reported as [`Syndrome::Other`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Syndrome {
+    /// Unknown reason.
+    Unknown,
+    /// Trapped WF* instruction execution.
+    TrappedWFIorWFE(Wfx),
+    /// Illegal Execution state.
+    IllegalExecutionState,
+    /// SVC instruction execution in AArch32 state, with the immediate value of the instruction.
+    SVC32(u16),
+    /// HVC instruction execution in AArch32 state, with the immediate value of the instruction.
+    HVC32(u16),
+    /// SVC instruction execution in AArch64 state, with the immediate value of the instruction.
+    SVC64(u16),
+    /// HVC instruction execution in AArch64 state, with the immediate value of the instruction.
+    HVC64(u16),
+    /// SMC instruction execution in AArch64 state, with the immediate value of the instruction.
+    SMC64(u16),
+    /// Trapped MSR, MRS or System instruction execution in AArch64 state.
+    TrappedMsrMrs(MsrMrs),
+    /// Instruction Abort from a lower Exception level.
+    InstrAbortLowerEL(InstructionAbort),
+    /// Instruction Abort taken without a change in Exception level.
+    InstrAbortCurrentEL(InstructionAbort),
+    /// PC alignment fault exception.
+    PCAlignmentFault,
+    /// Data Abort exception from a lower Exception level.
+    DataAbortLowerEL(DataAbort),
+    /// Data Abort exception without a change in Exception level.
+    DataAbortCurrentEL(DataAbort),
+    /// SP alignment fault exception.
+    SPAlignmentFault,
+    /// Trapped floating-point exception taken from AArch32 state.
+    TrappedFP32(FpException),
+    /// Trapped floating-point exception taken from AArch64 state.
+    TrappedFP64(FpException),
+    /// SError exception.
+    SError(SErrorSyndrome),
+    /// Breakpoint exception from a lower Exception level.
+    BreakpointLowerEL(FaultStatus),
+    /// Breakpoint exception taken without a change in Exception level.
+    BreakpointCurrentEL(FaultStatus),
+    /// Software Step exception from a lower Exception level.
+    SoftwareStepLowerEL(SoftwareStep),
+    /// Software Step exception taken without a change in Exception level.
+    SoftwareStepCurrentEL(SoftwareStep),
+    /// Watchpoint exception from a lower Exception level.
+    WatchpointLowerEL(Watchpoint),
+    /// Watchpoint exception taken without a change in Exception level.
+    WatchpointCurrentEL(Watchpoint),
+    /// BKPT instruction execution in AArch32 state, with the comment field of the instruction.
+    Bkpt32(u16),
+    /// BRK instruction execution in AArch64 state, with the comment field of the instruction.
+    Brk64(u16),
+    /// An exception class without a decoded ISS encoding, and the raw ISS.
+    Other(ExceptionClass, u32),
+    /// A reserved exception class, and the raw ISS.
+    Reserved(u8, u32),
+}
+
+impl Syndrome {
+    /// Decodes a raw ESR value.
+    pub const fn from_esr(esr: u64) -> Self {
+        let ec = ((esr >> 26) & 0x3f) as u8;
+        let iss = (esr & 0x01ff_ffff) as u32;
+        let imm16 = iss as u16;
+
+        let ec = match ExceptionClass::from_bits(ec) {
+            Some(ec) => ec,
+            None => return Syndrome::Reserved(ec, iss),
+        };
+
+        match ec {
+            ExceptionClass::Unknown => Syndrome::Unknown,
+            ExceptionClass::TrappedWFIorWFE => Syndrome::TrappedWFIorWFE(Wfx::from_iss(iss)),
+            ExceptionClass::IllegalExecutionState => Syndrome::IllegalExecutionState,
+            ExceptionClass::SVC32 => Syndrome::SVC32(imm16),
+            ExceptionClass::HVC32 => Syndrome::HVC32(imm16),
+            ExceptionClass::SVC64 => Syndrome::SVC64(imm16),
+            ExceptionClass::HVC64 => Syndrome::HVC64(imm16),
+            ExceptionClass::SMC64 => Syndrome::SMC64(imm16),
+            ExceptionClass::TrappedMsrMrs => Syndrome::TrappedMsrMrs(MsrMrs::from_iss(iss)),
+            ExceptionClass::InstrAbortLowerEL => {
+                Syndrome::InstrAbortLowerEL(InstructionAbort::from_iss(iss))
+            }
+            ExceptionClass::InstrAbortCurrentEL => {
+                Syndrome::InstrAbortCurrentEL(InstructionAbort::from_iss(iss))
+            }
+            ExceptionClass::PCAlignmentFault => Syndrome::PCAlignmentFault,
+            ExceptionClass::DataAbortLowerEL => {
+                Syndrome::DataAbortLowerEL(DataAbort::from_iss(iss))
+            }
+            ExceptionClass::DataAbortCurrentEL => {
+                Syndrome::DataAbortCurrentEL(DataAbort::from_iss(iss))
+            }
+            ExceptionClass::SPAlignmentFault => Syndrome::SPAlignmentFault,
+            ExceptionClass::TrappedFP32 => Syndrome::TrappedFP32(FpException::from_iss(iss)),
+            ExceptionClass::TrappedFP64 => Syndrome::TrappedFP64(FpException::from_iss(iss)),
+            ExceptionClass::SError => Syndrome::SError(SErrorSyndrome::from_iss(iss)),
+            ExceptionClass::BreakpointLowerEL => {
+                Syndrome::BreakpointLowerEL(FaultStatus::from_bits(iss as u8))
+            }
+            ExceptionClass::BreakpointCurrentEL => {
+                Syndrome::BreakpointCurrentEL(FaultStatus::from_bits(iss as u8))
+            }
+            ExceptionClass::SoftwareStepLowerEL => {
+                Syndrome::SoftwareStepLowerEL(SoftwareStep::from_iss(iss))
+            }
+            ExceptionClass::SoftwareStepCurrentEL => {
+                Syndrome::SoftwareStepCurrentEL(SoftwareStep::from_iss(iss))
+            }
+            ExceptionClass::WatchpointLowerEL => {
+                Syndrome::WatchpointLowerEL(Watchpoint::from_iss(iss))
+            }
+            ExceptionClass::WatchpointCurrentEL => {
+                Syndrome::WatchpointCurrentEL(Watchpoint::from_iss(iss))
+            }
+            ExceptionClass::Bkpt32 => Syndrome::Bkpt32(imm16),
+            ExceptionClass::Brk64 => Syndrome::Brk64(imm16),
+            ec => Syndrome::Other(ec, iss),
+        }
+    }
+}
+
+#[inline(always)]
+const fn bit(iss: u32, n: u32) -> bool {
+    iss & (1 << n) != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_data_abort() {
+        // STR x1, [x0] to an unmapped level 3 page, taken from EL0.
+        let esr = (0b10_0100 << 26)
+            | (1 << 25)
+            | (1 << 24)
+            | (0b11 << 22)
+            | (1 << 16)
+            | (1 << 15)
+            | (1 << 6)
+            | 0b00_0111;
+
+        assert_eq!(
+            Syndrome::from_esr(esr),
+            Syndrome::DataAbortLowerEL(DataAbort {
+                isv: Some(DataAbortInstr {
+                    sas: AccessSize::Doubleword,
+                    sse: false,
+                    srt: 1,
+                    sf: true,
+                    ar: false,
+                }),
+                vncr: false,
+                set: 0,
+                fnv: false,
+                ea: false,
+                cm: false,
+                s1ptw: false,
+                wnr: true,
+                dfsc: FaultStatus::Translation { level: 3 },
+            })
+        );
+    }
+
+    #[test]
+    fn decode_msr_mrs() {
+        // MRS x3, CNTPCT_EL0 (3, 3, c14, c0, 1).
+        let esr = (0b01_1000 << 26)
+            | (1 << 25)
+            | (3 << 20)
+            | (1 << 17)
+            | (3 << 14)
+            | (14 << 10)
+            | (3 << 5)
+            | 1;
+
+        match Syndrome::from_esr(esr) {
+            Syndrome::TrappedMsrMrs(iss) => {
+                assert_eq!(iss.encoding(), (3, 3, 14, 0, 1));
+                assert_eq!(iss.rt, 3);
+                assert_eq!(iss.direction, Direction::Read);
+            }
+            s => panic!("unexpected syndrome {:?}", s),
+        }
+    }
+
+    #[test]
+    fn decode_immediates_and_reserved() {
+        assert_eq!(Syndrome::from_esr(0x5a00_1234), Syndrome::HVC64(0x1234));
+        assert_eq!(Syndrome::from_esr(0xf200_0800), Syndrome::Brk64(0x800));
+        assert_eq!(
+            Syndrome::from_esr(0x0800_0000),
+            Syndrome::Reserved(0b00_0010, 0)
+        );
+        assert_eq!(
+            Syndrome::from_esr(0x6600_0000),
+            Syndrome::Other(ExceptionClass::TrappedSve, 0)
+        );
+    }
+
+    #[test]
+    fn decode_fault_status_levels() {
+        assert_eq!(
+            FaultStatus::from_bits(0b10_1011),
+            FaultStatus::Translation { level: -1 }
+        );
+        assert_eq!(
+            FaultStatus::from_bits(0b00_1111),
+            FaultStatus::Permission { level: 3 }
+        );
+        assert_eq!(
+            FaultStatus::from_bits(0b01_0101),
+            FaultStatus::SyncExternalOnWalk { level: 1 }
+        );
+        assert_eq!(
+            FaultStatus::from_bits(0b11_1111),
+            FaultStatus::Other(0b11_1111)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Exception Syndrome Register - EL12
+//!
+//! Accesses ESR_EL1 from EL2 when HCR_EL2.E2H is 1, in which case ESR_EL1 accesses its EL2
+//! counterpart. Uses the ESR_EL1 layout. Requires FEAT_VHE.
+
+use crate::registers::{esr::Syndrome, ESR_EL1};
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Reg {
+    /// Reads the register and decodes the Instruction Specific Syndrome according to the
+    /// Exception Class.
+    #[inline(always)]
+    pub fn syndrome(&self) -> Syndrome {
+        Syndrome::from_esr(self.get())
+    }
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ESR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ESR_EL12" = "S3_5_C5_C2_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ESR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ESR_EL12" = "S3_5_C5_C2_0", "x");
+}
+
+pub const ESR_EL12: Reg = Reg {};
@@ -12,13 +12,143 @@
 //! Provides configuration controls for virtualization, including defining
 //! whether various Non-secure operations are trapped to EL2.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub HCR_EL2 [
+        /// TWE Delay. The delay before a trapped WFE or WFET instruction is taken, of at least
+        /// 2^(TWEDEL + 8) cycles, when HCR_EL2.TWEDEn is 1. Requires FEAT_TWED.
+        TWEDEL OFFSET(60) NUMBITS(4) [],
+
+        /// TWE Delay Enable. Enables a configurable delay before a WFE or WFET instruction trapped
+        /// by HCR_EL2.TWE is taken. Requires FEAT_TWED.
+        ///
+        /// 0 The delay is IMPLEMENTATION DEFINED.
+        /// 1 The delay is given by HCR_EL2.TWEDEL.
+        TWEDEn OFFSET(59) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Trap ID group 5. Traps EL0 and EL1 reads of GMID_EL1 to EL2, when EL2 is enabled in the
+        /// current Security state. Requires FEAT_MTE2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 The specified EL0 and EL1 reads are trapped to EL2.
+        TID5 OFFSET(58) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Default Cacheability Tagging. When HCR_EL2.DC is 1, controls whether stage 1
+        /// translations are treated as Tagged or Untagged. Requires FEAT_MTE2.
+        ///
+        /// 0 Stage 1 translations are treated as Untagged.
+        /// 1 Stage 1 translations are treated as Tagged.
+        DCT OFFSET(57) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Allocation Tag Access. Controls access to Allocation Tags, system registers for
+        /// Allocation Tags and instructions for Allocation Tags at EL0 and EL1. Requires FEAT_MTE2.
+        ///
+        /// 0 Accesses at EL0 and EL1 are prevented, and instructions for Allocation Tags are
+        ///   trapped to EL2.
+        /// 1 This control does not prevent any accesses.
+        ATA OFFSET(56) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Trap TLB maintenance instructions that operate on the Outer Shareable domain. Traps EL1
+        /// execution of TLBI *OS instructions to EL2. Requires FEAT_EVT.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 execution of the specified instructions is trapped to EL2.
+        TTLBOS OFFSET(55) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Trap TLB maintenance instructions that operate on the Inner Shareable domain. Traps EL1
+        /// execution of TLBI *IS instructions to EL2. Requires FEAT_EVT.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 execution of the specified instructions is trapped to EL2.
+        TTLBIS OFFSET(54) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Enable Access to the SCXTNUM_EL1 and SCXTNUM_EL0 registers. Requires FEAT_CSV2.
+        ///
+        /// 0 EL0 and EL1 accesses to SCXTNUM_EL0 and SCXTNUM_EL1 are trapped to EL2.
+        /// 1 This control does not cause any accesses to be trapped.
+        EnSCXT OFFSET(53) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Trap cache maintenance instructions that operate to the Point of Unification. Traps EL0
+        /// and EL1 execution of IC IVAU, IC IALLU and DC CVAU to EL2. Requires FEAT_EVT.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 and EL1 execution of the specified instructions is trapped to EL2.
+        TOCU OFFSET(52) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Activity Monitors Virtual Offsets Enable. Requires FEAT_AMUv1p1.
+        ///
+        /// 0 Virtualization of the Activity Monitors is disabled.
+        /// 1 Indirect reads of the virtual Activity Monitors counters from EL0 and EL1 are offset
+        ///   by the AMEVCNTVOFF<n>_EL2 registers.
+        AMVOFFEN OFFSET(51) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Trap Instruction Cache maintenance instructions that operate to the Inner Shareable
+        /// domain. Traps EL1 execution of IC IALLUIS to EL2. Requires FEAT_EVT.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 execution of the specified instructions is trapped to EL2.
+        TICAB OFFSET(50) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Trap ID group 4. Traps EL0 and EL1 accesses to CCSIDR_EL1, CCSIDR2_EL1, CLIDR_EL1 and
+        /// CSSELR_EL1 to EL2. Requires FEAT_EVT.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 and EL1 accesses to the specified registers are trapped to EL2.
+        TID4 OFFSET(49) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Controls the reporting of Granule protection faults at EL0 and EL1. Requires FEAT_RME.
+        ///
+        /// 0 This control does not cause exceptions to be routed from EL0 and EL1 to EL2.
+        /// 1 Instruction and Data Abort exceptions due to Granule protection faults from EL0 and
+        ///   EL1 are routed to EL2.
+        GPF OFFSET(48) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Fault Injection Enable. Requires FEAT_RASv1p1.
+        ///
+        /// 0 EL1 accesses to ERXPFGCDN_EL1, ERXPFGCTL_EL1 and ERXPFGF_EL1 are trapped to EL2.
+        /// 1 This control does not cause any instructions to be trapped.
+        FIEN OFFSET(47) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
         /// When FEAT_S2FWB is implemented Forced Write-back changes the combined cachability of stage1
         /// and stage2 attributes
         FWB OFFSET(46) NUMBITS(1) [
@@ -28,6 +158,47 @@ register_bitfields! {u64,
            Enabled = 1,
         ],
 
+        /// Nested Virtualization. Changes the behavior of HCR_EL2.{NV1, NV} to redirect EL1
+        /// accesses to some EL2 and EL1 registers to memory at VNCR_EL2. Requires FEAT_NV2.
+        ///
+        /// 0 This control does not change the behavior of HCR_EL2.{NV1, NV}.
+        /// 1 Redirection of the specified EL1 accesses to memory is enabled.
+        NV2 OFFSET(45) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Address Translation. Traps EL1 execution of the AT S1E0R, S1E0W, S1E1R, S1E1W, S1E1RP
+        /// and S1E1WP instructions to EL2. Requires FEAT_NV.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 execution of the specified instructions is trapped to EL2.
+        AT OFFSET(44) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Nested Virtualization. With HCR_EL2.NV, traps EL1 accesses to the registers used by a
+        /// guest hypervisor with HCR_EL2.E2H 0 to EL2. Requires FEAT_NV.
+        ///
+        /// 0 If HCR_EL2.NV is 1, EL1 accesses to VBAR_EL1, ELR_EL1 and SPSR_EL1 are not trapped.
+        /// 1 EL1 accesses to VBAR_EL1, ELR_EL1, SPSR_EL1 and the EL02 and EL12 registers are
+        ///   trapped to EL2.
+        NV1 OFFSET(43) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Nested Virtualization. Traps EL1 accesses to the EL2 registers and the execution of ERET
+        /// at EL1 to EL2, and reports EL2 in CurrentEL when executing at EL1. Requires FEAT_NV.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 accesses to the specified registers and instructions are trapped to EL2.
+        NV OFFSET(42) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
         /// Controls the use of instructions related to Pointer Authentication:
         ///
         ///   - In EL0, when HCR_EL2.TGE==0 or HCR_EL2.E2H==0, and the associated SCTLR_EL1.En<N><M>==1.
@@ -57,6 +228,27 @@ register_bitfields! {u64,
             DisableTrapPointerAuthKeyRegsToEl2 = 1,
         ],
 
+        /// Enables access to the TSTART, TCOMMIT, TTEST and TCANCEL instructions at EL0 and EL1.
+        /// Requires FEAT_TME.
+        ///
+        /// 0 EL0 and EL1 execution of the specified instructions is UNDEFINED.
+        /// 1 This control does not cause any instructions to be UNDEFINED.
+        TME OFFSET(39) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Mismatched Inner/Outer Cacheable Non-Coherency Enable, for the EL1&0 translation regime.
+        ///
+        /// 0 For the EL1&0 translation regime, there is no loss of coherency for data accesses from
+        ///   memory locations with mismatched Inner and Outer cacheability attributes.
+        /// 1 For the EL1&0 translation regime, a loss of coherency is permitted for data accesses
+        ///   from such memory locations.
+        MIOCNCE OFFSET(38) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
         /// Route synchronous External abort exceptions to EL2.
         ///   if 0: This control does not cause exceptions to be routed from EL0 and EL1 to EL2.
         ///   if 1: Route synchronous External abort exceptions from EL0 and EL1 to EL2, when EL2 is
@@ -72,14 +264,20 @@ register_bitfields! {u64,
         /// 0 Accesses of the specified Error Record registers are not trapped by this mechanism.
         /// 1 Accesses of the specified Error Record registers at EL1 are trapped to EL2,
         ///   unless the instruction generates a higher priority exception.
-        TERR  OFFSET(36) NUMBITS(1) [],
+        TERR  OFFSET(36) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
 
         /// Trap LOR registers. Traps Non-secure EL1 accesses to LORSA_EL1, LOREA_EL1, LORN_EL1,
         /// LORC_EL1, and LORID_EL1 registers to EL2.
         ///
         /// 0 This control does not cause any instructions to be trapped.
         /// 1 Non-secure EL1 accesses to the LOR registers are trapped to EL2.
-        TLOR  OFFSET(35) NUMBITS(1) [],
+        TLOR  OFFSET(35) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
 
         /// EL2 Host. Enables a configuration where a Host Operating System is running in EL2, and
         /// the Host Operating System's applications are running in EL0.
@@ -88,6 +286,28 @@ register_bitfields! {u64,
             EnableOsAtEl2 = 1
         ],
 
+        /// Stage 2 Instruction access cacheability disable. For the EL1&0 translation regime, when
+        /// HCR_EL2.VM is 1, forces all stage 2 translations for instruction accesses to Normal
+        /// memory to be Non-cacheable.
+        ///
+        /// 0 This control has no effect on stage 2 of the EL1&0 translation regime.
+        /// 1 Instruction accesses to Normal memory are Non-cacheable.
+        ID OFFSET(33) NUMBITS(1) [
+            Cacheable = 0,
+            NonCacheable = 1
+        ],
+
+        /// Stage 2 Data access cacheability disable. For the EL1&0 translation regime, when
+        /// HCR_EL2.VM is 1, forces all stage 2 translations for data accesses and translation table
+        /// walks to Normal memory to be Non-cacheable.
+        ///
+        /// 0 This control has no effect on stage 2 of the EL1&0 translation regime.
+        /// 1 Data accesses and translation table walks to Normal memory are Non-cacheable.
+        CD OFFSET(32) NUMBITS(1) [
+            Cacheable = 0,
+            NonCacheable = 1
+        ],
+
         /// Execution state control for lower Exception levels:
         ///
         /// 0 Lower levels are all AArch32.
@@ -109,6 +329,37 @@ register_bitfields! {u64,
             EL1IsAarch64 = 1
         ],
 
+        /// Trap Reads of Virtual Memory controls. Traps EL1 reads of the virtual memory control
+        /// registers SCTLR_EL1, TTBR0_EL1, TTBR1_EL1, TCR_EL1, ESR_EL1, FAR_EL1, AFSR0_EL1,
+        /// AFSR1_EL1, MAIR_EL1, AMAIR_EL1 and CONTEXTIDR_EL1 to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 reads of the specified registers are trapped to EL2.
+        TRVM OFFSET(30) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// HVC instruction disable. Disables EL1 execution of HVC instructions, when EL3 is not
+        /// implemented.
+        ///
+        /// 0 HVC instruction execution is enabled at EL2 and EL1.
+        /// 1 HVC instructions are UNDEFINED at EL2 and EL1.
+        HCD OFFSET(29) NUMBITS(1) [
+            HvcEnabled = 0,
+            HvcDisabled = 1
+        ],
+
+        /// Trap DC ZVA instructions. Traps EL0 and EL1 execution of DC ZVA to EL2, and makes
+        /// DCZID_EL0.DZP read as 1 at EL0 and EL1.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 and EL1 execution of DC ZVA is trapped to EL2.
+        TDZ OFFSET(28) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
         /// Trap General Exceptions, from EL0.
         ///
         /// If enabled:
@@ -143,6 +394,47 @@ register_bitfields! {u64,
             EnableTrapGeneralExceptionsToEl2 = 1,
         ],
 
+        /// Trap Virtual Memory controls. Traps EL1 writes to the virtual memory control registers
+        /// SCTLR_EL1, TTBR0_EL1, TTBR1_EL1, TCR_EL1, ESR_EL1, FAR_EL1, AFSR0_EL1, AFSR1_EL1,
+        /// MAIR_EL1, AMAIR_EL1 and CONTEXTIDR_EL1 to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 writes to the specified registers are trapped to EL2.
+        TVM OFFSET(26) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Trap TLB maintenance instructions. Traps EL1 execution of TLBI instructions to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 execution of TLBI instructions is trapped to EL2.
+        TTLB OFFSET(25) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Trap cache maintenance instructions that operate to the Point of Unification. Traps EL0
+        /// and EL1 execution of IC IVAU, IC IALLU, IC IALLUIS and DC CVAU to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 and EL1 execution of the specified instructions is trapped to EL2.
+        TPU OFFSET(24) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Trap data or unified cache maintenance instructions that operate to the Point of
+        /// Coherency or Persistence. Traps EL0 and EL1 execution of the DC IVAC, CIVAC, CVAC, CVAP
+        /// and CVADP instructions to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 and EL1 execution of the specified instructions is trapped to EL2.
+        TPCP OFFSET(23) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
         /// Trap data or unified cache maintenance instructions that operate by Set/Way.
         /// Traps execution of those cache maintenance instructions at EL1 to EL2, when
         /// EL2 is enabled in the current Security state.
@@ -150,7 +442,10 @@ register_bitfields! {u64,
         /// 0 This control does not cause any instructions to be trapped.
         /// 1 Execution of the specified instructions is trapped to EL2, when EL2 is enabled
         /// in the current Security state.
-        TSW   OFFSET(22) NUMBITS(1) [],
+        TSW   OFFSET(22) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
 
         /// Trap Auxiliary Control Registers. Traps EL1 accesses to the Auxiliary Control Registers
         /// to EL2, when EL2 is enabled in the current Security state
@@ -158,7 +453,10 @@ register_bitfields! {u64,
         /// 0 This control does not cause any instructions to be trapped.
         /// 1 EL1 accesses to the specified registers are trapped to EL2, when EL2 is enabled in the
         ///   current Security state.
-        TACR  OFFSET(21) NUMBITS(1) [],
+        TACR  OFFSET(21) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
 
         /// Trap IMPLEMENTATION DEFINED functionality. Traps EL1 accesses to the encodings reserved
         /// for IMPLEMENTATION DEFINED functionality to EL2, when EL2 is enabled in the current
@@ -168,7 +466,10 @@ register_bitfields! {u64,
         /// 1 EL1 accesses to or execution of the specified encodings reserved for IMPLEMENTATION
         /// DEFINED functionality are trapped to EL2, when EL2 is enabled in the current Security
         /// state.
-        TIDCP OFFSET(20) NUMBITS(1) [],
+        TIDCP OFFSET(20) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
 
         /// Trap SMC instructions. Traps EL1 execution of SMC instructions to EL2, when EL2 is
         /// enabled in the current Security state.
@@ -214,7 +515,59 @@ register_bitfields! {u64,
         /// 0 This control does not cause any instructions to be trapped.
         /// 1 The specified EL1 read accesses to ID group 3 registers are trapped to EL2, when EL2
         /// is enabled in the current Security state.
-        TID3  OFFSET(18) NUMBITS(1) [],
+        TID3  OFFSET(18) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Trap ID group 2. Traps EL0 and EL1 reads of CTR_EL0, CCSIDR_EL1, CCSIDR2_EL1 and
+        /// CLIDR_EL1, and EL0 and EL1 accesses to CSSELR_EL1, to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 The specified EL0 and EL1 accesses are trapped to EL2.
+        TID2 OFFSET(17) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Trap ID group 1. Traps EL1 reads of REVIDR_EL1 and AIDR_EL1 to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 reads of the specified registers are trapped to EL2.
+        TID1 OFFSET(16) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Trap ID group 0. Traps EL0 and EL1 reads of the AArch32 ID group 0 registers JIDR and
+        /// FPSID to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 and EL1 reads of the specified registers are trapped to EL2.
+        TID0 OFFSET(15) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Traps EL0 and EL1 execution of WFE and WFET instructions to EL2, when the instruction
+        /// would cause the PE to enter a low-power state.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 and EL1 execution of the specified instructions is trapped to EL2.
+        TWE OFFSET(14) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Traps EL0 and EL1 execution of WFI and WFIT instructions to EL2, when the instruction
+        /// would cause the PE to enter a low-power state.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 and EL1 execution of the specified instructions is trapped to EL2.
+        TWI OFFSET(13) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
 
         /// Default Cacheability.
         ///
@@ -244,7 +597,10 @@ register_bitfields! {u64,
         ///
         /// When ARMv8.1-VHE is implemented, and the value of HCR_EL2.{E2H, TGE} is {1, 1}, this
         /// field behaves as 0 for all purposes other than a direct read of the value of this field.
-        DC   OFFSET(12) NUMBITS(1) [],
+        DC   OFFSET(12) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
 
         /// Barrier Shareability upgrade. This field determines the minimum shareability domain that
         /// is applied to any barrier instruction executed from EL1 or EL0.
@@ -261,14 +617,50 @@ register_bitfields! {u64,
         /// 0 This field has no effect on the operation of the specified instructions.
         /// 1 When one of the specified instruction is executed at EL1, the instruction is broadcast
         /// within the Inner Shareable shareability domain.
-        FB    OFFSET(9) NUMBITS(1) [],
+        FB    OFFSET(9) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Virtual SError interrupt. Makes a virtual SError interrupt pending, when HCR_EL2.AMO is
+        /// 1 and HCR_EL2.TGE is 0. The syndrome of the interrupt is taken from VSESR_EL2.
+        ///
+        /// 0 This mechanism is not making a virtual SError interrupt pending.
+        /// 1 A virtual SError interrupt is pending.
+        VSE OFFSET(8) NUMBITS(1) [
+            NotPending = 0,
+            Pending = 1
+        ],
+
+        /// Virtual IRQ Interrupt. Makes a virtual IRQ pending, when HCR_EL2.IMO is 1 and
+        /// HCR_EL2.TGE is 0.
+        ///
+        /// 0 This mechanism is not making a virtual IRQ pending.
+        /// 1 A virtual IRQ is pending.
+        VI OFFSET(7) NUMBITS(1) [
+            NotPending = 0,
+            Pending = 1
+        ],
+
+        /// Virtual FIQ Interrupt. Makes a virtual FIQ pending, when HCR_EL2.FMO is 1 and
+        /// HCR_EL2.TGE is 0.
+        ///
+        /// 0 This mechanism is not making a virtual FIQ pending.
+        /// 1 A virtual FIQ is pending.
+        VF OFFSET(6) NUMBITS(1) [
+            NotPending = 0,
+            Pending = 1
+        ],
 
         /// Physical SError interrupt routing.
         ///   - If bit is 1 when executing at any Exception level, and EL2 is enabled in the current
         ///     Security state:
         ///     - Physical SError interrupts are taken to EL2, unless they are routed to EL3.
         ///     - When the value of HCR_EL2.TGE is 0, then virtual SError interrupts are enabled.
-        AMO   OFFSET(5) NUMBITS(1) [],
+        AMO   OFFSET(5) NUMBITS(1) [
+            DisableVirtualSError = 0,
+            EnableVirtualSError = 1
+        ],
 
         /// Physical IRQ Routing.
         ///
@@ -329,6 +721,17 @@ register_bitfields! {u64,
             EnableVirtualFIQ = 1,
         ],
 
+        /// Protected Table Walk. When HCR_EL2.VM is 1, stage 1 translation table walks that access
+        /// Device memory at stage 2 generate a stage 2 Permission fault.
+        ///
+        /// 0 This control has no effect on stage 1 translation table walks.
+        /// 1 Stage 1 translation table walks to Device memory at stage 2 generate a Permission
+        ///   fault.
+        PTW OFFSET(2) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
         /// Set/Way Invalidation Override. Causes Non-secure EL1 execution of the data cache
         /// invalidate by set/way instructions to perform a data cache clean and invalidate by
         /// set/way:
@@ -353,7 +756,10 @@ register_bitfields! {u64,
         ///
         /// When HCR_EL2.TGE is 1, the PE ignores the value of this field for all purposes other
         /// than a direct read of this field.
-        SWIO OFFSET(1) NUMBITS(1) [],
+        SWIO OFFSET(1) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
 
         /// Virtualization enable. Enables stage 2 address translation for the EL1&0 translation regime,
         /// when EL2 is enabled in the current Security state. The possible values are:
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Extended Hypervisor Configuration Register - EL2
+//!
+//! Provides configuration controls for virtualization, in addition to HCR_EL2. Requires FEAT_HCX.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub HCRX_EL2 [
+        /// Enables access to SCTLR2_EL1 at EL1. Requires FEAT_SCTLR2.
+        ///
+        /// 0 EL1 accesses to SCTLR2_EL1 are trapped to EL2, and SCTLR2_EL1 has no effect.
+        /// 1 This control does not cause any instructions to be trapped.
+        SCTLR2En OFFSET(15) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Enables access to TCR2_EL1 at EL1. Requires FEAT_TCR2.
+        ///
+        /// 0 EL1 accesses to TCR2_EL1 are trapped to EL2, and TCR2_EL1 has no effect.
+        /// 1 This control does not cause any instructions to be trapped.
+        TCR2En OFFSET(14) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Memory Set and Memory Copy instructions Enable. Enables execution of the CPY* and SET*
+        /// instructions at EL1 and EL0, when HCR_EL2.{E2H, TGE} is not {1, 1}. Requires FEAT_MOPS.
+        ///
+        /// 0 Execution of the specified instructions is UNDEFINED at EL1 and EL0.
+        /// 1 This control does not cause any instructions to be UNDEFINED.
+        MSCEn OFFSET(11) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Controls the routing of the Memory Copy and Memory Set exceptions generated by the CPY*
+        /// and SET* instructions at EL1. Requires FEAT_MOPS.
+        ///
+        /// 0 The exceptions are taken to EL1.
+        /// 1 The exceptions are taken to EL2.
+        MCE2 OFFSET(10) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Controls cache maintenance instruction permission for the EL1&0 translation regime.
+        /// Requires FEAT_CMOW.
+        ///
+        /// 0 This control has no effect on the permission checks of cache maintenance instructions.
+        /// 1 Instruction cache invalidation to the Point of Unification generates a stage 2
+        ///   Permission fault for locations without write permission at stage 2.
+        CMOW OFFSET(9) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Virtual FIQ Interrupt with Superpriority. With HCR_EL2.VF, makes a virtual FIQ with
+        /// Superpriority pending. Requires FEAT_NMI.
+        ///
+        /// 0 A pending virtual FIQ does not have Superpriority.
+        /// 1 A pending virtual FIQ has Superpriority.
+        VFNMI OFFSET(8) NUMBITS(1) [
+            NotPending = 0,
+            Pending = 1
+        ],
+
+        /// Virtual IRQ Interrupt with Superpriority. With HCR_EL2.VI, makes a virtual IRQ with
+        /// Superpriority pending. Requires FEAT_NMI.
+        ///
+        /// 0 A pending virtual IRQ does not have Superpriority.
+        /// 1 A pending virtual IRQ has Superpriority.
+        VINMI OFFSET(7) NUMBITS(1) [
+            NotPending = 0,
+            Pending = 1
+        ],
+
+        /// Traps MSR writes of ALLINT at EL1 to EL2. Requires FEAT_NMI.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL1 execution of MSR (register) writing ALLINT and MSR (immediate) writing ALLINT with
+        ///   the value 1 is trapped to EL2.
+        TALLINT OFFSET(6) NUMBITS(1) [
+            NotTrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Streaming Mode Priority Mapping Enable. Enables the mapping of the Streaming SVE mode
+        /// priority of EL1 and EL0 through SMPRIMAP_EL2. Requires FEAT_SME.
+        ///
+        /// 0 The priority is not mapped.
+        /// 1 The priority is mapped through SMPRIMAP_EL2.
+        SMPME OFFSET(5) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Determines whether the fine-grained traps of TLBI instructions also apply to the TLBI
+        /// nXS variants. Requires FEAT_XS.
+        ///
+        /// 0 The fine-grained traps apply to the nXS variants.
+        /// 1 The fine-grained traps do not apply to the nXS variants.
+        FGTnXS OFFSET(4) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Determines the behavior of TLBI and DSB instructions at EL1 that are affected by the XS
+        /// attribute. Requires FEAT_XS.
+        ///
+        /// 0 This control does not affect the behavior of the instructions.
+        /// 1 The TLBI and DSB instructions without the nXS qualifier at EL1 behave as the nXS
+        ///   variants.
+        FnXS OFFSET(3) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Enables execution of ST64BV at EL0 and EL1. Requires FEAT_LS64_V.
+        ///
+        /// 0 EL0 and EL1 execution of ST64BV is trapped to EL2.
+        /// 1 This control does not cause any instructions to be trapped.
+        EnASR OFFSET(2) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Enables execution of LD64B and ST64B at EL0 and EL1. Requires FEAT_LS64.
+        ///
+        /// 0 EL0 and EL1 execution of LD64B and ST64B is trapped to EL2.
+        /// 1 This control does not cause any instructions to be trapped.
+        EnALS OFFSET(1) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Enables execution of ST64BV0 at EL0 and EL1. Requires FEAT_LS64_ACCDATA.
+        ///
+        /// 0 EL0 and EL1 execution of ST64BV0 is trapped to EL2.
+        /// 1 This control does not cause any instructions to be trapped.
+        EnAS0 OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = HCRX_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "HCRX_EL2" = "S3_4_C1_C2_2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = HCRX_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "HCRX_EL2" = "S3_4_C1_C2_2", "x");
+}
+
+pub const HCRX_EL2: Reg = Reg {};
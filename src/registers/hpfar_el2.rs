@@ -9,9 +9,9 @@
 //!
 //! Holds the faulting IPA for some aborts on a stage 2 translation taken to EL2.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub HPFAR_EL2 [
         /// Faulting IPA address space.
         NS   OFFSET(63) NUMBITS(1) [],
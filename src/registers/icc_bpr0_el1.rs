@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Binary Point Register 0 - EL1
+//!
+//! Defines the point at which the priority value fields split into two parts, the group priority
+//! field and the subpriority field, for Group 0 interrupt preemption.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub ICC_BPR0_EL1 [
+        /// The value of this field controls how the 8-bit interrupt priority field is split into a
+        /// group priority field, that determines interrupt preemption, and a subpriority field.
+        BinaryPoint OFFSET(0) NUMBITS(3) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_BPR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_BPR0_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_BPR0_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_BPR0_EL1", "x");
+}
+
+pub const ICC_BPR0_EL1: Reg = Reg {};
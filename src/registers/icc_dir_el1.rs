@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Deactivate Interrupt Register - EL1
+//!
+//! When interrupt priority drop is separated from interrupt deactivation, a write to this
+//! register deactivates the specified interrupt.
+
+use tock_registers::interfaces::Writeable;
+
+described_bitfields! {u64,
+    pub ICC_DIR_EL1 [
+        /// The INTID of the interrupt to be deactivated.
+        INTID OFFSET(0) NUMBITS(24) []
+    ]
+}
+
+pub struct Reg;
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_DIR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_DIR_EL1", "x");
+}
+
+pub const ICC_DIR_EL1: Reg = Reg {};
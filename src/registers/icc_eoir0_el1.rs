@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller End Of Interrupt Register 0 - EL1
+//!
+//! A write to this register performs priority drop for the specified Group 0 interrupt and, if
+//! `ICC_CTLR_EL1.EOImode` is 0, also deactivates the interrupt.
+
+use tock_registers::interfaces::Writeable;
+
+described_bitfields! {u64,
+    pub ICC_EOIR0_EL1 [
+        /// The INTID from the corresponding `ICC_IAR0_EL1` access.
+        INTID OFFSET(0) NUMBITS(24) []
+    ]
+}
+
+pub struct Reg;
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_EOIR0_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_EOIR0_EL1", "x");
+}
+
+pub const ICC_EOIR0_EL1: Reg = Reg {};
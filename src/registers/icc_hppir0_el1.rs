@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Highest Priority Pending Interrupt Register 0 - EL1
+//!
+//! Indicates the highest priority pending Group 0 interrupt on the CPU interface.
+
+use tock_registers::interfaces::Readable;
+
+described_bitfields! {u64,
+    pub ICC_HPPIR0_EL1 [
+        /// The INTID of the highest priority pending interrupt, or 1023 if there is none.
+        INTID OFFSET(0) NUMBITS(24) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_HPPIR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_HPPIR0_EL1", "x");
+}
+
+pub const ICC_HPPIR0_EL1: Reg = Reg {};
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Interrupt Acknowledge Register 1 - EL1
+//!
+//! Reading this register returns the INTID of the signaled Group 1 interrupt and acknowledges it.
+
+use tock_registers::interfaces::Readable;
+
+described_bitfields! {u64,
+    pub ICC_IAR1_EL1 [
+        /// The INTID of the signaled interrupt. Special INTIDs 1020 to 1023 indicate that there is
+        /// no pending interrupt, or that it is not acknowledged by this read.
+        INTID OFFSET(0) NUMBITS(24) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_IAR1_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_IAR1_EL1", "x");
+}
+
+pub const ICC_IAR1_EL1: Reg = Reg {};
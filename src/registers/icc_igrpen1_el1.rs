@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Interrupt Group 1 Enable register - EL1
+//!
+//! Controls whether Group 1 interrupts are enabled or not.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub ICC_IGRPEN1_EL1 [
+        /// Enables Group 1 interrupts.
+        Enable OFFSET(0) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_IGRPEN1_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_IGRPEN1_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_IGRPEN1_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_IGRPEN1_EL1", "x");
+}
+
+pub const ICC_IGRPEN1_EL1: Reg = Reg {};
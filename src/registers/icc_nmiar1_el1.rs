@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Non-maskable Interrupt Acknowledge Register 1 - EL1
+//!
+//! Reading this register returns the INTID of the signaled Group 1 interrupt with the
+//! Non-maskable property and acknowledges it. Requires FEAT_GICv3_NMI.
+
+use tock_registers::interfaces::Readable;
+
+described_bitfields! {u64,
+    pub ICC_NMIAR1_EL1 [
+        /// The INTID of the signaled interrupt.
+        INTID OFFSET(0) NUMBITS(24) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_NMIAR1_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_NMIAR1_EL1" = "S3_0_C12_C9_5", "x");
+}
+
+pub const ICC_NMIAR1_EL1: Reg = Reg {};
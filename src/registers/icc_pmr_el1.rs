@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Interrupt Priority Mask Register - EL1
+//!
+//! Provides an interrupt priority filter. Only interrupts with a higher priority than the value in
+//! this register are signaled to the PE.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub ICC_PMR_EL1 [
+        /// The priority mask level for the CPU interface. Unimplemented low-order bits are RAZ/WI.
+        Priority OFFSET(0) NUMBITS(8) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_PMR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_PMR_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_PMR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_PMR_EL1", "x");
+}
+
+pub const ICC_PMR_EL1: Reg = Reg {};
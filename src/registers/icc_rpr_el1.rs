@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Running Priority Register - EL1
+//!
+//! Indicates the Running priority of the CPU interface.
+
+use tock_registers::interfaces::Readable;
+
+described_bitfields! {u64,
+    pub ICC_RPR_EL1 [
+        /// Indicates whether the Running priority is from an interrupt with the Non-maskable
+        /// property in the current Security state. Requires FEAT_GICv3_NMI.
+        NMI OFFSET(63) NUMBITS(1) [],
+
+        /// Indicates whether the Running priority is from a Non-secure interrupt with the
+        /// Non-maskable property, when accessed from Secure state. Requires FEAT_GICv3_NMI.
+        NMI_NS OFFSET(62) NUMBITS(1) [],
+
+        /// The current running priority on the CPU interface, or 0xff if there is no active
+        /// interrupt.
+        Priority OFFSET(0) NUMBITS(8) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_RPR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_RPR_EL1", "x");
+}
+
+pub const ICC_RPR_EL1: Reg = Reg {};
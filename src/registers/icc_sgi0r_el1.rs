@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Software Generated Interrupt Group 0 Register - EL1
+//!
+//! Generates Secure Group 0 SGIs.
+
+use tock_registers::interfaces::Writeable;
+
+described_bitfields! {u64,
+    pub ICC_SGI0R_EL1 [
+        /// The affinity 3 value of the affinity path of the cluster for which SGI interrupts will
+        /// be generated.
+        Aff3 OFFSET(48) NUMBITS(8) [],
+
+        /// RangeSelector. Controls which group of 16 values is represented by the TargetList field.
+        ///
+        /// TargetList\[n\] represents aff0 value ((RS * 16) + n). Values other than 0 require
+        /// `ICC_CTLR_EL1.RSS` to be set.
+        RS OFFSET(44) NUMBITS(4) [],
+
+        /// Interrupt Routing Mode.
+        IRM OFFSET(40) NUMBITS(1) [
+            /// Interrupts are routed to the PEs specified by Aff3.Aff2.Aff1.\<target list\>.
+            Affinity = 0,
+
+            /// Interrupts are routed to all PEs in the system, excluding "self".
+            AllOthers = 1
+        ],
+
+        /// The affinity 2 value of the affinity path of the cluster for which SGI interrupts will
+        /// be generated.
+        Aff2 OFFSET(32) NUMBITS(8) [],
+
+        /// The INTID of the SGI.
+        INTID OFFSET(24) NUMBITS(4) [],
+
+        /// The affinity 1 value of the affinity path of the cluster for which SGI interrupts will
+        /// be generated.
+        Aff1 OFFSET(16) NUMBITS(8) [],
+
+        /// Target List. The set of PEs for which SGI interrupts will be generated. Each bit
+        /// corresponds to the PE within a cluster with an Affinity 0 value equal to the bit number.
+        TargetList OFFSET(0) NUMBITS(16) []
+    ]
+}
+
+pub struct Reg;
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_SGI0R_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_SGI0R_EL1", "x");
+}
+
+pub const ICC_SGI0R_EL1: Reg = Reg {};
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller Software Generated Interrupt Group 1 Register - EL1
+//!
+//! Generates Group 1 SGIs for the current Security state.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::registers::*;
+//!
+//! // Wake up the PE with affinity 0.0.1.2.
+//! ICC_SGI1R_EL1.send(0, 0x0000_0102);
+//! ```
+
+use super::MPIDR_EL1;
+use tock_registers::{fields::FieldValue, interfaces::Writeable, LocalRegisterCopy};
+
+described_bitfields! {u64,
+    pub ICC_SGI1R_EL1 [
+        /// The affinity 3 value of the affinity path of the cluster for which SGI interrupts will
+        /// be generated.
+        Aff3 OFFSET(48) NUMBITS(8) [],
+
+        /// RangeSelector. Controls which group of 16 values is represented by the TargetList field.
+        ///
+        /// TargetList\[n\] represents aff0 value ((RS * 16) + n). Values other than 0 require
+        /// `ICC_CTLR_EL1.RSS` to be set.
+        RS OFFSET(44) NUMBITS(4) [],
+
+        /// Interrupt Routing Mode.
+        IRM OFFSET(40) NUMBITS(1) [
+            /// Interrupts are routed to the PEs specified by Aff3.Aff2.Aff1.\<target list\>.
+            Affinity = 0,
+
+            /// Interrupts are routed to all PEs in the system, excluding "self".
+            AllOthers = 1
+        ],
+
+        /// The affinity 2 value of the affinity path of the cluster for which SGI interrupts will
+        /// be generated.
+        Aff2 OFFSET(32) NUMBITS(8) [],
+
+        /// The INTID of the SGI.
+        INTID OFFSET(24) NUMBITS(4) [],
+
+        /// The affinity 1 value of the affinity path of the cluster for which SGI interrupts will
+        /// be generated.
+        Aff1 OFFSET(16) NUMBITS(8) [],
+
+        /// Target List. The set of PEs for which SGI interrupts will be generated. Each bit
+        /// corresponds to the PE within a cluster with an Affinity 0 value equal to the bit number.
+        TargetList OFFSET(0) NUMBITS(16) []
+    ]
+}
+
+pub struct Reg;
+
+impl Reg {
+    /// Returns the value that targets SGI `intid` at the PE with the `MPIDR_EL1` value `mpidr`.
+    ///
+    /// Affinity 0 values of 16 and above require `ICC_CTLR_EL1.RSS` to be set.
+    pub fn target(&self, intid: u8, mpidr: u64) -> FieldValue<u64, ICC_SGI1R_EL1::Register> {
+        let mpidr: LocalRegisterCopy<u64, MPIDR_EL1::Register> = LocalRegisterCopy::new(mpidr);
+        let aff0 = mpidr.read(MPIDR_EL1::Aff0);
+
+        ICC_SGI1R_EL1::Aff3.val(mpidr.read(MPIDR_EL1::Aff3))
+            + ICC_SGI1R_EL1::Aff2.val(mpidr.read(MPIDR_EL1::Aff2))
+            + ICC_SGI1R_EL1::Aff1.val(mpidr.read(MPIDR_EL1::Aff1))
+            + ICC_SGI1R_EL1::RS.val(aff0 / 16)
+            + ICC_SGI1R_EL1::TargetList.val(1 << (aff0 % 16))
+            + ICC_SGI1R_EL1::INTID.val(intid as u64)
+    }
+
+    /// Generates SGI `intid` for the PE with the `MPIDR_EL1` value `mpidr`.
+    #[inline(always)]
+    pub fn send(&self, intid: u8, mpidr: u64) {
+        self.write(self.target(intid, mpidr));
+    }
+
+    /// Generates SGI `intid` for all PEs other than the current one.
+    #[inline(always)]
+    pub fn send_to_others(&self, intid: u8) {
+        self.write(ICC_SGI1R_EL1::IRM::AllOthers + ICC_SGI1R_EL1::INTID.val(intid as u64));
+    }
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_SGI1R_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_SGI1R_EL1", "x");
+}
+
+pub const ICC_SGI1R_EL1: Reg = Reg {};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target() {
+        // Aff3 = 1, U, Aff2 = 2, Aff1 = 3, Aff0 = 0x14.
+        let value = ICC_SGI1R_EL1.target(5, 0x1_4002_0314).value;
+
+        assert_eq!(value, 0x0001_1002_0503_0010);
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller System Register Enable Register - EL1
+//!
+//! Controls whether the System register interface or the memory-mapped interface to the GIC CPU
+//! interface is used for EL0 and EL1.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub ICC_SRE_EL1 [
+        /// Disable IRQ bypass.
+        ///
+        /// 0 IRQ bypass enabled.
+        ///
+        /// 1 IRQ bypass disabled.
+        DIB OFFSET(2) NUMBITS(1) [],
+
+        /// Disable FIQ bypass.
+        ///
+        /// 0 FIQ bypass enabled.
+        ///
+        /// 1 FIQ bypass disabled.
+        DFB OFFSET(1) NUMBITS(1) [],
+
+        /// System Register Enable.
+        ///
+        /// 0 The memory-mapped interface must be used.
+        ///
+        /// 1 The System register interface for the current Security state is enabled.
+        SRE OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_SRE_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_SRE_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_SRE_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_SRE_EL1", "x");
+}
+
+pub const ICC_SRE_EL1: Reg = Reg {};
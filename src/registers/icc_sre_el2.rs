@@ -10,12 +10,9 @@
 //! Controls whether the System register interface or the memory-mapped interface
 //! to the GIC CPU interface is used for EL2.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ICC_SRE_EL2 [
         /// Enables lower Exception level access to ICC_SRE_EL1.
         ///
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Interrupt Controller System Register Enable Register - EL3
+//!
+//! Controls whether the System register interface or the memory-mapped interface to the GIC CPU
+//! interface is used for EL3.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub ICC_SRE_EL3 [
+        /// Enables lower Exception level access to `ICC_SRE_EL1` and `ICC_SRE_EL2`.
+        ///
+        /// 0 EL1 and EL2 accesses to `ICC_SRE_EL1` or `ICC_SRE_EL2` trap to EL3.
+        ///
+        /// 1 EL2 accesses to `ICC_SRE_EL1` and `ICC_SRE_EL2` do not trap to EL3.
+        Enable OFFSET(3) NUMBITS(1) [],
+
+        /// Disable IRQ bypass.
+        ///
+        /// 0 IRQ bypass enabled.
+        ///
+        /// 1 IRQ bypass disabled.
+        DIB OFFSET(2) NUMBITS(1) [],
+
+        /// Disable FIQ bypass.
+        ///
+        /// 0 FIQ bypass enabled.
+        ///
+        /// 1 FIQ bypass disabled.
+        DFB OFFSET(1) NUMBITS(1) [],
+
+        /// System Register Enable.
+        ///
+        /// 0 The memory-mapped interface must be used.
+        ///
+        /// 1 The System register interface to the ICC_* registers is enabled for EL3.
+        SRE OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICC_SRE_EL3::Register;
+
+    sys_coproc_read_raw!(u64, "ICC_SRE_EL3", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICC_SRE_EL3::Register;
+
+    sys_coproc_write_raw!(u64, "ICC_SRE_EL3", "x");
+}
+
+pub const ICC_SRE_EL3: Reg = Reg {};
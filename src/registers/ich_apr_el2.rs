@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2024 by the author(s)
+//
+// Author(s):
+//   - Sangwan Kwon <sangwan.kwon@samsung.com>
+
+//! Interrupt Controller Hyp Active Priorities Group 0 and Group 1 Registers - EL2
+//!
+//! Provide information about Group 0 and Group 1 virtual active priorities for EL2.
+//!
+//! All Active Priorities registers share the [`ICH_APR_EL2`] layout and can be selected by index
+//! with [`ichap0r`] and [`ichap1r`]. `ICH_AP<m>R1_EL2` is only implemented with at least 6
+//! preemption bits, `ICH_AP<m>R2_EL2` and `ICH_AP<m>R3_EL2` only with 7, as indicated by
+//! `ICH_VTR_EL2.PREbits`.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub ICH_APR_EL2 [
+        /// When FEAT_GICv3_NMI is implemented, in `ICH_AP1R0_EL2` only:
+        /// Indicates whether a virtual interrupt with the Non-maskable property is active.
+        NMI OFFSET(63) NUMBITS(1) [],
+
+        /// Active priorities. Bit n corresponds to the priority group n + 32 * m of
+        /// `ICH_AP<g>R<m>_EL2`, and is set when an interrupt of that priority group is active.
+        P OFFSET(0) NUMBITS(32) [],
+    ]
+}
+
+pub struct Reg {
+    pub(super) index: u8,
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICH_APR_EL2::Register;
+
+    sys_coproc_read_raw_indexed!(u64, [
+        0 => "ICH_AP0R0_EL2",
+        1 => "ICH_AP0R1_EL2",
+        2 => "ICH_AP0R2_EL2",
+        3 => "ICH_AP0R3_EL2",
+        4 => "ICH_AP1R0_EL2",
+        5 => "ICH_AP1R1_EL2",
+        6 => "ICH_AP1R2_EL2",
+        7 => "ICH_AP1R3_EL2"
+    ], "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICH_APR_EL2::Register;
+
+    sys_coproc_write_raw_indexed!(u64, [
+        0 => "ICH_AP0R0_EL2",
+        1 => "ICH_AP0R1_EL2",
+        2 => "ICH_AP0R2_EL2",
+        3 => "ICH_AP0R3_EL2",
+        4 => "ICH_AP1R0_EL2",
+        5 => "ICH_AP1R1_EL2",
+        6 => "ICH_AP1R2_EL2",
+        7 => "ICH_AP1R3_EL2"
+    ], "x");
+}
+
+/// Returns Group 0 Active Priorities register `ICH_AP0R<n>_EL2`.
+///
+/// Panics if `n` is greater than 3.
+pub const fn ichap0r(n: u8) -> Reg {
+    assert!(n <= 3, "ICH_AP0R<n>_EL2 index out of range");
+
+    Reg { index: n }
+}
+
+/// Returns Group 1 Active Priorities register `ICH_AP1R<n>_EL2`.
+///
+/// Panics if `n` is greater than 3.
+pub const fn ichap1r(n: u8) -> Reg {
+    assert!(n <= 3, "ICH_AP1R<n>_EL2 index out of range");
+
+    Reg { index: 4 + n }
+}
+
+pub const ICH_AP0R0_EL2: Reg = ichap0r(0);
+pub const ICH_AP0R1_EL2: Reg = ichap0r(1);
+pub const ICH_AP0R2_EL2: Reg = ichap0r(2);
+pub const ICH_AP0R3_EL2: Reg = ichap0r(3);
+pub const ICH_AP1R0_EL2: Reg = ichap1r(0);
+pub const ICH_AP1R1_EL2: Reg = ichap1r(1);
+pub const ICH_AP1R2_EL2: Reg = ichap1r(2);
+pub const ICH_AP1R3_EL2: Reg = ichap1r(3);
@@ -9,12 +9,9 @@
 //!
 //! Controls the environment for VMs.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ICH_HCR_EL2 [
         /// This field is incremented whenever a successful write to a virtual EOIR or
         /// DIR register would have resulted in a virtual interrupt deactivation.
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2024 by the author(s)
+//
+// Author(s):
+//   - Sangwan Kwon <sangwan.kwon@samsung.com>
+
+//! Interrupt Controller List Registers - EL2
+//!
+//! Provides interrupt context information for the virtual CPU interface.
+//!
+//! All List registers share the [`ICH_LR_EL2`] layout and can be selected by index with
+//! [`ichlr`]. The number of implemented List registers is given by `ICH_VTR_EL2.ListRegs`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::registers::*;
+//!
+//! let lrs = ICH_VTR_EL2.read(ICH_VTR_EL2::ListRegs) as u8 + 1;
+//! let free = (0..lrs).find(|&n| ichlr(n).matches_all(ICH_LR_EL2::State::Invalid));
+//! ```
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub ICH_LR_EL2 [
+        /// The state of the interrupt.
+        State OFFSET(62) NUMBITS(2) [
+            Invalid = 0b00,
+            Pending = 0b01,
+            Active = 0b10,
+            PendingAndActive = 0b11,
+        ],
+
+        /// Indicates whether this virtual interrupt maps directly to a hardware interrupt, meaning
+        /// that it corresponds to a physical interrupt. Deactivation of the virtual interrupt also
+        /// causes the deactivation of the physical interrupt with the ID that the pINTID field
+        /// indicates.
+        HW OFFSET(61) NUMBITS(1) [],
+
+        /// Indicates the group for this virtual interrupt.
+        Group OFFSET(60) NUMBITS(1) [],
+
+        /// When FEAT_GICv3_NMI is implemented:
+        /// Indicates whether the virtual priority has the non-maskable property.
+        NMI OFFSET(59) NUMBITS(1) [],
+
+        /// The priority of this interrupt.
+        Priority OFFSET(48) NUMBITS(8) [],
+
+        /// Physical INTID, for hardware interrupts.
+        pINTID OFFSET(32) NUMBITS(13) [],
+
+        /// If this bit is 1, then when the interrupt identified by vINTID is deactivated,
+        /// a maintenance interrupt is asserted.
+        EOI OFFSET(41) NUMBITS(1) [],
+
+        /// Virtual INTID of the interrupt.
+        vINTID OFFSET(0) NUMBITS(32) [],
+    ]
+}
+
+/// The field definitions used to be named after the first List register.
+#[doc(hidden)]
+pub use ICH_LR_EL2 as ICH_LR0_EL2;
+
+pub struct Reg {
+    pub(super) index: u8,
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ICH_LR_EL2::Register;
+
+    sys_coproc_read_raw_indexed!(u64, [
+        0 => "ICH_LR0_EL2",
+        1 => "ICH_LR1_EL2",
+        2 => "ICH_LR2_EL2",
+        3 => "ICH_LR3_EL2",
+        4 => "ICH_LR4_EL2",
+        5 => "ICH_LR5_EL2",
+        6 => "ICH_LR6_EL2",
+        7 => "ICH_LR7_EL2",
+        8 => "ICH_LR8_EL2",
+        9 => "ICH_LR9_EL2",
+        10 => "ICH_LR10_EL2",
+        11 => "ICH_LR11_EL2",
+        12 => "ICH_LR12_EL2",
+        13 => "ICH_LR13_EL2",
+        14 => "ICH_LR14_EL2",
+        15 => "ICH_LR15_EL2"
+    ], "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ICH_LR_EL2::Register;
+
+    sys_coproc_write_raw_indexed!(u64, [
+        0 => "ICH_LR0_EL2",
+        1 => "ICH_LR1_EL2",
+        2 => "ICH_LR2_EL2",
+        3 => "ICH_LR3_EL2",
+        4 => "ICH_LR4_EL2",
+        5 => "ICH_LR5_EL2",
+        6 => "ICH_LR6_EL2",
+        7 => "ICH_LR7_EL2",
+        8 => "ICH_LR8_EL2",
+        9 => "ICH_LR9_EL2",
+        10 => "ICH_LR10_EL2",
+        11 => "ICH_LR11_EL2",
+        12 => "ICH_LR12_EL2",
+        13 => "ICH_LR13_EL2",
+        14 => "ICH_LR14_EL2",
+        15 => "ICH_LR15_EL2"
+    ], "x");
+}
+
+/// Returns List register `ICH_LR<n>_EL2`.
+///
+/// Panics if `n` is greater than 15.
+pub const fn ichlr(n: u8) -> Reg {
+    assert!(n <= 15, "ICH_LR<n>_EL2 index out of range");
+
+    Reg { index: n }
+}
+
+pub const ICH_LR0_EL2: Reg = ichlr(0);
+pub const ICH_LR1_EL2: Reg = ichlr(1);
+pub const ICH_LR2_EL2: Reg = ichlr(2);
+pub const ICH_LR3_EL2: Reg = ichlr(3);
+pub const ICH_LR4_EL2: Reg = ichlr(4);
+pub const ICH_LR5_EL2: Reg = ichlr(5);
+pub const ICH_LR6_EL2: Reg = ichlr(6);
+pub const ICH_LR7_EL2: Reg = ichlr(7);
+pub const ICH_LR8_EL2: Reg = ichlr(8);
+pub const ICH_LR9_EL2: Reg = ichlr(9);
+pub const ICH_LR10_EL2: Reg = ichlr(10);
+pub const ICH_LR11_EL2: Reg = ichlr(11);
+pub const ICH_LR12_EL2: Reg = ichlr(12);
+pub const ICH_LR13_EL2: Reg = ichlr(13);
+pub const ICH_LR14_EL2: Reg = ichlr(14);
+pub const ICH_LR15_EL2: Reg = ichlr(15);
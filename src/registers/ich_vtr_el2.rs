@@ -9,9 +9,9 @@
 //!
 //! Reports supported GIC virtualization features.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ICH_VTR_EL2 [
         /// Priority bits. Indicates the number of virtual priority bits implemented, minus one.
         PRIbits OFFSET(29) NUMBITS(3) [],
@@ -9,9 +9,9 @@
 //!
 //! Provides top level information about the debug system in AArch64 state.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ID_AA64DFR0_EL1 [
         /// Branch Record Buffer Extension.
         ///
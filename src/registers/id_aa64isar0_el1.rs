@@ -9,9 +9,9 @@
 //!
 //! Provides information about the implemented instruction set.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ID_AA64ISAR0_EL1 [
         /// Support for Random Number instructions in AArch64.
         ///
@@ -23,6 +23,61 @@ register_bitfields! {u64,
             Supported = 0b0001,
             NotSupported = 0b0000
         ],
+
+        /// Indicates support for Outer Shareable and TLB range maintenance instructions.
+        ///
+        /// 0000 Outer Shareable and TLB range maintenance instructions are not implemented.
+        /// 0001 Outer Shareable TLB maintenance instructions are implemented.
+        /// 0010 Outer Shareable and TLB range maintenance instructions are implemented.
+        ///
+        /// All other values are reserved.
+        TLB OFFSET(56) NUMBITS(4) [
+            NotSupported = 0b0000,
+            OuterShareable = 0b0001,
+            OuterShareableAndRange = 0b0010
+        ],
+
+        /// Indicates support for flag manipulation instructions. 0b0001 indicates FEAT_FlagM,
+        /// 0b0010 FEAT_FlagM2.
+        TS OFFSET(52) NUMBITS(4) [],
+
+        /// Indicates support for FMLAL and FMLSL instructions (FEAT_FHM).
+        FHM OFFSET(48) NUMBITS(4) [],
+
+        /// Indicates support for Dot Product instructions (FEAT_DotProd).
+        DP OFFSET(44) NUMBITS(4) [],
+
+        /// Indicates support for SM4 instructions (FEAT_SM4).
+        SM4 OFFSET(40) NUMBITS(4) [],
+
+        /// Indicates support for SM3 instructions (FEAT_SM3).
+        SM3 OFFSET(36) NUMBITS(4) [],
+
+        /// Indicates support for SHA3 instructions (FEAT_SHA3).
+        SHA3 OFFSET(32) NUMBITS(4) [],
+
+        /// Indicates support for SQRDMLAH and SQRDMLSH instructions (FEAT_RDM).
+        RDM OFFSET(28) NUMBITS(4) [],
+
+        /// Indicates support for TME instructions (FEAT_TME).
+        TME OFFSET(24) NUMBITS(4) [],
+
+        /// Indicates support for Atomic instructions. 0b0010 indicates FEAT_LSE, 0b0011
+        /// FEAT_LSE128.
+        Atomic OFFSET(20) NUMBITS(4) [],
+
+        /// Indicates support for CRC32 instructions (FEAT_CRC32).
+        CRC32 OFFSET(16) NUMBITS(4) [],
+
+        /// Indicates support for SHA2 instructions. 0b0001 indicates FEAT_SHA256, 0b0010
+        /// FEAT_SHA512.
+        SHA2 OFFSET(12) NUMBITS(4) [],
+
+        /// Indicates support for SHA1 instructions (FEAT_SHA1).
+        SHA1 OFFSET(8) NUMBITS(4) [],
+
+        /// Indicates support for AES instructions. 0b0001 indicates FEAT_AES, 0b0010 FEAT_PMULL.
+        AES OFFSET(4) NUMBITS(4) []
     ]
 }
 
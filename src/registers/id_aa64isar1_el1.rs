@@ -9,10 +9,38 @@
 //!
 //! Provides information about the features and instructions implemented in AArch64 state.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ID_AA64ISAR1_EL1 [
+        /// Indicates support for LD64B and ST64B* instructions. 0b0001 indicates FEAT_LS64,
+        /// 0b0010 FEAT_LS64_V, 0b0011 FEAT_LS64_ACCDATA.
+        LS64 OFFSET(60) NUMBITS(4) [],
+
+        /// Indicates support for the XS attribute (FEAT_XS).
+        XS OFFSET(56) NUMBITS(4) [],
+
+        /// Indicates support for Advanced SIMD and Floating-point Int8 matrix multiplication
+        /// instructions (FEAT_I8MM).
+        I8MM OFFSET(52) NUMBITS(4) [],
+
+        /// Indicates support for the Data Gathering Hint instruction (FEAT_DGH).
+        DGH OFFSET(48) NUMBITS(4) [],
+
+        /// Indicates support for BFloat16 instructions. 0b0001 indicates FEAT_BF16, 0b0010
+        /// FEAT_EBF16.
+        BF16 OFFSET(44) NUMBITS(4) [],
+
+        /// Indicates support for prediction invalidation instructions (FEAT_SPECRES).
+        SPECRES OFFSET(40) NUMBITS(4) [],
+
+        /// Indicates support for the SB instruction (FEAT_SB).
+        SB OFFSET(36) NUMBITS(4) [],
+
+        /// Indicates support for the FRINT32Z, FRINT32X, FRINT64Z and FRINT64X instructions
+        /// (FEAT_FRINTTS).
+        FRINTTS OFFSET(32) NUMBITS(4) [],
+
         /// Indicates support for an IMPLEMENTATION DEFINED algorithm is implemented in the PE for
         /// generic code authentication in AArch64 state.
         GPI OFFSET(28) NUMBITS(4) [
@@ -36,6 +64,20 @@ register_bitfields! {u64,
         /// authentication, in AArch64 state. This applies to all Pointer Authentication
         /// instructions other than the PACGA instruction.
         APA OFFSET(4) NUMBITS(4) [],
+
+        /// Indicates support for RCpc load-acquire instructions. 0b0001 indicates FEAT_LRCPC,
+        /// 0b0010 FEAT_LRCPC2, 0b0011 FEAT_LRCPC3.
+        LRCPC OFFSET(20) NUMBITS(4) [],
+
+        /// Indicates support for complex number addition and multiplication instructions
+        /// (FEAT_FCMA).
+        FCMA OFFSET(16) NUMBITS(4) [],
+
+        /// Indicates support for the FJCVTZS instruction (FEAT_JSCVT).
+        JSCVT OFFSET(12) NUMBITS(4) [],
+
+        /// Data Persistence writeback. 0b0001 indicates FEAT_DPB, 0b0010 FEAT_DPB2.
+        DPB OFFSET(0) NUMBITS(4) []
     ]
 }
 
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Instruction Set Attribute Register 2 - EL1
+//!
+//! Provides information about the features and instructions implemented in AArch64 state.
+
+use tock_registers::interfaces::Readable;
+
+described_bitfields! {u64,
+    pub ID_AA64ISAR2_EL1 [
+        /// Indicates support for Common Short Sequence Compression instructions (FEAT_CSSC).
+        CSSC OFFSET(52) NUMBITS(4) [],
+
+        /// Indicates support for the RPRFM hint instruction (FEAT_RPRFM).
+        RPRFM OFFSET(48) NUMBITS(4) [],
+
+        /// Indicates support for the CLRBHB instruction (FEAT_CLRBHB).
+        CLRBHB OFFSET(28) NUMBITS(4) [],
+
+        /// Indicates whether the ConstPACField() function returns TRUE (FEAT_CONSTPACFIELD).
+        PAC_frac OFFSET(24) NUMBITS(4) [],
+
+        /// Indicates support for the BC instruction (FEAT_HBC).
+        BC OFFSET(20) NUMBITS(4) [],
+
+        /// Indicates support for the Memory Copy and Memory Set instructions (FEAT_MOPS).
+        MOPS OFFSET(16) NUMBITS(4) [],
+
+        /// Indicates whether the QARMA3 algorithm is implemented in the PE for address
+        /// authentication in AArch64 state.
+        APA3 OFFSET(12) NUMBITS(4) [],
+
+        /// Indicates whether the QARMA3 algorithm is implemented in the PE for generic code
+        /// authentication in AArch64 state.
+        GPA3 OFFSET(8) NUMBITS(4) [],
+
+        /// Indicates increased precision of FRECPE and FRSQRTE (FEAT_RPRES).
+        RPRES OFFSET(4) NUMBITS(4) [],
+
+        /// Indicates support for the WFET and WFIT instructions. 0b0010 indicates FEAT_WFxT.
+        WFxT OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ID_AA64ISAR2_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ID_AA64ISAR2_EL1", "x");
+}
+
+pub const ID_AA64ISAR2_EL1: Reg = Reg {};
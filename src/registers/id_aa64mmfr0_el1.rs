@@ -10,10 +10,22 @@
 //! Provides information about the implemented memory model and memory
 //! management support in AArch64 state.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ID_AA64MMFR0_EL1 [
+        /// Indicates support for Enhanced Counter Virtualization. 0b0001 indicates FEAT_ECV,
+        /// 0b0010 additionally CNTHCTL_EL2.ECV and CNTPOFF_EL2.
+        ECV OFFSET(60) NUMBITS(4) [],
+
+        /// Indicates support for fine-grained trap controls. 0b0001 indicates FEAT_FGT, 0b0010
+        /// FEAT_FGT2.
+        FGT OFFSET(56) NUMBITS(4) [],
+
+        /// Indicates support for disabling context synchronizing exception entry and exit
+        /// (FEAT_ExS).
+        ExS OFFSET(44) NUMBITS(4) [],
+
         /// Support for 4KiB memory translation granule size. Defined values are:
         ///
         /// 0000 4KiB granule supported.
@@ -10,10 +10,33 @@
 //! Provides information about the implemented memory model and memory
 //! management support in AArch64 state.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ID_AA64MMFR1_EL1 [
+        /// Indicates support for the Clear Branch History instruction (FEAT_CLRBHB).
+        ECBHB OFFSET(60) NUMBITS(4) [],
+
+        /// Indicates support for cache maintenance instruction permission (FEAT_CMOW).
+        CMOW OFFSET(56) NUMBITS(4) [],
+
+        /// Indicates support for restrictive trapping of IMPLEMENTATION DEFINED System registers
+        /// (FEAT_TIDCP1).
+        TIDCP1 OFFSET(52) NUMBITS(4) [],
+
+        /// Indicates support for intermediate caching of translation table walks (FEAT_nTLBPA).
+        nTLBPA OFFSET(48) NUMBITS(4) [],
+
+        /// Indicates support for FPCR.{AH, FIZ, NEP} (FEAT_AFP).
+        AFP OFFSET(44) NUMBITS(4) [],
+
+        /// Indicates support for HCRX_EL2 (FEAT_HCX).
+        HCX OFFSET(40) NUMBITS(4) [],
+
+        /// Indicates support for Enhanced Translation Synchronization. 0b0010 indicates
+        /// FEAT_ETS2.
+        ETS OFFSET(36) NUMBITS(4) [],
+
         /// Support for configurable trapping delay of WFE instructions
         TWED OFFSET(32) NUMBITS(4) [
             /// Delaying the trapping of WFE instructions isn't supported
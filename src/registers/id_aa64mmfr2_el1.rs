@@ -10,9 +10,9 @@
 //! Provides information about the implemented memory model and memory
 //! management support in AArch64 state.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ID_AA64MMFR2_EL1 [
         /// Indicates support for the E0PD mechanism.
         E0PD OFFSET(60) NUMBITS(4) [],
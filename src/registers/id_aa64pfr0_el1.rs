@@ -9,15 +9,61 @@
 //!
 //! Provides additional information about implemented PE features in AArch64 state.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ID_AA64PFR0_EL1 [
+        /// Speculative use of faulting data (FEAT_CSV3).
+        CSV3 OFFSET(60) NUMBITS(4) [],
+
+        /// Speculative use of out of context branch targets (FEAT_CSV2).
+        CSV2 OFFSET(56) NUMBITS(4) [],
+
+        /// Indicates support for the Realm Management Extension (FEAT_RME).
+        RME OFFSET(52) NUMBITS(4) [],
+
+        /// Indicates support for Data Independent Timing (FEAT_DIT).
+        DIT OFFSET(48) NUMBITS(4) [],
+
         /// Indicates support for Activity Monitors Extension.
         AMU OFFSET(44) NUMBITS(4) [],
 
+        /// Indicates support for the Memory Partitioning and Monitoring Extension (FEAT_MPAM).
+        MPAM OFFSET(40) NUMBITS(4) [],
+
+        /// Indicates support for Secure EL2 (FEAT_SEL2).
+        SEL2 OFFSET(36) NUMBITS(4) [],
+
         /// Scalable Vector Extension.
         SVE OFFSET(32) NUMBITS(4) [],
+
+        /// Indicates support for the RAS Extension. 0b0001 indicates FEAT_RAS, 0b0010
+        /// FEAT_RASv1p1.
+        RAS OFFSET(28) NUMBITS(4) [],
+
+        /// Indicates support for the System register GIC CPU interface. 0b0001 indicates GICv3 or
+        /// GICv4, 0b0011 GICv4.1.
+        GIC OFFSET(24) NUMBITS(4) [],
+
+        /// Advanced SIMD. Signed field: 0b0000 indicates support, 0b0001 additional half-precision
+        /// support, 0b1111 no support.
+        AdvSIMD OFFSET(20) NUMBITS(4) [],
+
+        /// Floating-point. Signed field: 0b0000 indicates support, 0b0001 additional
+        /// half-precision support, 0b1111 no support.
+        FP OFFSET(16) NUMBITS(4) [],
+
+        /// EL3 Exception level handling. 0b0000 indicates that EL3 is not implemented.
+        EL3 OFFSET(12) NUMBITS(4) [],
+
+        /// EL2 Exception level handling. 0b0000 indicates that EL2 is not implemented.
+        EL2 OFFSET(8) NUMBITS(4) [],
+
+        /// EL1 Exception level handling.
+        EL1 OFFSET(4) NUMBITS(4) [],
+
+        /// EL0 Exception level handling.
+        EL0 OFFSET(0) NUMBITS(4) []
     ]
 }
 
@@ -9,12 +9,29 @@
 //!
 //! Provides additional information about implemented PE features in AArch64 state.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub ID_AA64PFR1_EL1 [
+        /// Indicates support for the Guarded Control Stack (FEAT_GCS).
+        GCS OFFSET(44) NUMBITS(4) [],
+
+        /// Indicates support for Non-maskable Interrupts (FEAT_NMI).
+        NMI OFFSET(36) NUMBITS(4) [],
+
+        /// Indicates support for the Scalable Matrix Extension. 0b0001 indicates FEAT_SME,
+        /// 0b0010 FEAT_SME2.
+        SME OFFSET(24) NUMBITS(4) [],
+
         /// Support for the Memory Tagging Extension.
         MTE OFFSET(8) NUMBITS(4) [],
+
+        /// Indicates support for the Speculative Store Bypass Safe mechanism. 0b0001 indicates
+        /// FEAT_SSBS, 0b0010 FEAT_SSBS2.
+        SSBS OFFSET(4) NUMBITS(4) [],
+
+        /// Indicates support for Branch Target Identification (FEAT_BTI).
+        BT OFFSET(0) NUMBITS(4) []
     ]
 }
 
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! SME Feature ID Register 0 - EL1
+//!
+//! Provides additional information about the implemented features of the AArch64 Scalable
+//! Matrix Extension, when FEAT_SME is implemented. Reads as zero otherwise.
+
+use tock_registers::interfaces::Readable;
+
+described_bitfields! {u64,
+    pub ID_AA64SMFR0_EL1 [
+        /// Indicates support for execution of the full A64 instruction set in Streaming SVE mode
+        /// (FEAT_SME_FA64).
+        FA64 OFFSET(63) NUMBITS(1) [],
+
+        /// Scalable Matrix Extension instructions version. 0b0001 indicates FEAT_SME2.
+        SMEver OFFSET(56) NUMBITS(4) [],
+
+        /// Indicates support for SME instructions that accumulate into 64-bit integer elements.
+        /// 0b1111 indicates FEAT_SME_I16I64.
+        I16I64 OFFSET(52) NUMBITS(4) [],
+
+        /// Indicates support for SME instructions that accumulate into FP64 double-precision
+        /// floating-point elements (FEAT_SME_F64F64).
+        F64F64 OFFSET(48) NUMBITS(1) [],
+
+        /// Indicates support for SME2 instructions that accumulate 16-bit outer products into 32-bit
+        /// integer tiles.
+        I16I32 OFFSET(44) NUMBITS(4) [],
+
+        /// Indicates support for SME2.1 non-widening BFloat16 instructions (FEAT_SVE_B16B16).
+        B16B16 OFFSET(43) NUMBITS(1) [],
+
+        /// Indicates support for SME2.1 non-widening half-precision instructions (FEAT_SME_F16F16).
+        F16F16 OFFSET(42) NUMBITS(1) [],
+
+        /// Indicates support for SME instructions that accumulate into 32-bit integer elements.
+        I8I32 OFFSET(36) NUMBITS(4) [],
+
+        /// Indicates support for SME instructions that accumulate half-precision outer products into
+        /// FP32 tiles.
+        F16F32 OFFSET(35) NUMBITS(1) [],
+
+        /// Indicates support for SME instructions that accumulate BFloat16 outer products into FP32
+        /// tiles.
+        B16F32 OFFSET(34) NUMBITS(1) [],
+
+        /// Indicates support for SME instructions that accumulate 1-bit binary outer products into
+        /// 32-bit integer tiles.
+        BI32I32 OFFSET(33) NUMBITS(1) [],
+
+        /// Indicates support for SME instructions that accumulate FP32 outer products into FP32
+        /// tiles.
+        F32F32 OFFSET(32) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ID_AA64SMFR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ID_AA64SMFR0_EL1" = "S3_0_C0_C4_5", "x");
+}
+
+pub const ID_AA64SMFR0_EL1: Reg = Reg {};
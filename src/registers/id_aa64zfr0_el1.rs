@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! SVE Feature ID Register 0 - EL1
+//!
+//! Provides additional information about the implemented features of the AArch64 Scalable
+//! Vector Extension, when FEAT_SVE is implemented. Reads as zero otherwise.
+
+use tock_registers::interfaces::Readable;
+
+described_bitfields! {u64,
+    pub ID_AA64ZFR0_EL1 [
+        /// Indicates support for the SVE FP64 double-precision floating-point matrix multiplication
+        /// instruction (FEAT_F64MM).
+        F64MM OFFSET(56) NUMBITS(4) [],
+
+        /// Indicates support for the SVE FP32 single-precision floating-point matrix multiplication
+        /// instruction (FEAT_F32MM).
+        F32MM OFFSET(52) NUMBITS(4) [],
+
+        /// Indicates support for SVE Int8 matrix multiplication instructions.
+        I8MM OFFSET(44) NUMBITS(4) [],
+
+        /// Indicates support for SVE SM4 instructions (FEAT_SVE_SM4).
+        SM4 OFFSET(40) NUMBITS(4) [],
+
+        /// Indicates support for the SVE SHA3 instructions (FEAT_SVE_SHA3).
+        SHA3 OFFSET(32) NUMBITS(4) [],
+
+        /// Indicates support for SVE2.1 non-widening BFloat16 instructions (FEAT_SVE_B16B16).
+        B16B16 OFFSET(24) NUMBITS(4) [],
+
+        /// Indicates support for SVE BFloat16 instructions.
+        BF16 OFFSET(20) NUMBITS(4) [],
+
+        /// Indicates support for SVE bit permute instructions (FEAT_SVE_BitPerm).
+        BitPerm OFFSET(16) NUMBITS(4) [],
+
+        /// Indicates support for SVE AES instructions. 0b0001 indicates FEAT_SVE_AES, 0b0010
+        /// FEAT_SVE_PMULL128.
+        AES OFFSET(4) NUMBITS(4) [],
+
+        /// Scalable Vector Extension instructions version. 0b0001 indicates FEAT_SVE2, 0b0010
+        /// FEAT_SVE2p1.
+        SVEver OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ID_AA64ZFR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ID_AA64ZFR0_EL1" = "S3_0_C0_C4_4", "x");
+}
+
+pub const ID_AA64ZFR0_EL1: Reg = Reg {};
@@ -5,61 +5,111 @@
 // Author(s):
 //   - Andre Richter <andre.o.richter@gmail.com>
 
+/// Reads a register, naming it `$name` in the mock backend.
+///
+/// Additional operands, e.g. `const` operands referenced by `$asm_reg_name`, are passed on to
+/// `asm!`.
+macro_rules! __read_reg {
+    ($width:ty, $asm_instr:tt, $name:expr, $asm_reg_name:tt, $asm_width:tt $(, $($operands:tt)+)?) => {
+        match () {
+            #[cfg(all(target_arch = "aarch64", not(feature = "mock")))]
+            () => {
+                let reg: $width;
+                unsafe {
+                    core::arch::asm!(concat!($asm_instr, " {reg:", $asm_width, "}, ", $asm_reg_name), reg = out(reg) reg, $($($operands)+,)? options(nomem, nostack));
+                }
+                reg
+            }
+
+            #[cfg(feature = "mock")]
+            () => crate::registers::mock::read($name) as $width,
+
+            #[cfg(not(any(target_arch = "aarch64", feature = "mock")))]
+            () => unimplemented!(),
+        }
+    };
+}
+
+/// Writes `$value` to a register, naming it `$name` in the mock backend.
+///
+/// Additional operands are passed on to `asm!` as for `__read_reg!`.
+macro_rules! __write_reg {
+    ($width:ty, $asm_instr:tt, $name:expr, $asm_reg_name:tt, $asm_width:tt, $value:expr $(, $($operands:tt)+)?) => {
+        match () {
+            #[cfg(all(target_arch = "aarch64", not(feature = "mock")))]
+            () => {
+                unsafe {
+                    core::arch::asm!(concat!($asm_instr, " ", $asm_reg_name, ", {reg:", $asm_width, "}"), reg = in(reg) $value, $($($operands)+,)? options(nomem, nostack))
+                }
+            }
+
+            #[cfg(feature = "mock")]
+            () => crate::registers::mock::write($name, $value as u64),
+
+            #[cfg(not(any(target_arch = "aarch64", feature = "mock")))]
+            () => unimplemented!(),
+        }
+    };
+}
+
 macro_rules! __read_raw {
     ($width:ty, $asm_instr:tt, $asm_reg_name:tt, $asm_width:tt) => {
+        __read_raw!($width, $asm_instr, $asm_reg_name, $asm_reg_name, $asm_width);
+    };
+
+    ($width:ty, $asm_instr:tt, $name:tt, $asm_reg_name:tt, $asm_width:tt) => {
         /// Reads the raw bits of the CPU register.
         #[inline]
         fn get(&self) -> $width {
-            match () {
-                #[cfg(target_arch = "aarch64")]
-                () => {
-                    let reg;
-                    unsafe {
-                        core::arch::asm!(concat!($asm_instr, " {reg:", $asm_width, "}, ", $asm_reg_name), reg = out(reg) reg, options(nomem, nostack));
-                    }
-                    reg
-                }
-
-                #[cfg(not(target_arch = "aarch64"))]
-                () => unimplemented!(),
-            }
+            __read_reg!($width, $asm_instr, $name, $asm_reg_name, $asm_width)
         }
     };
 }
 
 macro_rules! __write_raw {
     ($width:ty, $asm_instr:tt, $asm_reg_name:tt, $asm_width:tt) => {
+        __write_raw!($width, $asm_instr, $asm_reg_name, $asm_reg_name, $asm_width);
+    };
+
+    ($width:ty, $asm_instr:tt, $name:tt, $asm_reg_name:tt, $asm_width:tt) => {
         /// Writes raw bits to the CPU register.
-        #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+        #[cfg_attr(
+            not(any(target_arch = "aarch64", feature = "mock")),
+            allow(unused_variables)
+        )]
         #[inline]
         fn set(&self, value: $width) {
-            match () {
-                #[cfg(target_arch = "aarch64")]
-                () => {
-                    unsafe {
-                        core::arch::asm!(concat!($asm_instr, " ", $asm_reg_name, ", {reg:", $asm_width, "}"), reg = in(reg) value, options(nomem, nostack))
-                    }
-                }
-
-                #[cfg(not(target_arch = "aarch64"))]
-                () => unimplemented!(),
-            }
+            __write_reg!($width, $asm_instr, $name, $asm_reg_name, $asm_width, value)
         }
     };
 }
 
 /// Raw read from system coprocessor registers.
+///
+/// Registers that are not known to all assemblers can be accessed by their generic
+/// `S<op0>_<op1>_C<n>_C<m>_<op2>` encoding with `"NAME" = "S3_..."`.
 macro_rules! sys_coproc_read_raw {
     ($width:ty, $asm_reg_name:tt, $asm_width:tt) => {
         __read_raw!($width, "mrs", $asm_reg_name, $asm_width);
     };
+
+    ($width:ty, $name:tt = $asm_reg_name:tt, $asm_width:tt) => {
+        __read_raw!($width, "mrs", $name, $asm_reg_name, $asm_width);
+    };
 }
 
 /// Raw write to system coprocessor registers.
+///
+/// Registers that are not known to all assemblers can be accessed by their generic
+/// `S<op0>_<op1>_C<n>_C<m>_<op2>` encoding with `"NAME" = "S3_..."`.
 macro_rules! sys_coproc_write_raw {
     ($width:ty, $asm_reg_name:tt, $asm_width:tt) => {
         __write_raw!($width, "msr", $asm_reg_name, $asm_width);
     };
+
+    ($width:ty, $name:tt = $asm_reg_name:tt, $asm_width:tt) => {
+        __write_raw!($width, "msr", $name, $asm_reg_name, $asm_width);
+    };
 }
 
 /// Raw read from (ordinary) registers.
@@ -74,3 +124,74 @@ macro_rules! write_raw {
         __write_raw!($width, "mov", $asm_reg_name, $asm_width);
     };
 }
+
+/// Raw read from one of an array of system coprocessor registers, selected by the `index` field
+/// of the register struct.
+macro_rules! sys_coproc_read_raw_indexed {
+    ($width:ty, [$($index:literal => $asm_reg_name:tt),+ $(,)?], $asm_width:tt) => {
+        /// Reads the raw bits of the CPU register.
+        #[inline]
+        fn get(&self) -> $width {
+            match self.index {
+                $($index => __read_reg!($width, "mrs", $asm_reg_name, $asm_reg_name, $asm_width),)+
+                _ => unreachable!(),
+            }
+        }
+    };
+}
+
+/// Raw write to one of an array of system coprocessor registers, selected by the `index` field
+/// of the register struct.
+macro_rules! sys_coproc_write_raw_indexed {
+    ($width:ty, [$($index:literal => $asm_reg_name:tt),+ $(,)?], $asm_width:tt) => {
+        /// Writes raw bits to the CPU register.
+        #[cfg_attr(not(any(target_arch = "aarch64", feature = "mock")), allow(unused_variables))]
+        #[inline]
+        fn set(&self, value: $width) {
+            match self.index {
+                $($index => __write_reg!($width, "msr", $asm_reg_name, $asm_reg_name, $asm_width, value),)+
+                _ => unreachable!(),
+            }
+        }
+    };
+}
+
+/// Defines the bitfields `$reg` like [`register_bitfields!`](tock_registers::register_bitfields),
+/// and implements [`RegisterFields`](crate::registers::metadata::RegisterFields) for them with
+/// all fields `$field` and their named values `$value`.
+macro_rules! described_bitfields {
+    {
+        u64,
+        $(#[$attr:meta])*
+        $vis:vis $reg:ident [
+            $(
+                $(#[$field_attr:meta])*
+                $field:ident OFFSET($offset:expr) NUMBITS($numbits:expr) [
+                    $($(#[$value_attr:meta])* $value:ident = $bits:expr),* $(,)?
+                ]
+            ),* $(,)?
+        ]
+    } => {
+        tock_registers::register_bitfields! {u64,
+            $(#[$attr])*
+            $vis $reg [
+                $(
+                    $(#[$field_attr])*
+                    $field OFFSET($offset) NUMBITS($numbits) [
+                        $($(#[$value_attr])* $value = $bits),*
+                    ]
+                ),*
+            ]
+        }
+
+        impl crate::registers::metadata::RegisterFields for $reg::Register {
+            const FIELDS: &'static [crate::registers::metadata::FieldInfo] = &[$(
+                crate::registers::metadata::FieldInfo::new(
+                    stringify!($field),
+                    $reg::$field,
+                    &[$((stringify!($value), $reg::$field::Value::$value as u64)),*],
+                )
+            ),*];
+        }
+    };
+}
@@ -11,12 +11,9 @@
 //! Provides the memory attribute encodings corresponding to the possible AttrIndx values in a
 //! Long-descriptor format translation table entry for stage 1 translations at EL1.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub MAIR_EL1 [
         /// Attribute 7
         Attr7_Normal_Outer OFFSET(60) NUMBITS(4) [
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Memory Attribute Indirection Register - EL12
+//!
+//! Accesses MAIR_EL1 from EL2 when HCR_EL2.E2H is 1, in which case MAIR_EL1 accesses its EL2
+//! counterpart. Uses the MAIR_EL1 layout. Requires FEAT_VHE.
+
+use crate::registers::MAIR_EL1;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = MAIR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "MAIR_EL12" = "S3_5_C10_C2_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = MAIR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "MAIR_EL12" = "S3_5_C10_C2_0", "x");
+}
+
+pub const MAIR_EL12: Reg = Reg {};
@@ -11,9 +11,9 @@
 //! transferring commands and data to a debug target. See DBGDTR_EL0 for additional architectural
 //! mappings. It is a component of the Debug Communications Channel.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub MDCCSR_EL0 [
         /// DTRRX full. Read-only view of the equivalent bit in the EDSCR.
         RXfull OFFSET(30) NUMBITS(1) [
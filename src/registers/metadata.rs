@@ -0,0 +1,1228 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Register metadata.
+//!
+//! Every register of this crate is described by a [`RegisterInfo`], which is available through
+//! [`Introspect::info`] and in the [`REGISTERS`] table. The table is sorted by encoding, so that
+//! registers accessed by trapped `MRS` and `MSR` instructions can be looked up with [`lookup`].
+//!
+//! Register values can be formatted by field with [`Decode::decode`] and [`RegisterInfo::decode`],
+//! which name the values of fields with their enum variant, e.g. `M=EL1h D=Masked`. Formatting
+//! does not allocate, so that it can be used in panic handlers.
+//!
+//! # Example
+//!
+//! ```
+//! use aarch64_cpu::registers::{
+//!     esr::MsrMrs,
+//!     metadata::{self, Introspect},
+//!     CNTV_CTL_EL0,
+//! };
+//!
+//! let info = CNTV_CTL_EL0.info();
+//! assert_eq!(info.encoding, Some((3, 3, 14, 3, 1)));
+//! assert_eq!(info.field("ISTATUS").map(|f| f.offset), Some(2));
+//!
+//! // `MRS x3, CNTV_CTL_EL0`, as reported by `ESR_EL2.ISS`.
+//! let iss = MsrMrs::from_iss(0x32_f867);
+//! assert_eq!(iss.register().map(|r| r.name), Some("CNTV_CTL_EL0"));
+//! ```
+//!
+//! ```
+//! use aarch64_cpu::registers::{metadata::Decode, SPSR_EL1};
+//! use tock_registers::LocalRegisterCopy;
+//!
+//! // E.g. `SPSR_EL1.extract()` in an exception handler.
+//! let spsr = LocalRegisterCopy::<u64, SPSR_EL1::Register>::new(0x3c5);
+//!
+//! assert!(spsr
+//!     .decode()
+//!     .to_string()
+//!     .ends_with("D=Masked A=Masked I=Masked F=Masked M=EL1h"));
+//! ```
+
+#![allow(non_upper_case_globals)]
+
+use core::fmt;
+use tock_registers::{fields::Field, LocalRegisterCopy, RegisterLongName};
+
+/// The `(op0, op1, CRn, CRm, op2)` encoding of a System register.
+pub type Encoding = (u8, u8, u8, u8, u8);
+
+/// Whether a register can be read, written, or both.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Access {
+    /// Only `MRS` is defined.
+    ReadOnly,
+    /// Only `MSR` is defined.
+    WriteOnly,
+    /// Both `MRS` and `MSR` are defined.
+    ReadWrite,
+}
+
+impl Access {
+    /// Returns whether the register can be read with `MRS`.
+    pub const fn is_readable(self) -> bool {
+        !matches!(self, Access::WriteOnly)
+    }
+
+    /// Returns whether the register can be written with `MSR`.
+    pub const fn is_writeable(self) -> bool {
+        !matches!(self, Access::ReadOnly)
+    }
+}
+
+/// A field of a register.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FieldInfo {
+    pub name: &'static str,
+    /// Position of the least significant bit.
+    pub offset: u8,
+    /// Number of bits.
+    pub width: u8,
+    /// The named values of the field.
+    pub values: &'static [(&'static str, u64)],
+}
+
+impl FieldInfo {
+    pub(crate) const fn new<R: RegisterLongName>(
+        name: &'static str,
+        field: Field<u64, R>,
+        values: &'static [(&'static str, u64)],
+    ) -> Self {
+        FieldInfo {
+            name,
+            offset: field.shift as u8,
+            width: field.mask.count_ones() as u8,
+            values,
+        }
+    }
+
+    /// The bits of the field, in their position in the register.
+    pub const fn mask(&self) -> u64 {
+        (u64::MAX >> (64 - self.width)) << self.offset
+    }
+
+    /// Extracts the value of the field from the register value `value`.
+    pub const fn read(&self, value: u64) -> u64 {
+        (value & self.mask()) >> self.offset
+    }
+
+    /// Returns the name of the field value `value`, if it has one.
+    pub fn value_name(&self, value: u64) -> Option<&'static str> {
+        self.values
+            .iter()
+            .find(|(_, v)| *v == value)
+            .map(|(name, _)| *name)
+    }
+}
+
+/// The value of a field, formatted by name if it has one, else as a number.
+struct FieldValue<'a>(&'a FieldInfo, u64);
+
+impl fmt::Display for FieldValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let FieldValue(field, value) = *self;
+
+        match field.value_name(value) {
+            Some(name) => f.write_str(name),
+            None if field.width == 1 => write!(f, "{}", value),
+            None => write!(f, "{:#x}", value),
+        }
+    }
+}
+
+impl fmt::Debug for FieldValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// The name of a field, formatted without quotes.
+struct FieldName(&'static str);
+
+impl fmt::Debug for FieldName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// A register value, formatted by field.
+///
+/// `Display` prints the fields separated by spaces, e.g. `D=Masked M=EL1h`, and `Debug` prints
+/// them as a map, e.g. `{D: Masked, M: EL1h}`. Bits outside of the fields are not printed.
+#[derive(Copy, Clone)]
+pub struct Decoded {
+    fields: &'static [FieldInfo],
+    value: u64,
+}
+
+impl Decoded {
+    /// The raw register value.
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl fmt::Display for Decoded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(
+                f,
+                "{}={}",
+                field.name,
+                FieldValue(field, field.read(self.value))
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Decoded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.fields.iter().map(|field| {
+                (
+                    FieldName(field.name),
+                    FieldValue(field, field.read(self.value)),
+                )
+            }))
+            .finish()
+    }
+}
+
+/// Bitfield definitions that describe their fields.
+pub trait RegisterFields: RegisterLongName {
+    const FIELDS: &'static [FieldInfo];
+}
+
+/// Formats local register copies by field.
+pub trait Decode {
+    fn decode(&self) -> Decoded;
+}
+
+impl<R: RegisterFields> Decode for LocalRegisterCopy<u64, R> {
+    fn decode(&self) -> Decoded {
+        Decoded {
+            fields: R::FIELDS,
+            value: self.get(),
+        }
+    }
+}
+
+/// Description of a register.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RegisterInfo {
+    /// The architectural name.
+    pub name: &'static str,
+    /// The encoding, for System registers.
+    pub encoding: Option<Encoding>,
+    /// The lowest Exception level at which the register can be accessed, possibly subject to
+    /// trapping and enable controls.
+    pub min_el: u8,
+    /// Whether `MRS` and `MSR` can access the register, at least at the highest Exception level
+    /// that can access it, as defined by the architecture.
+    pub access: Access,
+    /// The fields, as far as defined by this crate.
+    pub fields: &'static [FieldInfo],
+}
+
+impl RegisterInfo {
+    /// Returns the field named `name`.
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Formats the register value `value` by field.
+    pub const fn decode(&self, value: u64) -> Decoded {
+        Decoded {
+            fields: self.fields,
+            value,
+        }
+    }
+}
+
+/// Registers that describe themselves.
+pub trait Introspect {
+    fn info(&self) -> &'static RegisterInfo;
+}
+
+/// Returns the register named `name`, compared case-insensitively.
+pub fn by_name(name: &str) -> Option<&'static RegisterInfo> {
+    REGISTERS
+        .iter()
+        .find(|info| info.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns the register with the encoding `encoding`.
+///
+/// `DBGDTRRX_EL0` and `DBGDTRTX_EL0` share their encoding; use [`lookup`] to tell them apart.
+pub fn by_encoding(encoding: Encoding) -> Option<&'static RegisterInfo> {
+    with_encoding(encoding).next()
+}
+
+/// Returns the register accessed by an `MRS` instruction (`read`) or an `MSR` instruction with the
+/// encoding `encoding`.
+pub fn lookup(encoding: Encoding, read: bool) -> Option<&'static RegisterInfo> {
+    with_encoding(encoding).find(|info| {
+        if read {
+            info.access.is_readable()
+        } else {
+            info.access.is_writeable()
+        }
+    })
+}
+
+fn with_encoding(encoding: Encoding) -> impl Iterator<Item = &'static RegisterInfo> {
+    let start = REGISTERS.partition_point(|info| info.encoding < Some(encoding));
+
+    REGISTERS[start..]
+        .iter()
+        .take_while(move |info| info.encoding == Some(encoding))
+}
+
+/// Declares the descriptors of the registers `$name`, with the fields of the bitfield definitions
+/// `$fields`.
+macro_rules! describe {
+    (@el EL0) => { 0 };
+    (@el EL1) => { 1 };
+    (@el EL2) => { 2 };
+    (@el EL3) => { 3 };
+
+    (@fields) => { &[] };
+    (@fields $fields:ident) => {
+        <super::$fields::Register as RegisterFields>::FIELDS
+    };
+
+    ($(
+        $name:ident = $encoding:expr, $el:ident, $access:ident $(, $fields:ident)?;
+    )*) => {
+        $(
+            const $name: RegisterInfo = RegisterInfo {
+                name: stringify!($name),
+                encoding: $encoding,
+                min_el: describe!(@el $el),
+                access: Access::$access,
+                fields: describe!(@fields $($fields)?),
+            };
+        )*
+    };
+}
+
+/// Implements [`Introspect`] for the register in module `$module`, or for the register array in
+/// module `$module` selected by index.
+macro_rules! introspect {
+    (@info $name:ident $self:ident) => {
+        &$name
+    };
+
+    (@info [$($index:literal => $name:ident),* $(,)?] $self:ident) => {
+        match $self.index {
+            $($index => &$name,)*
+            _ => unreachable!(),
+        }
+    };
+
+    (@all $name:ident) => {
+        &[$name]
+    };
+
+    (@all [$($index:literal => $name:ident),* $(,)?]) => {
+        &[$($name),*]
+    };
+
+    ($($module:ident => $info:tt;)*) => {
+        $(
+            impl Introspect for super::$module::Reg {
+                fn info(&self) -> &'static RegisterInfo {
+                    introspect!(@info $info self)
+                }
+            }
+        )*
+
+        /// The descriptors of all registers implementing [`Introspect`].
+        #[cfg(test)]
+        const INTROSPECTED: &[&[RegisterInfo]] = &[$(introspect!(@all $info)),*];
+    };
+}
+
+// Sorted by encoding, registers without an encoding first.
+describe! {
+    FP = None, EL0, ReadWrite;
+    LR = None, EL0, ReadWrite;
+    SP = None, EL0, ReadWrite;
+    OSLAR_EL1 = Some((2, 0, 1, 0, 4)), EL1, WriteOnly, OSLAR_EL1;
+    MDCCSR_EL0 = Some((2, 3, 0, 1, 0)), EL0, ReadOnly, MDCCSR_EL0;
+    DBGDTR_EL0 = Some((2, 3, 0, 4, 0)), EL0, ReadWrite, DBGDTR_EL0;
+    DBGDTRRX_EL0 = Some((2, 3, 0, 5, 0)), EL0, ReadOnly;
+    DBGDTRTX_EL0 = Some((2, 3, 0, 5, 0)), EL0, WriteOnly;
+    MIDR_EL1 = Some((3, 0, 0, 0, 0)), EL1, ReadOnly, MIDR_EL1;
+    MPIDR_EL1 = Some((3, 0, 0, 0, 5)), EL1, ReadOnly, MPIDR_EL1;
+    ID_AA64PFR0_EL1 = Some((3, 0, 0, 4, 0)), EL1, ReadOnly, ID_AA64PFR0_EL1;
+    ID_AA64PFR1_EL1 = Some((3, 0, 0, 4, 1)), EL1, ReadOnly, ID_AA64PFR1_EL1;
+    ID_AA64ZFR0_EL1 = Some((3, 0, 0, 4, 4)), EL1, ReadOnly, ID_AA64ZFR0_EL1;
+    ID_AA64SMFR0_EL1 = Some((3, 0, 0, 4, 5)), EL1, ReadOnly, ID_AA64SMFR0_EL1;
+    ID_AA64DFR0_EL1 = Some((3, 0, 0, 5, 0)), EL1, ReadOnly, ID_AA64DFR0_EL1;
+    ID_AA64DFR1_EL1 = Some((3, 0, 0, 5, 1)), EL1, ReadOnly;
+    ID_AA64AFR0_EL1 = Some((3, 0, 0, 5, 4)), EL1, ReadOnly;
+    ID_AA64AFR1_EL1 = Some((3, 0, 0, 5, 5)), EL1, ReadOnly;
+    ID_AA64ISAR0_EL1 = Some((3, 0, 0, 6, 0)), EL1, ReadOnly, ID_AA64ISAR0_EL1;
+    ID_AA64ISAR1_EL1 = Some((3, 0, 0, 6, 1)), EL1, ReadOnly, ID_AA64ISAR1_EL1;
+    ID_AA64ISAR2_EL1 = Some((3, 0, 0, 6, 2)), EL1, ReadOnly, ID_AA64ISAR2_EL1;
+    ID_AA64MMFR0_EL1 = Some((3, 0, 0, 7, 0)), EL1, ReadOnly, ID_AA64MMFR0_EL1;
+    ID_AA64MMFR1_EL1 = Some((3, 0, 0, 7, 1)), EL1, ReadOnly, ID_AA64MMFR1_EL1;
+    ID_AA64MMFR2_EL1 = Some((3, 0, 0, 7, 2)), EL1, ReadOnly, ID_AA64MMFR2_EL1;
+    SCTLR_EL1 = Some((3, 0, 1, 0, 0)), EL1, ReadWrite, SCTLR_EL1;
+    ACTLR_EL1 = Some((3, 0, 1, 0, 1)), EL1, ReadWrite;
+    CPACR_EL1 = Some((3, 0, 1, 0, 2)), EL1, ReadWrite, CPACR_EL1;
+    TTBR0_EL1 = Some((3, 0, 2, 0, 0)), EL1, ReadWrite, TTBR0_EL1;
+    TTBR1_EL1 = Some((3, 0, 2, 0, 1)), EL1, ReadWrite, TTBR1_EL1;
+    TCR_EL1 = Some((3, 0, 2, 0, 2)), EL1, ReadWrite, TCR_EL1;
+    APIAKEYLO_EL1 = Some((3, 0, 2, 1, 0)), EL1, ReadWrite;
+    APIAKEYHI_EL1 = Some((3, 0, 2, 1, 1)), EL1, ReadWrite;
+    APIBKEYLO_EL1 = Some((3, 0, 2, 1, 2)), EL1, ReadWrite;
+    APIBKEYHI_EL1 = Some((3, 0, 2, 1, 3)), EL1, ReadWrite;
+    APDAKEYLO_EL1 = Some((3, 0, 2, 2, 0)), EL1, ReadWrite;
+    APDAKEYHI_EL1 = Some((3, 0, 2, 2, 1)), EL1, ReadWrite;
+    APDBKEYLO_EL1 = Some((3, 0, 2, 2, 2)), EL1, ReadWrite;
+    APDBKEYHI_EL1 = Some((3, 0, 2, 2, 3)), EL1, ReadWrite;
+    APGAKEYLO_EL1 = Some((3, 0, 2, 3, 0)), EL1, ReadWrite;
+    APGAKEYHI_EL1 = Some((3, 0, 2, 3, 1)), EL1, ReadWrite;
+    SPSR_EL1 = Some((3, 0, 4, 0, 0)), EL1, ReadWrite, SPSR_EL1;
+    ELR_EL1 = Some((3, 0, 4, 0, 1)), EL1, ReadWrite;
+    SP_EL0 = Some((3, 0, 4, 1, 0)), EL1, ReadWrite;
+    SPSel = Some((3, 0, 4, 2, 0)), EL1, ReadWrite, SPSel;
+    CurrentEL = Some((3, 0, 4, 2, 2)), EL1, ReadOnly, CurrentEL;
+    PAN = Some((3, 0, 4, 2, 3)), EL1, ReadWrite, PAN;
+    UAO = Some((3, 0, 4, 2, 4)), EL1, ReadWrite, UAO;
+    ALLINT = Some((3, 0, 4, 3, 0)), EL1, ReadWrite, ALLINT;
+    ICC_PMR_EL1 = Some((3, 0, 4, 6, 0)), EL1, ReadWrite, ICC_PMR_EL1;
+    ESR_EL1 = Some((3, 0, 5, 2, 0)), EL1, ReadWrite, ESR_EL1;
+    FAR_EL1 = Some((3, 0, 6, 0, 0)), EL1, ReadWrite;
+    PAR_EL1 = Some((3, 0, 7, 4, 0)), EL1, ReadWrite, PAR_EL1;
+    PMINTENSET_EL1 = Some((3, 0, 9, 14, 1)), EL1, ReadWrite, PMINTENSET_EL1;
+    PMINTENCLR_EL1 = Some((3, 0, 9, 14, 2)), EL1, ReadWrite, PMINTENCLR_EL1;
+    MAIR_EL1 = Some((3, 0, 10, 2, 0)), EL1, ReadWrite, MAIR_EL1;
+    VBAR_EL1 = Some((3, 0, 12, 0, 0)), EL1, ReadWrite;
+    RVBAR_EL1 = Some((3, 0, 12, 0, 1)), EL1, ReadOnly;
+    ICC_IAR0_EL1 = Some((3, 0, 12, 8, 0)), EL1, ReadOnly, ICC_IAR0_EL1;
+    ICC_EOIR0_EL1 = Some((3, 0, 12, 8, 1)), EL1, WriteOnly, ICC_EOIR0_EL1;
+    ICC_HPPIR0_EL1 = Some((3, 0, 12, 8, 2)), EL1, ReadOnly, ICC_HPPIR0_EL1;
+    ICC_BPR0_EL1 = Some((3, 0, 12, 8, 3)), EL1, ReadWrite, ICC_BPR0_EL1;
+    ICC_NMIAR1_EL1 = Some((3, 0, 12, 9, 5)), EL1, ReadOnly, ICC_NMIAR1_EL1;
+    ICC_DIR_EL1 = Some((3, 0, 12, 11, 1)), EL1, WriteOnly, ICC_DIR_EL1;
+    ICC_RPR_EL1 = Some((3, 0, 12, 11, 3)), EL1, ReadOnly, ICC_RPR_EL1;
+    ICC_SGI1R_EL1 = Some((3, 0, 12, 11, 5)), EL1, WriteOnly, ICC_SGI1R_EL1;
+    ICC_ASGI1R_EL1 = Some((3, 0, 12, 11, 6)), EL1, WriteOnly, ICC_ASGI1R_EL1;
+    ICC_SGI0R_EL1 = Some((3, 0, 12, 11, 7)), EL1, WriteOnly, ICC_SGI0R_EL1;
+    ICC_IAR1_EL1 = Some((3, 0, 12, 12, 0)), EL1, ReadOnly, ICC_IAR1_EL1;
+    ICC_EOIR1_EL1 = Some((3, 0, 12, 12, 1)), EL1, WriteOnly, ICC_EOIR1_EL1;
+    ICC_HPPIR1_EL1 = Some((3, 0, 12, 12, 2)), EL1, ReadOnly, ICC_HPPIR1_EL1;
+    ICC_BPR1_EL1 = Some((3, 0, 12, 12, 3)), EL1, ReadWrite, ICC_BPR1_EL1;
+    ICC_CTLR_EL1 = Some((3, 0, 12, 12, 4)), EL1, ReadWrite, ICC_CTLR_EL1;
+    ICC_SRE_EL1 = Some((3, 0, 12, 12, 5)), EL1, ReadWrite, ICC_SRE_EL1;
+    ICC_IGRPEN0_EL1 = Some((3, 0, 12, 12, 6)), EL1, ReadWrite, ICC_IGRPEN0_EL1;
+    ICC_IGRPEN1_EL1 = Some((3, 0, 12, 12, 7)), EL1, ReadWrite, ICC_IGRPEN1_EL1;
+    CONTEXTIDR_EL1 = Some((3, 0, 13, 0, 1)), EL1, ReadWrite, CONTEXTIDR_EL1;
+    TPIDR_EL1 = Some((3, 0, 13, 0, 4)), EL1, ReadWrite;
+    CNTKCTL_EL1 = Some((3, 0, 14, 1, 0)), EL1, ReadWrite, CNTKCTL_EL1;
+    CCSIDR_EL1 = Some((3, 1, 0, 0, 0)), EL1, ReadOnly, CCSIDR_EL1;
+    CLIDR_EL1 = Some((3, 1, 0, 0, 1)), EL1, ReadOnly, CLIDR_EL1;
+    CSSELR_EL1 = Some((3, 2, 0, 0, 0)), EL1, ReadWrite, CSSELR_EL1;
+    CTR_EL0 = Some((3, 3, 0, 0, 1)), EL0, ReadOnly, CTR_EL0;
+    NZCV = Some((3, 3, 4, 2, 0)), EL0, ReadWrite, NZCV;
+    DAIF = Some((3, 3, 4, 2, 1)), EL0, ReadWrite, DAIF;
+    SVCR = Some((3, 3, 4, 2, 2)), EL0, ReadWrite, SVCR;
+    DIT = Some((3, 3, 4, 2, 5)), EL0, ReadWrite, DIT;
+    SSBS = Some((3, 3, 4, 2, 6)), EL0, ReadWrite, SSBS;
+    TCO = Some((3, 3, 4, 2, 7)), EL0, ReadWrite, TCO;
+    PMCR_EL0 = Some((3, 3, 9, 12, 0)), EL0, ReadWrite, PMCR_EL0;
+    PMCNTENSET_EL0 = Some((3, 3, 9, 12, 1)), EL0, ReadWrite, PMCNTENSET_EL0;
+    PMCNTENCLR_EL0 = Some((3, 3, 9, 12, 2)), EL0, ReadWrite, PMCNTENCLR_EL0;
+    PMOVSCLR_EL0 = Some((3, 3, 9, 12, 3)), EL0, ReadWrite, PMOVSCLR_EL0;
+    PMSELR_EL0 = Some((3, 3, 9, 12, 5)), EL0, ReadWrite, PMSELR_EL0;
+    PMCCNTR_EL0 = Some((3, 3, 9, 13, 0)), EL0, ReadWrite;
+    PMUSERENR_EL0 = Some((3, 3, 9, 14, 0)), EL0, ReadWrite, PMUSERENR_EL0;
+    PMOVSSET_EL0 = Some((3, 3, 9, 14, 3)), EL0, ReadWrite, PMOVSSET_EL0;
+    TPIDR_EL0 = Some((3, 3, 13, 0, 2)), EL0, ReadWrite;
+    TPIDRRO_EL0 = Some((3, 3, 13, 0, 3)), EL0, ReadWrite;
+    CNTFRQ_EL0 = Some((3, 3, 14, 0, 0)), EL0, ReadWrite;
+    CNTPCT_EL0 = Some((3, 3, 14, 0, 1)), EL0, ReadOnly;
+    CNTVCT_EL0 = Some((3, 3, 14, 0, 2)), EL0, ReadOnly;
+    CNTPCTSS_EL0 = Some((3, 3, 14, 0, 5)), EL0, ReadOnly;
+    CNTVCTSS_EL0 = Some((3, 3, 14, 0, 6)), EL0, ReadOnly;
+    CNTP_TVAL_EL0 = Some((3, 3, 14, 2, 0)), EL0, ReadWrite;
+    CNTP_CTL_EL0 = Some((3, 3, 14, 2, 1)), EL0, ReadWrite, CNTP_CTL_EL0;
+    CNTP_CVAL_EL0 = Some((3, 3, 14, 2, 2)), EL0, ReadWrite;
+    CNTV_TVAL_EL0 = Some((3, 3, 14, 3, 0)), EL0, ReadWrite;
+    CNTV_CTL_EL0 = Some((3, 3, 14, 3, 1)), EL0, ReadWrite, CNTV_CTL_EL0;
+    CNTV_CVAL_EL0 = Some((3, 3, 14, 3, 2)), EL0, ReadWrite;
+    PMEVCNTR0_EL0 = Some((3, 3, 14, 8, 0)), EL0, ReadWrite;
+    PMEVCNTR1_EL0 = Some((3, 3, 14, 8, 1)), EL0, ReadWrite;
+    PMEVCNTR2_EL0 = Some((3, 3, 14, 8, 2)), EL0, ReadWrite;
+    PMEVCNTR3_EL0 = Some((3, 3, 14, 8, 3)), EL0, ReadWrite;
+    PMEVCNTR4_EL0 = Some((3, 3, 14, 8, 4)), EL0, ReadWrite;
+    PMEVCNTR5_EL0 = Some((3, 3, 14, 8, 5)), EL0, ReadWrite;
+    PMEVCNTR6_EL0 = Some((3, 3, 14, 8, 6)), EL0, ReadWrite;
+    PMEVCNTR7_EL0 = Some((3, 3, 14, 8, 7)), EL0, ReadWrite;
+    PMEVCNTR8_EL0 = Some((3, 3, 14, 9, 0)), EL0, ReadWrite;
+    PMEVCNTR9_EL0 = Some((3, 3, 14, 9, 1)), EL0, ReadWrite;
+    PMEVCNTR10_EL0 = Some((3, 3, 14, 9, 2)), EL0, ReadWrite;
+    PMEVCNTR11_EL0 = Some((3, 3, 14, 9, 3)), EL0, ReadWrite;
+    PMEVCNTR12_EL0 = Some((3, 3, 14, 9, 4)), EL0, ReadWrite;
+    PMEVCNTR13_EL0 = Some((3, 3, 14, 9, 5)), EL0, ReadWrite;
+    PMEVCNTR14_EL0 = Some((3, 3, 14, 9, 6)), EL0, ReadWrite;
+    PMEVCNTR15_EL0 = Some((3, 3, 14, 9, 7)), EL0, ReadWrite;
+    PMEVCNTR16_EL0 = Some((3, 3, 14, 10, 0)), EL0, ReadWrite;
+    PMEVCNTR17_EL0 = Some((3, 3, 14, 10, 1)), EL0, ReadWrite;
+    PMEVCNTR18_EL0 = Some((3, 3, 14, 10, 2)), EL0, ReadWrite;
+    PMEVCNTR19_EL0 = Some((3, 3, 14, 10, 3)), EL0, ReadWrite;
+    PMEVCNTR20_EL0 = Some((3, 3, 14, 10, 4)), EL0, ReadWrite;
+    PMEVCNTR21_EL0 = Some((3, 3, 14, 10, 5)), EL0, ReadWrite;
+    PMEVCNTR22_EL0 = Some((3, 3, 14, 10, 6)), EL0, ReadWrite;
+    PMEVCNTR23_EL0 = Some((3, 3, 14, 10, 7)), EL0, ReadWrite;
+    PMEVCNTR24_EL0 = Some((3, 3, 14, 11, 0)), EL0, ReadWrite;
+    PMEVCNTR25_EL0 = Some((3, 3, 14, 11, 1)), EL0, ReadWrite;
+    PMEVCNTR26_EL0 = Some((3, 3, 14, 11, 2)), EL0, ReadWrite;
+    PMEVCNTR27_EL0 = Some((3, 3, 14, 11, 3)), EL0, ReadWrite;
+    PMEVCNTR28_EL0 = Some((3, 3, 14, 11, 4)), EL0, ReadWrite;
+    PMEVCNTR29_EL0 = Some((3, 3, 14, 11, 5)), EL0, ReadWrite;
+    PMEVCNTR30_EL0 = Some((3, 3, 14, 11, 6)), EL0, ReadWrite;
+    PMEVTYPER0_EL0 = Some((3, 3, 14, 12, 0)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER1_EL0 = Some((3, 3, 14, 12, 1)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER2_EL0 = Some((3, 3, 14, 12, 2)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER3_EL0 = Some((3, 3, 14, 12, 3)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER4_EL0 = Some((3, 3, 14, 12, 4)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER5_EL0 = Some((3, 3, 14, 12, 5)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER6_EL0 = Some((3, 3, 14, 12, 6)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER7_EL0 = Some((3, 3, 14, 12, 7)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER8_EL0 = Some((3, 3, 14, 13, 0)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER9_EL0 = Some((3, 3, 14, 13, 1)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER10_EL0 = Some((3, 3, 14, 13, 2)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER11_EL0 = Some((3, 3, 14, 13, 3)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER12_EL0 = Some((3, 3, 14, 13, 4)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER13_EL0 = Some((3, 3, 14, 13, 5)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER14_EL0 = Some((3, 3, 14, 13, 6)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER15_EL0 = Some((3, 3, 14, 13, 7)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER16_EL0 = Some((3, 3, 14, 14, 0)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER17_EL0 = Some((3, 3, 14, 14, 1)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER18_EL0 = Some((3, 3, 14, 14, 2)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER19_EL0 = Some((3, 3, 14, 14, 3)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER20_EL0 = Some((3, 3, 14, 14, 4)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER21_EL0 = Some((3, 3, 14, 14, 5)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER22_EL0 = Some((3, 3, 14, 14, 6)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER23_EL0 = Some((3, 3, 14, 14, 7)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER24_EL0 = Some((3, 3, 14, 15, 0)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER25_EL0 = Some((3, 3, 14, 15, 1)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER26_EL0 = Some((3, 3, 14, 15, 2)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER27_EL0 = Some((3, 3, 14, 15, 3)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER28_EL0 = Some((3, 3, 14, 15, 4)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER29_EL0 = Some((3, 3, 14, 15, 5)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMEVTYPER30_EL0 = Some((3, 3, 14, 15, 6)), EL0, ReadWrite, PMEVTYPER_EL0;
+    PMCCFILTR_EL0 = Some((3, 3, 14, 15, 7)), EL0, ReadWrite, PMCCFILTR_EL0;
+    SCTLR_EL2 = Some((3, 4, 1, 0, 0)), EL2, ReadWrite, SCTLR_EL2;
+    ACTLR_EL2 = Some((3, 4, 1, 0, 1)), EL2, ReadWrite;
+    HCR_EL2 = Some((3, 4, 1, 1, 0)), EL2, ReadWrite, HCR_EL2;
+    CPTR_EL2 = Some((3, 4, 1, 1, 2)), EL2, ReadWrite, CPTR_EL2;
+    HCRX_EL2 = Some((3, 4, 1, 2, 2)), EL2, ReadWrite, HCRX_EL2;
+    TTBR0_EL2 = Some((3, 4, 2, 0, 0)), EL2, ReadWrite, TTBR0_EL2;
+    TTBR1_EL2 = Some((3, 4, 2, 0, 1)), EL2, ReadWrite, TTBR1_EL2;
+    TCR_EL2 = Some((3, 4, 2, 0, 2)), EL2, ReadWrite, TCR_EL2;
+    VTTBR_EL2 = Some((3, 4, 2, 1, 0)), EL2, ReadWrite, VTTBR_EL2;
+    VTCR_EL2 = Some((3, 4, 2, 1, 2)), EL2, ReadWrite, VTCR_EL2;
+    DACR32_EL2 = Some((3, 4, 3, 0, 0)), EL2, ReadWrite, DACR32_EL2;
+    SPSR_EL2 = Some((3, 4, 4, 0, 0)), EL2, ReadWrite, SPSR_EL2;
+    ELR_EL2 = Some((3, 4, 4, 0, 1)), EL2, ReadWrite;
+    SP_EL1 = Some((3, 4, 4, 1, 0)), EL2, ReadWrite;
+    ESR_EL2 = Some((3, 4, 5, 2, 0)), EL2, ReadWrite, ESR_EL2;
+    FAR_EL2 = Some((3, 4, 6, 0, 0)), EL2, ReadWrite;
+    HPFAR_EL2 = Some((3, 4, 6, 0, 4)), EL2, ReadWrite, HPFAR_EL2;
+    MAIR_EL2 = Some((3, 4, 10, 2, 0)), EL2, ReadWrite, MAIR_EL2;
+    VBAR_EL2 = Some((3, 4, 12, 0, 0)), EL2, ReadWrite;
+    RVBAR_EL2 = Some((3, 4, 12, 0, 1)), EL2, ReadOnly;
+    ICH_AP0R0_EL2 = Some((3, 4, 12, 8, 0)), EL2, ReadWrite, ICH_APR_EL2;
+    ICH_AP0R1_EL2 = Some((3, 4, 12, 8, 1)), EL2, ReadWrite, ICH_APR_EL2;
+    ICH_AP0R2_EL2 = Some((3, 4, 12, 8, 2)), EL2, ReadWrite, ICH_APR_EL2;
+    ICH_AP0R3_EL2 = Some((3, 4, 12, 8, 3)), EL2, ReadWrite, ICH_APR_EL2;
+    ICH_AP1R0_EL2 = Some((3, 4, 12, 9, 0)), EL2, ReadWrite, ICH_APR_EL2;
+    ICH_AP1R1_EL2 = Some((3, 4, 12, 9, 1)), EL2, ReadWrite, ICH_APR_EL2;
+    ICH_AP1R2_EL2 = Some((3, 4, 12, 9, 2)), EL2, ReadWrite, ICH_APR_EL2;
+    ICH_AP1R3_EL2 = Some((3, 4, 12, 9, 3)), EL2, ReadWrite, ICH_APR_EL2;
+    ICC_SRE_EL2 = Some((3, 4, 12, 9, 5)), EL2, ReadWrite, ICC_SRE_EL2;
+    ICH_HCR_EL2 = Some((3, 4, 12, 11, 0)), EL2, ReadWrite, ICH_HCR_EL2;
+    ICH_VTR_EL2 = Some((3, 4, 12, 11, 1)), EL2, ReadOnly, ICH_VTR_EL2;
+    ICH_MISR_EL2 = Some((3, 4, 12, 11, 2)), EL2, ReadOnly;
+    ICH_VMCR_EL2 = Some((3, 4, 12, 11, 7)), EL2, ReadWrite;
+    ICH_LR0_EL2 = Some((3, 4, 12, 12, 0)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR1_EL2 = Some((3, 4, 12, 12, 1)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR2_EL2 = Some((3, 4, 12, 12, 2)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR3_EL2 = Some((3, 4, 12, 12, 3)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR4_EL2 = Some((3, 4, 12, 12, 4)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR5_EL2 = Some((3, 4, 12, 12, 5)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR6_EL2 = Some((3, 4, 12, 12, 6)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR7_EL2 = Some((3, 4, 12, 12, 7)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR8_EL2 = Some((3, 4, 12, 13, 0)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR9_EL2 = Some((3, 4, 12, 13, 1)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR10_EL2 = Some((3, 4, 12, 13, 2)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR11_EL2 = Some((3, 4, 12, 13, 3)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR12_EL2 = Some((3, 4, 12, 13, 4)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR13_EL2 = Some((3, 4, 12, 13, 5)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR14_EL2 = Some((3, 4, 12, 13, 6)), EL2, ReadWrite, ICH_LR_EL2;
+    ICH_LR15_EL2 = Some((3, 4, 12, 13, 7)), EL2, ReadWrite, ICH_LR_EL2;
+    CONTEXTIDR_EL2 = Some((3, 4, 13, 0, 1)), EL2, ReadWrite, CONTEXTIDR_EL2;
+    TPIDR_EL2 = Some((3, 4, 13, 0, 2)), EL2, ReadWrite;
+    CNTVOFF_EL2 = Some((3, 4, 14, 0, 3)), EL2, ReadWrite;
+    CNTSCALE_EL2 = Some((3, 4, 14, 0, 4)), EL2, ReadWrite;
+    CNTISCALE_EL2 = Some((3, 4, 14, 0, 5)), EL2, ReadWrite;
+    CNTPOFF_EL2 = Some((3, 4, 14, 0, 6)), EL2, ReadWrite;
+    CNTVFRQ_EL2 = Some((3, 4, 14, 0, 7)), EL2, ReadWrite;
+    CNTHCTL_EL2 = Some((3, 4, 14, 1, 0)), EL2, ReadWrite, CNTHCTL_EL2;
+    CNTHP_TVAL_EL2 = Some((3, 4, 14, 2, 0)), EL2, ReadWrite;
+    CNTHP_CTL_EL2 = Some((3, 4, 14, 2, 1)), EL2, ReadWrite, CNTHP_CTL_EL2;
+    CNTHP_CVAL_EL2 = Some((3, 4, 14, 2, 2)), EL2, ReadWrite;
+    CNTHV_TVAL_EL2 = Some((3, 4, 14, 3, 0)), EL2, ReadWrite;
+    CNTHV_CTL_EL2 = Some((3, 4, 14, 3, 1)), EL2, ReadWrite, CNTHV_CTL_EL2;
+    CNTHV_CVAL_EL2 = Some((3, 4, 14, 3, 2)), EL2, ReadWrite;
+    SCTLR_EL12 = Some((3, 5, 1, 0, 0)), EL2, ReadWrite, SCTLR_EL1;
+    CPACR_EL12 = Some((3, 5, 1, 0, 2)), EL2, ReadWrite, CPACR_EL1;
+    TTBR0_EL12 = Some((3, 5, 2, 0, 0)), EL2, ReadWrite, TTBR0_EL1;
+    TTBR1_EL12 = Some((3, 5, 2, 0, 1)), EL2, ReadWrite, TTBR1_EL1;
+    TCR_EL12 = Some((3, 5, 2, 0, 2)), EL2, ReadWrite, TCR_EL1;
+    SPSR_EL12 = Some((3, 5, 4, 0, 0)), EL2, ReadWrite, SPSR_EL1;
+    ELR_EL12 = Some((3, 5, 4, 0, 1)), EL2, ReadWrite;
+    ESR_EL12 = Some((3, 5, 5, 2, 0)), EL2, ReadWrite, ESR_EL1;
+    FAR_EL12 = Some((3, 5, 6, 0, 0)), EL2, ReadWrite;
+    MAIR_EL12 = Some((3, 5, 10, 2, 0)), EL2, ReadWrite, MAIR_EL1;
+    VBAR_EL12 = Some((3, 5, 12, 0, 0)), EL2, ReadWrite;
+    CONTEXTIDR_EL12 = Some((3, 5, 13, 0, 1)), EL2, ReadWrite, CONTEXTIDR_EL1;
+    CNTKCTL_EL12 = Some((3, 5, 14, 1, 0)), EL2, ReadWrite, CNTKCTL_EL1;
+    CNTP_TVAL_EL02 = Some((3, 5, 14, 2, 0)), EL2, ReadWrite;
+    CNTP_CTL_EL02 = Some((3, 5, 14, 2, 1)), EL2, ReadWrite, CNTP_CTL_EL0;
+    CNTP_CVAL_EL02 = Some((3, 5, 14, 2, 2)), EL2, ReadWrite;
+    CNTV_TVAL_EL02 = Some((3, 5, 14, 3, 0)), EL2, ReadWrite;
+    CNTV_CTL_EL02 = Some((3, 5, 14, 3, 1)), EL2, ReadWrite, CNTV_CTL_EL0;
+    CNTV_CVAL_EL02 = Some((3, 5, 14, 3, 2)), EL2, ReadWrite;
+    SCTLR_EL3 = Some((3, 6, 1, 0, 0)), EL3, ReadWrite, SCTLR_EL3;
+    ACTLR_EL3 = Some((3, 6, 1, 0, 1)), EL3, ReadWrite;
+    SCR_EL3 = Some((3, 6, 1, 1, 0)), EL3, ReadWrite, SCR_EL3;
+    SPSR_EL3 = Some((3, 6, 4, 0, 0)), EL3, ReadWrite, SPSR_EL3;
+    ELR_EL3 = Some((3, 6, 4, 0, 1)), EL3, ReadWrite;
+    ESR_EL3 = Some((3, 6, 5, 2, 0)), EL3, ReadWrite, ESR_EL3;
+    FAR_EL3 = Some((3, 6, 6, 0, 0)), EL3, ReadWrite;
+    VBAR_EL3 = Some((3, 6, 12, 0, 0)), EL3, ReadWrite;
+    RVBAR_EL3 = Some((3, 6, 12, 0, 1)), EL3, ReadOnly;
+    ICC_CTLR_EL3 = Some((3, 6, 12, 12, 4)), EL3, ReadWrite, ICC_CTLR_EL3;
+    ICC_SRE_EL3 = Some((3, 6, 12, 12, 5)), EL3, ReadWrite, ICC_SRE_EL3;
+    CNTPS_TVAL_EL1 = Some((3, 7, 14, 2, 0)), EL1, ReadWrite;
+    CNTPS_CTL_EL1 = Some((3, 7, 14, 2, 1)), EL1, ReadWrite, CNTPS_CTL_EL1;
+    CNTPS_CVAL_EL1 = Some((3, 7, 14, 2, 2)), EL1, ReadWrite;
+}
+
+introspect! {
+    actlr_el1 => ACTLR_EL1;
+    actlr_el2 => ACTLR_EL2;
+    actlr_el3 => ACTLR_EL3;
+    allint => ALLINT;
+    apdakeyhi_el1 => APDAKEYHI_EL1;
+    apdakeylo_el1 => APDAKEYLO_EL1;
+    apdbkeyhi_el1 => APDBKEYHI_EL1;
+    apdbkeylo_el1 => APDBKEYLO_EL1;
+    apgakeyhi_el1 => APGAKEYHI_EL1;
+    apgakeylo_el1 => APGAKEYLO_EL1;
+    apiakeyhi_el1 => APIAKEYHI_EL1;
+    apiakeylo_el1 => APIAKEYLO_EL1;
+    apibkeyhi_el1 => APIBKEYHI_EL1;
+    apibkeylo_el1 => APIBKEYLO_EL1;
+    ccsidr_el1 => CCSIDR_EL1;
+    clidr_el1 => CLIDR_EL1;
+    cntfrq_el0 => CNTFRQ_EL0;
+    cnthctl_el2 => CNTHCTL_EL2;
+    cnthp_ctl_el2 => CNTHP_CTL_EL2;
+    cnthp_cval_el2 => CNTHP_CVAL_EL2;
+    cnthp_tval_el2 => CNTHP_TVAL_EL2;
+    cnthv_ctl_el2 => CNTHV_CTL_EL2;
+    cnthv_cval_el2 => CNTHV_CVAL_EL2;
+    cnthv_tval_el2 => CNTHV_TVAL_EL2;
+    cntiscale_el2 => CNTISCALE_EL2;
+    cntkctl_el12 => CNTKCTL_EL12;
+    cntkctl_el1 => CNTKCTL_EL1;
+    cntp_ctl_el02 => CNTP_CTL_EL02;
+    cntp_ctl_el0 => CNTP_CTL_EL0;
+    cntp_cval_el02 => CNTP_CVAL_EL02;
+    cntp_cval_el0 => CNTP_CVAL_EL0;
+    cntp_tval_el02 => CNTP_TVAL_EL02;
+    cntp_tval_el0 => CNTP_TVAL_EL0;
+    cntpct_el0 => CNTPCT_EL0;
+    cntpctss_el0 => CNTPCTSS_EL0;
+    cntpoff_el2 => CNTPOFF_EL2;
+    cntps_ctl_el1 => CNTPS_CTL_EL1;
+    cntps_cval_el1 => CNTPS_CVAL_EL1;
+    cntps_tval_el1 => CNTPS_TVAL_EL1;
+    cntscale_el2 => CNTSCALE_EL2;
+    cntv_ctl_el02 => CNTV_CTL_EL02;
+    cntv_ctl_el0 => CNTV_CTL_EL0;
+    cntv_cval_el02 => CNTV_CVAL_EL02;
+    cntv_cval_el0 => CNTV_CVAL_EL0;
+    cntv_tval_el02 => CNTV_TVAL_EL02;
+    cntv_tval_el0 => CNTV_TVAL_EL0;
+    cntvct_el0 => CNTVCT_EL0;
+    cntvctss_el0 => CNTVCTSS_EL0;
+    cntvfrq_el2 => CNTVFRQ_EL2;
+    cntvoff_el2 => CNTVOFF_EL2;
+    contextidr_el12 => CONTEXTIDR_EL12;
+    contextidr_el1 => CONTEXTIDR_EL1;
+    contextidr_el2 => CONTEXTIDR_EL2;
+    cpacr_el12 => CPACR_EL12;
+    cpacr_el1 => CPACR_EL1;
+    cptr_el2 => CPTR_EL2;
+    csselr_el1 => CSSELR_EL1;
+    ctr_el0 => CTR_EL0;
+    currentel => CurrentEL;
+    dacr32_el2 => DACR32_EL2;
+    daif => DAIF;
+    dbgdtr_el0 => DBGDTR_EL0;
+    dbgdtrrx_el0 => DBGDTRRX_EL0;
+    dbgdtrtx_el0 => DBGDTRTX_EL0;
+    dit => DIT;
+    elr_el12 => ELR_EL12;
+    elr_el1 => ELR_EL1;
+    elr_el2 => ELR_EL2;
+    elr_el3 => ELR_EL3;
+    esr_el12 => ESR_EL12;
+    esr_el1 => ESR_EL1;
+    esr_el2 => ESR_EL2;
+    esr_el3 => ESR_EL3;
+    far_el12 => FAR_EL12;
+    far_el1 => FAR_EL1;
+    far_el2 => FAR_EL2;
+    far_el3 => FAR_EL3;
+    fp => FP;
+    hcr_el2 => HCR_EL2;
+    hcrx_el2 => HCRX_EL2;
+    hpfar_el2 => HPFAR_EL2;
+    icc_asgi1r_el1 => ICC_ASGI1R_EL1;
+    icc_bpr0_el1 => ICC_BPR0_EL1;
+    icc_bpr1_el1 => ICC_BPR1_EL1;
+    icc_ctlr_el1 => ICC_CTLR_EL1;
+    icc_ctlr_el3 => ICC_CTLR_EL3;
+    icc_dir_el1 => ICC_DIR_EL1;
+    icc_eoir0_el1 => ICC_EOIR0_EL1;
+    icc_eoir1_el1 => ICC_EOIR1_EL1;
+    icc_hppir0_el1 => ICC_HPPIR0_EL1;
+    icc_hppir1_el1 => ICC_HPPIR1_EL1;
+    icc_iar0_el1 => ICC_IAR0_EL1;
+    icc_iar1_el1 => ICC_IAR1_EL1;
+    icc_igrpen0_el1 => ICC_IGRPEN0_EL1;
+    icc_igrpen1_el1 => ICC_IGRPEN1_EL1;
+    icc_nmiar1_el1 => ICC_NMIAR1_EL1;
+    icc_pmr_el1 => ICC_PMR_EL1;
+    icc_rpr_el1 => ICC_RPR_EL1;
+    icc_sgi0r_el1 => ICC_SGI0R_EL1;
+    icc_sgi1r_el1 => ICC_SGI1R_EL1;
+    icc_sre_el1 => ICC_SRE_EL1;
+    icc_sre_el2 => ICC_SRE_EL2;
+    icc_sre_el3 => ICC_SRE_EL3;
+    ich_apr_el2 => [
+        0 => ICH_AP0R0_EL2, 1 => ICH_AP0R1_EL2, 2 => ICH_AP0R2_EL2, 3 => ICH_AP0R3_EL2,
+        4 => ICH_AP1R0_EL2, 5 => ICH_AP1R1_EL2, 6 => ICH_AP1R2_EL2, 7 => ICH_AP1R3_EL2,
+    ];
+    ich_hcr_el2 => ICH_HCR_EL2;
+    ich_lr_el2 => [
+        0 => ICH_LR0_EL2, 1 => ICH_LR1_EL2, 2 => ICH_LR2_EL2, 3 => ICH_LR3_EL2, 4 => ICH_LR4_EL2,
+        5 => ICH_LR5_EL2, 6 => ICH_LR6_EL2, 7 => ICH_LR7_EL2, 8 => ICH_LR8_EL2, 9 => ICH_LR9_EL2,
+        10 => ICH_LR10_EL2, 11 => ICH_LR11_EL2, 12 => ICH_LR12_EL2, 13 => ICH_LR13_EL2,
+        14 => ICH_LR14_EL2, 15 => ICH_LR15_EL2,
+    ];
+    ich_misr_el2 => ICH_MISR_EL2;
+    ich_vmcr_el2 => ICH_VMCR_EL2;
+    ich_vtr_el2 => ICH_VTR_EL2;
+    id_aa64afr0_el1 => ID_AA64AFR0_EL1;
+    id_aa64afr1_el1 => ID_AA64AFR1_EL1;
+    id_aa64dfr0_el1 => ID_AA64DFR0_EL1;
+    id_aa64dfr1_el1 => ID_AA64DFR1_EL1;
+    id_aa64isar0_el1 => ID_AA64ISAR0_EL1;
+    id_aa64isar1_el1 => ID_AA64ISAR1_EL1;
+    id_aa64isar2_el1 => ID_AA64ISAR2_EL1;
+    id_aa64mmfr0_el1 => ID_AA64MMFR0_EL1;
+    id_aa64mmfr1_el1 => ID_AA64MMFR1_EL1;
+    id_aa64mmfr2_el1 => ID_AA64MMFR2_EL1;
+    id_aa64pfr0_el1 => ID_AA64PFR0_EL1;
+    id_aa64pfr1_el1 => ID_AA64PFR1_EL1;
+    id_aa64smfr0_el1 => ID_AA64SMFR0_EL1;
+    id_aa64zfr0_el1 => ID_AA64ZFR0_EL1;
+    lr => LR;
+    mair_el1 => MAIR_EL1;
+    mair_el12 => MAIR_EL12;
+    mair_el2 => MAIR_EL2;
+    mdccsr_el0 => MDCCSR_EL0;
+    midr_el1 => MIDR_EL1;
+    mpidr_el1 => MPIDR_EL1;
+    nzcv => NZCV;
+    oslar_el1 => OSLAR_EL1;
+    pan => PAN;
+    par_el1 => PAR_EL1;
+    pmccfiltr_el0 => PMCCFILTR_EL0;
+    pmccntr_el0 => PMCCNTR_EL0;
+    pmcntenclr_el0 => PMCNTENCLR_EL0;
+    pmcntenset_el0 => PMCNTENSET_EL0;
+    pmcr_el0 => PMCR_EL0;
+    pmevcntr_el0 => [
+        0 => PMEVCNTR0_EL0, 1 => PMEVCNTR1_EL0, 2 => PMEVCNTR2_EL0, 3 => PMEVCNTR3_EL0,
+        4 => PMEVCNTR4_EL0, 5 => PMEVCNTR5_EL0, 6 => PMEVCNTR6_EL0, 7 => PMEVCNTR7_EL0,
+        8 => PMEVCNTR8_EL0, 9 => PMEVCNTR9_EL0, 10 => PMEVCNTR10_EL0, 11 => PMEVCNTR11_EL0,
+        12 => PMEVCNTR12_EL0, 13 => PMEVCNTR13_EL0, 14 => PMEVCNTR14_EL0, 15 => PMEVCNTR15_EL0,
+        16 => PMEVCNTR16_EL0, 17 => PMEVCNTR17_EL0, 18 => PMEVCNTR18_EL0, 19 => PMEVCNTR19_EL0,
+        20 => PMEVCNTR20_EL0, 21 => PMEVCNTR21_EL0, 22 => PMEVCNTR22_EL0, 23 => PMEVCNTR23_EL0,
+        24 => PMEVCNTR24_EL0, 25 => PMEVCNTR25_EL0, 26 => PMEVCNTR26_EL0, 27 => PMEVCNTR27_EL0,
+        28 => PMEVCNTR28_EL0, 29 => PMEVCNTR29_EL0, 30 => PMEVCNTR30_EL0,
+    ];
+    pmevtyper_el0 => [
+        0 => PMEVTYPER0_EL0, 1 => PMEVTYPER1_EL0, 2 => PMEVTYPER2_EL0, 3 => PMEVTYPER3_EL0,
+        4 => PMEVTYPER4_EL0, 5 => PMEVTYPER5_EL0, 6 => PMEVTYPER6_EL0, 7 => PMEVTYPER7_EL0,
+        8 => PMEVTYPER8_EL0, 9 => PMEVTYPER9_EL0, 10 => PMEVTYPER10_EL0, 11 => PMEVTYPER11_EL0,
+        12 => PMEVTYPER12_EL0, 13 => PMEVTYPER13_EL0, 14 => PMEVTYPER14_EL0, 15 => PMEVTYPER15_EL0,
+        16 => PMEVTYPER16_EL0, 17 => PMEVTYPER17_EL0, 18 => PMEVTYPER18_EL0, 19 => PMEVTYPER19_EL0,
+        20 => PMEVTYPER20_EL0, 21 => PMEVTYPER21_EL0, 22 => PMEVTYPER22_EL0, 23 => PMEVTYPER23_EL0,
+        24 => PMEVTYPER24_EL0, 25 => PMEVTYPER25_EL0, 26 => PMEVTYPER26_EL0, 27 => PMEVTYPER27_EL0,
+        28 => PMEVTYPER28_EL0, 29 => PMEVTYPER29_EL0, 30 => PMEVTYPER30_EL0,
+    ];
+    pmintenclr_el1 => PMINTENCLR_EL1;
+    pmintenset_el1 => PMINTENSET_EL1;
+    pmovsclr_el0 => PMOVSCLR_EL0;
+    pmovsset_el0 => PMOVSSET_EL0;
+    pmselr_el0 => PMSELR_EL0;
+    pmuserenr_el0 => PMUSERENR_EL0;
+    rvbar_el1 => RVBAR_EL1;
+    rvbar_el2 => RVBAR_EL2;
+    rvbar_el3 => RVBAR_EL3;
+    scr_el3 => SCR_EL3;
+    sctlr_el1 => SCTLR_EL1;
+    sctlr_el12 => SCTLR_EL12;
+    sctlr_el2 => SCTLR_EL2;
+    sctlr_el3 => SCTLR_EL3;
+    sp => SP;
+    sp_el0 => SP_EL0;
+    sp_el1 => SP_EL1;
+    spsel => SPSel;
+    spsr_el1 => SPSR_EL1;
+    spsr_el12 => SPSR_EL12;
+    spsr_el2 => SPSR_EL2;
+    spsr_el3 => SPSR_EL3;
+    ssbs => SSBS;
+    svcr => SVCR;
+    tco => TCO;
+    tcr_el1 => TCR_EL1;
+    tcr_el12 => TCR_EL12;
+    tcr_el2 => TCR_EL2;
+    tpidr_el0 => TPIDR_EL0;
+    tpidr_el1 => TPIDR_EL1;
+    tpidr_el2 => TPIDR_EL2;
+    tpidrro_el0 => TPIDRRO_EL0;
+    ttbr0_el1 => TTBR0_EL1;
+    ttbr0_el12 => TTBR0_EL12;
+    ttbr0_el2 => TTBR0_EL2;
+    ttbr1_el1 => TTBR1_EL1;
+    ttbr1_el12 => TTBR1_EL12;
+    ttbr1_el2 => TTBR1_EL2;
+    uao => UAO;
+    vbar_el1 => VBAR_EL1;
+    vbar_el12 => VBAR_EL12;
+    vbar_el2 => VBAR_EL2;
+    vbar_el3 => VBAR_EL3;
+    vtcr_el2 => VTCR_EL2;
+    vttbr_el2 => VTTBR_EL2;
+}
+
+/// All registers, sorted by encoding.
+pub static REGISTERS: [RegisterInfo; 268] = [
+    FP,
+    LR,
+    SP,
+    OSLAR_EL1,
+    MDCCSR_EL0,
+    DBGDTR_EL0,
+    DBGDTRRX_EL0,
+    DBGDTRTX_EL0,
+    MIDR_EL1,
+    MPIDR_EL1,
+    ID_AA64PFR0_EL1,
+    ID_AA64PFR1_EL1,
+    ID_AA64ZFR0_EL1,
+    ID_AA64SMFR0_EL1,
+    ID_AA64DFR0_EL1,
+    ID_AA64DFR1_EL1,
+    ID_AA64AFR0_EL1,
+    ID_AA64AFR1_EL1,
+    ID_AA64ISAR0_EL1,
+    ID_AA64ISAR1_EL1,
+    ID_AA64ISAR2_EL1,
+    ID_AA64MMFR0_EL1,
+    ID_AA64MMFR1_EL1,
+    ID_AA64MMFR2_EL1,
+    SCTLR_EL1,
+    ACTLR_EL1,
+    CPACR_EL1,
+    TTBR0_EL1,
+    TTBR1_EL1,
+    TCR_EL1,
+    APIAKEYLO_EL1,
+    APIAKEYHI_EL1,
+    APIBKEYLO_EL1,
+    APIBKEYHI_EL1,
+    APDAKEYLO_EL1,
+    APDAKEYHI_EL1,
+    APDBKEYLO_EL1,
+    APDBKEYHI_EL1,
+    APGAKEYLO_EL1,
+    APGAKEYHI_EL1,
+    SPSR_EL1,
+    ELR_EL1,
+    SP_EL0,
+    SPSel,
+    CurrentEL,
+    PAN,
+    UAO,
+    ALLINT,
+    ICC_PMR_EL1,
+    ESR_EL1,
+    FAR_EL1,
+    PAR_EL1,
+    PMINTENSET_EL1,
+    PMINTENCLR_EL1,
+    MAIR_EL1,
+    VBAR_EL1,
+    RVBAR_EL1,
+    ICC_IAR0_EL1,
+    ICC_EOIR0_EL1,
+    ICC_HPPIR0_EL1,
+    ICC_BPR0_EL1,
+    ICC_NMIAR1_EL1,
+    ICC_DIR_EL1,
+    ICC_RPR_EL1,
+    ICC_SGI1R_EL1,
+    ICC_ASGI1R_EL1,
+    ICC_SGI0R_EL1,
+    ICC_IAR1_EL1,
+    ICC_EOIR1_EL1,
+    ICC_HPPIR1_EL1,
+    ICC_BPR1_EL1,
+    ICC_CTLR_EL1,
+    ICC_SRE_EL1,
+    ICC_IGRPEN0_EL1,
+    ICC_IGRPEN1_EL1,
+    CONTEXTIDR_EL1,
+    TPIDR_EL1,
+    CNTKCTL_EL1,
+    CCSIDR_EL1,
+    CLIDR_EL1,
+    CSSELR_EL1,
+    CTR_EL0,
+    NZCV,
+    DAIF,
+    SVCR,
+    DIT,
+    SSBS,
+    TCO,
+    PMCR_EL0,
+    PMCNTENSET_EL0,
+    PMCNTENCLR_EL0,
+    PMOVSCLR_EL0,
+    PMSELR_EL0,
+    PMCCNTR_EL0,
+    PMUSERENR_EL0,
+    PMOVSSET_EL0,
+    TPIDR_EL0,
+    TPIDRRO_EL0,
+    CNTFRQ_EL0,
+    CNTPCT_EL0,
+    CNTVCT_EL0,
+    CNTPCTSS_EL0,
+    CNTVCTSS_EL0,
+    CNTP_TVAL_EL0,
+    CNTP_CTL_EL0,
+    CNTP_CVAL_EL0,
+    CNTV_TVAL_EL0,
+    CNTV_CTL_EL0,
+    CNTV_CVAL_EL0,
+    PMEVCNTR0_EL0,
+    PMEVCNTR1_EL0,
+    PMEVCNTR2_EL0,
+    PMEVCNTR3_EL0,
+    PMEVCNTR4_EL0,
+    PMEVCNTR5_EL0,
+    PMEVCNTR6_EL0,
+    PMEVCNTR7_EL0,
+    PMEVCNTR8_EL0,
+    PMEVCNTR9_EL0,
+    PMEVCNTR10_EL0,
+    PMEVCNTR11_EL0,
+    PMEVCNTR12_EL0,
+    PMEVCNTR13_EL0,
+    PMEVCNTR14_EL0,
+    PMEVCNTR15_EL0,
+    PMEVCNTR16_EL0,
+    PMEVCNTR17_EL0,
+    PMEVCNTR18_EL0,
+    PMEVCNTR19_EL0,
+    PMEVCNTR20_EL0,
+    PMEVCNTR21_EL0,
+    PMEVCNTR22_EL0,
+    PMEVCNTR23_EL0,
+    PMEVCNTR24_EL0,
+    PMEVCNTR25_EL0,
+    PMEVCNTR26_EL0,
+    PMEVCNTR27_EL0,
+    PMEVCNTR28_EL0,
+    PMEVCNTR29_EL0,
+    PMEVCNTR30_EL0,
+    PMEVTYPER0_EL0,
+    PMEVTYPER1_EL0,
+    PMEVTYPER2_EL0,
+    PMEVTYPER3_EL0,
+    PMEVTYPER4_EL0,
+    PMEVTYPER5_EL0,
+    PMEVTYPER6_EL0,
+    PMEVTYPER7_EL0,
+    PMEVTYPER8_EL0,
+    PMEVTYPER9_EL0,
+    PMEVTYPER10_EL0,
+    PMEVTYPER11_EL0,
+    PMEVTYPER12_EL0,
+    PMEVTYPER13_EL0,
+    PMEVTYPER14_EL0,
+    PMEVTYPER15_EL0,
+    PMEVTYPER16_EL0,
+    PMEVTYPER17_EL0,
+    PMEVTYPER18_EL0,
+    PMEVTYPER19_EL0,
+    PMEVTYPER20_EL0,
+    PMEVTYPER21_EL0,
+    PMEVTYPER22_EL0,
+    PMEVTYPER23_EL0,
+    PMEVTYPER24_EL0,
+    PMEVTYPER25_EL0,
+    PMEVTYPER26_EL0,
+    PMEVTYPER27_EL0,
+    PMEVTYPER28_EL0,
+    PMEVTYPER29_EL0,
+    PMEVTYPER30_EL0,
+    PMCCFILTR_EL0,
+    SCTLR_EL2,
+    ACTLR_EL2,
+    HCR_EL2,
+    CPTR_EL2,
+    HCRX_EL2,
+    TTBR0_EL2,
+    TTBR1_EL2,
+    TCR_EL2,
+    VTTBR_EL2,
+    VTCR_EL2,
+    DACR32_EL2,
+    SPSR_EL2,
+    ELR_EL2,
+    SP_EL1,
+    ESR_EL2,
+    FAR_EL2,
+    HPFAR_EL2,
+    MAIR_EL2,
+    VBAR_EL2,
+    RVBAR_EL2,
+    ICH_AP0R0_EL2,
+    ICH_AP0R1_EL2,
+    ICH_AP0R2_EL2,
+    ICH_AP0R3_EL2,
+    ICH_AP1R0_EL2,
+    ICH_AP1R1_EL2,
+    ICH_AP1R2_EL2,
+    ICH_AP1R3_EL2,
+    ICC_SRE_EL2,
+    ICH_HCR_EL2,
+    ICH_VTR_EL2,
+    ICH_MISR_EL2,
+    ICH_VMCR_EL2,
+    ICH_LR0_EL2,
+    ICH_LR1_EL2,
+    ICH_LR2_EL2,
+    ICH_LR3_EL2,
+    ICH_LR4_EL2,
+    ICH_LR5_EL2,
+    ICH_LR6_EL2,
+    ICH_LR7_EL2,
+    ICH_LR8_EL2,
+    ICH_LR9_EL2,
+    ICH_LR10_EL2,
+    ICH_LR11_EL2,
+    ICH_LR12_EL2,
+    ICH_LR13_EL2,
+    ICH_LR14_EL2,
+    ICH_LR15_EL2,
+    CONTEXTIDR_EL2,
+    TPIDR_EL2,
+    CNTVOFF_EL2,
+    CNTSCALE_EL2,
+    CNTISCALE_EL2,
+    CNTPOFF_EL2,
+    CNTVFRQ_EL2,
+    CNTHCTL_EL2,
+    CNTHP_TVAL_EL2,
+    CNTHP_CTL_EL2,
+    CNTHP_CVAL_EL2,
+    CNTHV_TVAL_EL2,
+    CNTHV_CTL_EL2,
+    CNTHV_CVAL_EL2,
+    SCTLR_EL12,
+    CPACR_EL12,
+    TTBR0_EL12,
+    TTBR1_EL12,
+    TCR_EL12,
+    SPSR_EL12,
+    ELR_EL12,
+    ESR_EL12,
+    FAR_EL12,
+    MAIR_EL12,
+    VBAR_EL12,
+    CONTEXTIDR_EL12,
+    CNTKCTL_EL12,
+    CNTP_TVAL_EL02,
+    CNTP_CTL_EL02,
+    CNTP_CVAL_EL02,
+    CNTV_TVAL_EL02,
+    CNTV_CTL_EL02,
+    CNTV_CVAL_EL02,
+    SCTLR_EL3,
+    ACTLR_EL3,
+    SCR_EL3,
+    SPSR_EL3,
+    ELR_EL3,
+    ESR_EL3,
+    FAR_EL3,
+    VBAR_EL3,
+    RVBAR_EL3,
+    ICC_CTLR_EL3,
+    ICC_SRE_EL3,
+    CNTPS_TVAL_EL1,
+    CNTPS_CTL_EL1,
+    CNTPS_CVAL_EL1,
+];
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{
+        by_encoding, by_name, lookup, Access, Decode, Introspect, RegisterFields, INTROSPECTED,
+        REGISTERS,
+    };
+    use crate::registers::{
+        ichlr, pmevtyper_el0, SPSel, CNTPCT_EL0, CNTV_CTL_EL0, ICC_EOIR1_EL1, OSLAR_EL1,
+        SCTLR_EL12, SPSR_EL1,
+    };
+    use std::format;
+    use tock_registers::LocalRegisterCopy;
+
+    #[test]
+    fn sorted() {
+        assert!(REGISTERS.windows(2).all(|w| w[0].encoding <= w[1].encoding));
+    }
+
+    #[test]
+    fn complete() {
+        let introspected = INTROSPECTED.iter().flat_map(|infos| infos.iter());
+
+        for info in introspected.clone() {
+            assert!(REGISTERS.contains(info), "{} is missing", info.name);
+        }
+        assert_eq!(introspected.count(), REGISTERS.len());
+    }
+
+    #[test]
+    fn lookups() {
+        assert_eq!(by_name("cntpct_el0"), Some(CNTPCT_EL0.info()));
+        assert_eq!(by_encoding((3, 4, 12, 13, 7)), Some(ichlr(15).info()));
+        assert_eq!(
+            lookup((2, 3, 0, 5, 0), true).map(|r| r.name),
+            Some("DBGDTRRX_EL0")
+        );
+        assert_eq!(
+            lookup((2, 3, 0, 5, 0), false).map(|r| r.name),
+            Some("DBGDTRTX_EL0")
+        );
+        assert_eq!(lookup((3, 0, 0, 0, 0), false), None);
+
+        // The access is that of the architecture, not that of the accessors of this crate.
+        assert_eq!(lookup((3, 1, 0, 0, 0), false), None);
+        assert_eq!(lookup((3, 1, 0, 0, 1), false), None);
+        assert_eq!(lookup((3, 4, 12, 11, 2), false), None);
+        assert_eq!(
+            lookup((3, 6, 5, 2, 0), false).map(|r| r.name),
+            Some("ESR_EL3")
+        );
+        assert_eq!(
+            lookup((3, 4, 6, 0, 4), false).map(|r| r.name),
+            Some("HPFAR_EL2")
+        );
+        assert_eq!(
+            lookup((3, 3, 14, 0, 0), false).map(|r| r.name),
+            Some("CNTFRQ_EL0")
+        );
+        assert_eq!(by_encoding((3, 7, 15, 15, 7)), None);
+    }
+
+    #[test]
+    fn fields() {
+        let info = pmevtyper_el0(3).info();
+        assert_eq!(info.name, "PMEVTYPER3_EL0");
+        assert_eq!(info.min_el, 0);
+
+        let field = info.field("evtCount").unwrap();
+        assert_eq!((field.offset, field.width), (0, 16));
+        assert_eq!(field.read(0x8000_0011), 0x11);
+        assert_eq!(SPSel.info().min_el, 1);
+        assert_eq!(ICC_EOIR1_EL1.info().access, Access::WriteOnly);
+        assert_eq!(OSLAR_EL1.info().access, Access::WriteOnly);
+
+        // VHE aliases use the fields of the EL1 register, but are only accessible from EL2.
+        let info = SCTLR_EL12.info();
+        assert_eq!((info.name, info.min_el), ("SCTLR_EL12", 2));
+        assert!(info.field("M").is_some());
+
+        // The fields are generated from the bitfield definitions, including all named values.
+        let hcr = by_name("HCR_EL2").unwrap();
+        assert_eq!(hcr.field("FWB").unwrap().value_name(1), Some("Enabled"));
+        let ec = |name| by_name(name).unwrap().field("EC").unwrap().values;
+        assert_eq!(ec("ESR_EL1"), ec("ESR_EL2"));
+        assert_eq!(ec("ESR_EL3").len(), ec("ESR_EL1").len() + 1);
+    }
+
+    #[test]
+    fn decode() {
+        let spsr = LocalRegisterCopy::<u64, SPSR_EL1::Register>::new(0x2000_03c5);
+        assert_eq!(
+            format!("{}", spsr.decode()),
+            "N=0 Z=0 C=1 V=0 SS=0 IL=0 D=Masked A=Masked I=Masked F=Masked M=EL1h"
+        );
+
+        let info = CNTV_CTL_EL0.info();
+        assert_eq!(
+            format!("{:?}", info.decode(0b101)),
+            "{ISTATUS: 1, IMASK: 0, ENABLE: 1}"
+        );
+
+        // Values without a name.
+        let decoded = by_name("SPSR_EL1").unwrap().decode(0xf);
+        assert!(format!("{}", decoded).ends_with("F=Unmasked M=0xf"));
+        assert_eq!(
+            SPSR_EL1::Register::FIELDS[10].value_name(0b0101),
+            Some("EL1h")
+        );
+    }
+}
@@ -10,9 +10,9 @@
 //! Provides identification information for the processor, including an implementer code for the
 //! device and a device ID number.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub MIDR_EL1 [
         /// The Implementer code. This field must hold an implementer code that has been assigned by
         /// Arm. Assigned codes include the following:
@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! In-memory register backend for host-side testing.
+//!
+//! When the `mock` feature is enabled, every register access that would otherwise execute an
+//! `mrs`, `msr` or `mov` instruction is redirected to a register file that lives in thread-local
+//! storage. This allows code that programs system registers to be exercised by `cargo test` on
+//! any host, including non-aarch64 CI machines.
+//!
+//! Registers are identified by their architectural name, compared case-insensitively. A register
+//! that has not been written yet reads as its reset value, which defaults to zero and can be
+//! changed with [`set_reset_value`]. Hooks installed with [`on_read`] and [`on_write`] can be used
+//! to model registers with side effects, e.g. read-only or write-one-to-clear bits.
+//!
+//! Every access performed through [`Readable`](crate::registers::Readable) or
+//! [`Writeable`](crate::registers::Writeable) is appended to an access log, which can be inspected
+//! with [`accesses`] to assert register programming sequences.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "mock")]
+//! # {
+//! use aarch64_cpu::registers::{mock, *};
+//!
+//! mock::clear();
+//! mock::set_reset_value("SCTLR_EL1", 0x30d0_0800);
+//!
+//! SCTLR_EL1.modify(SCTLR_EL1::M::Enable);
+//!
+//! assert_eq!(
+//!     mock::accesses(),
+//!     [
+//!         mock::Access::Read("SCTLR_EL1", 0x30d0_0800),
+//!         mock::Access::Write("SCTLR_EL1", 0x30d0_0801),
+//!     ]
+//! );
+//! # }
+//! ```
+
+extern crate std;
+
+use std::{boxed::Box, cell::RefCell, collections::HashMap, string::String, vec::Vec};
+
+/// A single register access that was recorded by the mock backend.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Access {
+    /// The register with the given name was read and returned the given value.
+    Read(&'static str, u64),
+
+    /// The given value was written to the register with the given name.
+    Write(&'static str, u64),
+}
+
+type ReadHook = Box<dyn FnMut(u64) -> u64>;
+type WriteHook = Box<dyn FnMut(u64, u64) -> u64>;
+
+#[derive(Default)]
+struct Entry {
+    value: Option<u64>,
+    reset: u64,
+    read_hook: Option<ReadHook>,
+    write_hook: Option<WriteHook>,
+}
+
+impl Entry {
+    fn current(&self) -> u64 {
+        self.value.unwrap_or(self.reset)
+    }
+}
+
+#[derive(Default)]
+struct RegisterFile {
+    regs: HashMap<String, Entry>,
+    log: Vec<Access>,
+}
+
+impl RegisterFile {
+    fn entry(&mut self, name: &str) -> &mut Entry {
+        self.regs.entry(name.to_ascii_uppercase()).or_default()
+    }
+}
+
+std::thread_local! {
+    static REGISTER_FILE: RefCell<RegisterFile> = RefCell::new(RegisterFile::default());
+}
+
+fn with_file<R>(f: impl FnOnce(&mut RegisterFile) -> R) -> R {
+    REGISTER_FILE.with(|file| f(&mut file.borrow_mut()))
+}
+
+/// Removes all registers, reset values, hooks and the access log of the current thread.
+pub fn clear() {
+    with_file(|file| *file = RegisterFile::default());
+}
+
+/// Returns all registers of the current thread to their reset values and clears the access log.
+///
+/// Reset values and hooks are kept.
+pub fn reset() {
+    with_file(|file| {
+        file.regs.values_mut().for_each(|entry| entry.value = None);
+        file.log.clear();
+    });
+}
+
+/// Sets the value that `reg` holds out of reset.
+///
+/// Takes effect immediately if `reg` has not been written since the last [`reset`].
+pub fn set_reset_value(reg: &str, value: u64) {
+    with_file(|file| file.entry(reg).reset = value);
+}
+
+/// Returns the current value of `reg` without invoking hooks or recording an access.
+pub fn peek(reg: &str) -> u64 {
+    with_file(|file| file.entry(reg).current())
+}
+
+/// Sets the current value of `reg` without invoking hooks or recording an access.
+///
+/// Useful for emulating state changes made by hardware, e.g. a timer condition becoming true.
+pub fn poke(reg: &str, value: u64) {
+    with_file(|file| file.entry(reg).value = Some(value));
+}
+
+/// Installs a hook that is called on every read of `reg`.
+///
+/// The hook receives the current value of the register and returns the value that the read
+/// yields. The stored value is not changed.
+pub fn on_read(reg: &str, hook: impl FnMut(u64) -> u64 + 'static) {
+    with_file(|file| file.entry(reg).read_hook = Some(Box::new(hook)));
+}
+
+/// Installs a hook that is called on every write of `reg`.
+///
+/// The hook receives the current and the written value of the register, and returns the value
+/// that is stored.
+pub fn on_write(reg: &str, hook: impl FnMut(u64, u64) -> u64 + 'static) {
+    with_file(|file| file.entry(reg).write_hook = Some(Box::new(hook)));
+}
+
+/// Returns a copy of the access log of the current thread.
+pub fn accesses() -> Vec<Access> {
+    with_file(|file| file.log.clone())
+}
+
+/// Returns the access log of the current thread and clears it.
+pub fn take_accesses() -> Vec<Access> {
+    with_file(|file| core::mem::take(&mut file.log))
+}
+
+// Hooks are taken out of the register file while they run, so that they are free to access other
+// mock registers themselves.
+
+#[doc(hidden)]
+pub fn read(reg: &'static str) -> u64 {
+    let (current, hook) = with_file(|file| {
+        let entry = file.entry(reg);
+        (entry.current(), entry.read_hook.take())
+    });
+
+    let value = match hook {
+        Some(mut hook) => {
+            let value = hook(current);
+            with_file(|file| {
+                file.entry(reg).read_hook.get_or_insert(hook);
+            });
+            value
+        }
+        None => current,
+    };
+
+    with_file(|file| file.log.push(Access::Read(reg, value)));
+    value
+}
+
+#[doc(hidden)]
+pub fn write(reg: &'static str, value: u64) {
+    let (current, hook) = with_file(|file| {
+        let entry = file.entry(reg);
+        (entry.current(), entry.write_hook.take())
+    });
+
+    let stored = match hook {
+        Some(mut hook) => {
+            let stored = hook(current, value);
+            with_file(|file| {
+                file.entry(reg).write_hook.get_or_insert(hook);
+            });
+            stored
+        }
+        None => value,
+    };
+
+    with_file(|file| {
+        file.entry(reg).value = Some(stored);
+        file.log.push(Access::Write(reg, value));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::*;
+
+    #[test]
+    fn reads_reset_value_until_written() {
+        clear();
+        set_reset_value("TCR_EL1", 0x10);
+
+        assert_eq!(TCR_EL1.get(), 0x10);
+        TCR_EL1.set(0x20);
+        assert_eq!(TCR_EL1.get(), 0x20);
+
+        reset();
+        assert_eq!(TCR_EL1.get(), 0x10);
+    }
+
+    #[test]
+    fn records_access_sequence() {
+        clear();
+
+        HCR_EL2.write(HCR_EL2::RW::EL1IsAarch64);
+        SCTLR_EL1.modify(SCTLR_EL1::M::Enable);
+
+        assert_eq!(
+            take_accesses(),
+            [
+                Access::Write("HCR_EL2", 1 << 31),
+                Access::Read("SCTLR_EL1", 0),
+                Access::Write("SCTLR_EL1", 1),
+            ]
+        );
+        assert!(accesses().is_empty());
+    }
+
+    #[test]
+    fn hooks_model_side_effects() {
+        clear();
+
+        // Write-one-to-clear status bits.
+        poke("ICH_MISR_EL2", 0b111);
+        on_write("ICH_MISR_EL2", |old, new| old & !new);
+        ICH_MISR_EL2.set(0b010);
+        assert_eq!(peek("ICH_MISR_EL2"), 0b101);
+
+        // Hooks may access other registers.
+        on_read("CNTPCT_EL0", |_| peek("CNTVCT_EL0") + 1);
+        poke("CNTVCT_EL0", 41);
+        assert_eq!(CNTPCT_EL0.get(), 42);
+        assert_eq!(peek("cntpct_el0"), 0);
+    }
+}
@@ -10,9 +10,9 @@
 //! In a multiprocessor system, provides an additional PE identification mechanism for scheduling
 //! purposes.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::interfaces::Readable;
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub MPIDR_EL1 [
         /// Affinity level 3. See the description of Aff0 for more information.
         Aff3 OFFSET(32) NUMBITS(8) [],
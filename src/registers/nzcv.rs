@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Condition Flags
+//!
+//! Allows access to the condition flags.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub NZCV [
+        /// Negative condition flag. Set to 1 if the result of the last flag-setting instruction was
+        /// negative.
+        N OFFSET(31) NUMBITS(1) [],
+
+        /// Zero condition flag. Set to 1 if the result of the last flag-setting instruction was zero, and
+        /// to 0 otherwise. A result of zero often indicates an equal result from a comparison.
+        Z OFFSET(30) NUMBITS(1) [],
+
+        /// Carry condition flag. Set to 1 if the last flag-setting instruction resulted in a carry
+        /// condition, for example an unsigned overflow on an addition.
+        C OFFSET(29) NUMBITS(1) [],
+
+        /// Overflow condition flag. Set to 1 if the last flag-setting instruction resulted in an overflow
+        /// condition, for example a signed overflow on an addition.
+        V OFFSET(28) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = NZCV::Register;
+
+    sys_coproc_read_raw!(u64, "NZCV", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = NZCV::Register;
+
+    sys_coproc_write_raw!(u64, "NZCV", "x");
+}
+
+pub const NZCV: Reg = Reg {};
@@ -13,12 +13,9 @@
 //! AArch64 System register `OSLAR_EL1` bits \[31:0\] are architecturally mapped to External
 //! register `OSLAR_EL1[31:0]`. The OS Lock can also be locked or unlocked using `DBGOSLAR`.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub OSLAR_EL1 [
         /// On writes to `OSLAR_EL1`, bit[0] is copied to the OS Lock.
         /// Use `OSLSR_EL1.OSLK` to check the current status of the lock.
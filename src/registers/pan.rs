@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Privileged Access Never
+//!
+//! Allows access to the Privileged Access Never bit. Requires FEAT_PAN.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PAN [
+        /// Privileged Access Never.
+        ///
+        /// When set, privileged data accesses from EL1, or EL2 when `HCR_EL2.E2H` is 1, to virtual
+        /// memory that is accessible at EL0 generate a Permission fault.
+        PAN OFFSET(22) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PAN::Register;
+
+    sys_coproc_read_raw!(u64, "PAN" = "S3_0_C4_C2_3", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PAN::Register;
+
+    sys_coproc_write_raw!(u64, "PAN" = "S3_0_C4_C2_3", "x");
+}
+
+pub const PAN: Reg = Reg {};
@@ -10,13 +10,26 @@
 //! Returns the output address (OA) from an Address translation instruction that executed
 //! successfully, or fault information if the instruction did not execute successfully.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub PAR_EL1 [
+        /// Memory attributes for the returned output address. This field uses the same encoding
+        /// as the Attr<n> fields in MAIR_EL1, MAIR_EL2, and MAIR_EL3.
+        ///
+        /// Only valid if `F` is 0.
+        ///
+        /// This field resets to an architecturally UNKNOWN value.
+        ATTR OFFSET(56) NUMBITS(8) [],
+
+        /// Output address, bits[51:48]. When FEAT_LPA is implemented and 52-bit addresses are in
+        /// use, holds the upper part of the output address. Otherwise RES0.
+        ///
+        /// Only valid if `F` is 0.
+        ///
+        /// This field resets to an architecturally UNKNOWN value.
+        PA_51_48 OFFSET(48) NUMBITS(4) [],
+
         /// Output address. The output address (OA) corresponding to the supplied input address.
         /// This field returns address bits[47:12].
         ///
@@ -30,6 +43,50 @@ register_bitfields! {u64,
         /// This field resets to an architecturally UNKNOWN value.
         PA OFFSET(12) NUMBITS(36) [],
 
+        /// Non-secure. For a translation from Secure state, reports the NS attribute of the output
+        /// address.
+        ///
+        /// Only valid if `F` is 0.
+        ///
+        /// This field resets to an architecturally UNKNOWN value.
+        NS OFFSET(9) NUMBITS(1) [],
+
+        /// Shareability attribute for the returned output address.
+        ///
+        /// Only valid if `F` is 0.
+        ///
+        /// This field resets to an architecturally UNKNOWN value.
+        SH OFFSET(7) NUMBITS(2) [
+            NonShareable = 0b00,
+            OuterShareable = 0b10,
+            InnerShareable = 0b11
+        ],
+
+        /// Indicates the translation stage at which the translation aborted.
+        ///
+        /// Only valid if `F` is 1.
+        ///
+        /// This field resets to an architecturally UNKNOWN value.
+        S OFFSET(9) NUMBITS(1) [
+            Stage1 = 0,
+            Stage2 = 1
+        ],
+
+        /// If this value is 1, it indicates the translation aborted because of a stage 2 fault
+        /// during a stage 1 translation table walk.
+        ///
+        /// Only valid if `F` is 1.
+        ///
+        /// This field resets to an architecturally UNKNOWN value.
+        PTW OFFSET(8) NUMBITS(1) [],
+
+        /// Fault status code, as shown in the Data Abort exception ISS encoding.
+        ///
+        /// Only valid if `F` is 1.
+        ///
+        /// This field resets to an architecturally UNKNOWN value.
+        FST OFFSET(1) NUMBITS(6) [],
+
         /// Indicates whether the instruction performed a successful address translation.
         ///
         /// 0 Address translation completed successfully.
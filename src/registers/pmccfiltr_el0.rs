@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Cycle Count Filter Register - EL0
+//!
+//! Determines the modes in which the Cycle Counter, `PMCCNTR_EL0`, increments.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PMCCFILTR_EL0 [
+        /// Privileged filtering bit. Controls counting in EL1. When set, counting is disabled.
+        P OFFSET(31) NUMBITS(1) [],
+
+        /// User filtering bit. Controls counting in EL0. When set, counting is disabled.
+        U OFFSET(30) NUMBITS(1) [],
+
+        /// Non-secure EL1 (kernel) modes filtering bit. When different from P, counting in
+        /// Non-secure EL1 is inverted.
+        NSK OFFSET(29) NUMBITS(1) [],
+
+        /// Non-secure EL0 (user) modes filtering bit. When different from U, counting in Non-secure
+        /// EL0 is inverted.
+        NSU OFFSET(28) NUMBITS(1) [],
+
+        /// Non-secure EL2 (hypervisor) modes filtering bit. When set, counting in Non-secure EL2 is
+        /// enabled.
+        NSH OFFSET(27) NUMBITS(1) [],
+
+        /// Secure EL3 filtering bit. When different from P, counting in EL3 is inverted.
+        M OFFSET(26) NUMBITS(1) [],
+
+        /// Secure EL2 filtering bit. When different from NSH, counting in Secure EL2 is enabled.
+        SH OFFSET(24) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMCCFILTR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMCCFILTR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMCCFILTR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMCCFILTR_EL0", "x");
+}
+
+pub const PMCCFILTR_EL0: Reg = Reg {};
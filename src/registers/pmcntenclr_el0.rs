@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Count Enable Clear register - EL0
+//!
+//! Disables the Cycle Count Register, `PMCCNTR_EL0`, and any implemented event counters
+//! `PMEVCNTR<n>_EL0`. Reading this register shows which counters are enabled.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PMCNTENCLR_EL0 [
+        /// `PMCCNTR_EL0` disable bit. Writing 1 disables the cycle counter, writing 0 has no
+        /// effect.
+        C OFFSET(31) NUMBITS(1) [],
+
+        /// Event counter disable bit for `PMEVCNTR<n>_EL0`, one bit per counter. Writing 1 disables
+        /// the counter, writing 0 has no effect.
+        P OFFSET(0) NUMBITS(31) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMCNTENCLR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMCNTENCLR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMCNTENCLR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMCNTENCLR_EL0", "x");
+}
+
+pub const PMCNTENCLR_EL0: Reg = Reg {};
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Count Enable Set register - EL0
+//!
+//! Enables the Cycle Count Register, `PMCCNTR_EL0`, and any implemented event counters
+//! `PMEVCNTR<n>_EL0`. Reading this register shows which counters are enabled.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PMCNTENSET_EL0 [
+        /// `PMCCNTR_EL0` enable bit. Writing 1 enables the cycle counter, writing 0 has no effect.
+        C OFFSET(31) NUMBITS(1) [],
+
+        /// Event counter enable bit for `PMEVCNTR<n>_EL0`, one bit per counter. Writing 1 enables
+        /// the counter, writing 0 has no effect.
+        P OFFSET(0) NUMBITS(31) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMCNTENSET_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMCNTENSET_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMCNTENSET_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMCNTENSET_EL0", "x");
+}
+
+pub const PMCNTENSET_EL0: Reg = Reg {};
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Control Register - EL0
+//!
+//! Provides details of the Performance Monitors implementation, including the number of counters
+//! implemented, and configures and controls the counters.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PMCR_EL0 [
+        /// Implementer code. Reads as zero when the implementer is given by `MIDR_EL1`.
+        IMP OFFSET(24) NUMBITS(8) [],
+
+        /// Identification code. Reads as zero when the implementation is given by `MIDR_EL1`.
+        IDCODE OFFSET(16) NUMBITS(8) [],
+
+        /// Number of event counters implemented, `PMEVCNTR<n>_EL0` with n in the range 0 to N - 1.
+        ///
+        /// This field is read-only.
+        N OFFSET(11) NUMBITS(5) [],
+
+        /// Freeze-on-overflow. Stops event counters from counting when an overflow of a counter in
+        /// the first range is recorded in `PMOVSCLR_EL0`. Requires FEAT_PMUv3p7.
+        FZO OFFSET(9) NUMBITS(1) [],
+
+        /// Long event counter enable. Determines when unsigned overflow is recorded for an event
+        /// counter in the first range. Requires FEAT_PMUv3p5.
+        ///
+        /// 0 Overflow on increment that changes bit \[31\] from 1 to 0.
+        /// 1 Overflow on increment that changes bit \[63\] from 1 to 0.
+        LP OFFSET(7) NUMBITS(1) [],
+
+        /// Long cycle counter enable. Determines when unsigned overflow is recorded by the cycle
+        /// counter overflow bit.
+        ///
+        /// 0 Overflow on increment that changes `PMCCNTR_EL0[31]` from 1 to 0.
+        /// 1 Overflow on increment that changes `PMCCNTR_EL0[63]` from 1 to 0.
+        LC OFFSET(6) NUMBITS(1) [],
+
+        /// Disable cycle counter when event counting is prohibited.
+        DP OFFSET(5) NUMBITS(1) [],
+
+        /// Enable export of events in an IMPLEMENTATION DEFINED PMU event export bus.
+        X OFFSET(4) NUMBITS(1) [],
+
+        /// Clock divider. When set, `PMCCNTR_EL0` counts once every 64 clock cycles.
+        D OFFSET(3) NUMBITS(1) [],
+
+        /// Cycle counter reset. Writing 1 resets `PMCCNTR_EL0` to zero. Reads as zero.
+        C OFFSET(2) NUMBITS(1) [],
+
+        /// Event counter reset. Writing 1 resets all event counters in the first range, not
+        /// including `PMCCNTR_EL0`, to zero. Reads as zero.
+        P OFFSET(1) NUMBITS(1) [],
+
+        /// Enable.
+        ///
+        /// 0 All event counters in the first range and `PMCCNTR_EL0` are disabled.
+        /// 1 Counters are enabled by `PMCNTENSET_EL0`.
+        E OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMCR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMCR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMCR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMCR_EL0", "x");
+}
+
+pub const PMCR_EL0: Reg = Reg {};
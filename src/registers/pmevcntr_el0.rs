@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Event Count Registers - EL0
+//!
+//! Holds event counter n, which counts events, where n is 0 to 30.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg {
+    pub(super) index: u8,
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw_indexed!(u64, [
+        0 => "PMEVCNTR0_EL0",
+        1 => "PMEVCNTR1_EL0",
+        2 => "PMEVCNTR2_EL0",
+        3 => "PMEVCNTR3_EL0",
+        4 => "PMEVCNTR4_EL0",
+        5 => "PMEVCNTR5_EL0",
+        6 => "PMEVCNTR6_EL0",
+        7 => "PMEVCNTR7_EL0",
+        8 => "PMEVCNTR8_EL0",
+        9 => "PMEVCNTR9_EL0",
+        10 => "PMEVCNTR10_EL0",
+        11 => "PMEVCNTR11_EL0",
+        12 => "PMEVCNTR12_EL0",
+        13 => "PMEVCNTR13_EL0",
+        14 => "PMEVCNTR14_EL0",
+        15 => "PMEVCNTR15_EL0",
+        16 => "PMEVCNTR16_EL0",
+        17 => "PMEVCNTR17_EL0",
+        18 => "PMEVCNTR18_EL0",
+        19 => "PMEVCNTR19_EL0",
+        20 => "PMEVCNTR20_EL0",
+        21 => "PMEVCNTR21_EL0",
+        22 => "PMEVCNTR22_EL0",
+        23 => "PMEVCNTR23_EL0",
+        24 => "PMEVCNTR24_EL0",
+        25 => "PMEVCNTR25_EL0",
+        26 => "PMEVCNTR26_EL0",
+        27 => "PMEVCNTR27_EL0",
+        28 => "PMEVCNTR28_EL0",
+        29 => "PMEVCNTR29_EL0",
+        30 => "PMEVCNTR30_EL0"
+    ], "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw_indexed!(u64, [
+        0 => "PMEVCNTR0_EL0",
+        1 => "PMEVCNTR1_EL0",
+        2 => "PMEVCNTR2_EL0",
+        3 => "PMEVCNTR3_EL0",
+        4 => "PMEVCNTR4_EL0",
+        5 => "PMEVCNTR5_EL0",
+        6 => "PMEVCNTR6_EL0",
+        7 => "PMEVCNTR7_EL0",
+        8 => "PMEVCNTR8_EL0",
+        9 => "PMEVCNTR9_EL0",
+        10 => "PMEVCNTR10_EL0",
+        11 => "PMEVCNTR11_EL0",
+        12 => "PMEVCNTR12_EL0",
+        13 => "PMEVCNTR13_EL0",
+        14 => "PMEVCNTR14_EL0",
+        15 => "PMEVCNTR15_EL0",
+        16 => "PMEVCNTR16_EL0",
+        17 => "PMEVCNTR17_EL0",
+        18 => "PMEVCNTR18_EL0",
+        19 => "PMEVCNTR19_EL0",
+        20 => "PMEVCNTR20_EL0",
+        21 => "PMEVCNTR21_EL0",
+        22 => "PMEVCNTR22_EL0",
+        23 => "PMEVCNTR23_EL0",
+        24 => "PMEVCNTR24_EL0",
+        25 => "PMEVCNTR25_EL0",
+        26 => "PMEVCNTR26_EL0",
+        27 => "PMEVCNTR27_EL0",
+        28 => "PMEVCNTR28_EL0",
+        29 => "PMEVCNTR29_EL0",
+        30 => "PMEVCNTR30_EL0"
+    ], "x");
+}
+
+/// Returns event counter register `PMEVCNTR<n>_EL0`.
+///
+/// Panics if `n` is greater than 30.
+pub const fn pmevcntr_el0(n: u8) -> Reg {
+    assert!(n <= 30, "PMEVCNTR<n>_EL0 index out of range");
+
+    Reg { index: n }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Event Type Registers - EL0
+//!
+//! Configures event counter n, where n is 0 to 30.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PMEVTYPER_EL0 [
+        /// Privileged filtering bit. Controls counting in EL1. When set, counting is disabled.
+        P OFFSET(31) NUMBITS(1) [],
+
+        /// User filtering bit. Controls counting in EL0. When set, counting is disabled.
+        U OFFSET(30) NUMBITS(1) [],
+
+        /// Non-secure EL1 (kernel) modes filtering bit. When different from P, counting in
+        /// Non-secure EL1 is inverted.
+        NSK OFFSET(29) NUMBITS(1) [],
+
+        /// Non-secure EL0 (user) modes filtering bit. When different from U, counting in
+        /// Non-secure EL0 is inverted.
+        NSU OFFSET(28) NUMBITS(1) [],
+
+        /// Non-secure EL2 (hypervisor) modes filtering bit. When set, counting in Non-secure EL2
+        /// is enabled.
+        NSH OFFSET(27) NUMBITS(1) [],
+
+        /// Secure EL3 filtering bit. When different from P, counting in EL3 is inverted.
+        M OFFSET(26) NUMBITS(1) [],
+
+        /// Multithreading. When set, events of all PEs with the same level 1 affinity are
+        /// counted.
+        MT OFFSET(25) NUMBITS(1) [],
+
+        /// Secure EL2 filtering bit. When different from NSH, counting in Secure EL2 is enabled.
+        SH OFFSET(24) NUMBITS(1) [],
+
+        /// Event to count. See [`crate::pmu::Event`] for the common architectural events.
+        evtCount OFFSET(0) NUMBITS(16) []
+    ]
+}
+
+pub struct Reg {
+    pub(super) index: u8,
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMEVTYPER_EL0::Register;
+
+    sys_coproc_read_raw_indexed!(u64, [
+        0 => "PMEVTYPER0_EL0",
+        1 => "PMEVTYPER1_EL0",
+        2 => "PMEVTYPER2_EL0",
+        3 => "PMEVTYPER3_EL0",
+        4 => "PMEVTYPER4_EL0",
+        5 => "PMEVTYPER5_EL0",
+        6 => "PMEVTYPER6_EL0",
+        7 => "PMEVTYPER7_EL0",
+        8 => "PMEVTYPER8_EL0",
+        9 => "PMEVTYPER9_EL0",
+        10 => "PMEVTYPER10_EL0",
+        11 => "PMEVTYPER11_EL0",
+        12 => "PMEVTYPER12_EL0",
+        13 => "PMEVTYPER13_EL0",
+        14 => "PMEVTYPER14_EL0",
+        15 => "PMEVTYPER15_EL0",
+        16 => "PMEVTYPER16_EL0",
+        17 => "PMEVTYPER17_EL0",
+        18 => "PMEVTYPER18_EL0",
+        19 => "PMEVTYPER19_EL0",
+        20 => "PMEVTYPER20_EL0",
+        21 => "PMEVTYPER21_EL0",
+        22 => "PMEVTYPER22_EL0",
+        23 => "PMEVTYPER23_EL0",
+        24 => "PMEVTYPER24_EL0",
+        25 => "PMEVTYPER25_EL0",
+        26 => "PMEVTYPER26_EL0",
+        27 => "PMEVTYPER27_EL0",
+        28 => "PMEVTYPER28_EL0",
+        29 => "PMEVTYPER29_EL0",
+        30 => "PMEVTYPER30_EL0"
+    ], "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMEVTYPER_EL0::Register;
+
+    sys_coproc_write_raw_indexed!(u64, [
+        0 => "PMEVTYPER0_EL0",
+        1 => "PMEVTYPER1_EL0",
+        2 => "PMEVTYPER2_EL0",
+        3 => "PMEVTYPER3_EL0",
+        4 => "PMEVTYPER4_EL0",
+        5 => "PMEVTYPER5_EL0",
+        6 => "PMEVTYPER6_EL0",
+        7 => "PMEVTYPER7_EL0",
+        8 => "PMEVTYPER8_EL0",
+        9 => "PMEVTYPER9_EL0",
+        10 => "PMEVTYPER10_EL0",
+        11 => "PMEVTYPER11_EL0",
+        12 => "PMEVTYPER12_EL0",
+        13 => "PMEVTYPER13_EL0",
+        14 => "PMEVTYPER14_EL0",
+        15 => "PMEVTYPER15_EL0",
+        16 => "PMEVTYPER16_EL0",
+        17 => "PMEVTYPER17_EL0",
+        18 => "PMEVTYPER18_EL0",
+        19 => "PMEVTYPER19_EL0",
+        20 => "PMEVTYPER20_EL0",
+        21 => "PMEVTYPER21_EL0",
+        22 => "PMEVTYPER22_EL0",
+        23 => "PMEVTYPER23_EL0",
+        24 => "PMEVTYPER24_EL0",
+        25 => "PMEVTYPER25_EL0",
+        26 => "PMEVTYPER26_EL0",
+        27 => "PMEVTYPER27_EL0",
+        28 => "PMEVTYPER28_EL0",
+        29 => "PMEVTYPER29_EL0",
+        30 => "PMEVTYPER30_EL0"
+    ], "x");
+}
+
+/// Returns event type register `PMEVTYPER<n>_EL0`.
+///
+/// Panics if `n` is greater than 30.
+pub const fn pmevtyper_el0(n: u8) -> Reg {
+    assert!(n <= 30, "PMEVTYPER<n>_EL0 index out of range");
+
+    Reg { index: n }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Overflow Flag Status Clear Register - EL0
+//!
+//! Contains the state of the overflow bit for the Cycle Count Register, `PMCCNTR_EL0`, and each of
+//! the implemented event counters `PMEVCNTR<n>_EL0`. Writing to this register clears these bits.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PMOVSCLR_EL0 [
+        /// `PMCCNTR_EL0` overflow bit. Writing 1 clears the overflow bit, writing 0 has no effect.
+        C OFFSET(31) NUMBITS(1) [],
+
+        /// Event counter overflow clear bit for `PMEVCNTR<n>_EL0`, one bit per counter. Writing 1
+        /// clears the overflow bit, writing 0 has no effect.
+        P OFFSET(0) NUMBITS(31) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMOVSCLR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMOVSCLR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMOVSCLR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMOVSCLR_EL0", "x");
+}
+
+pub const PMOVSCLR_EL0: Reg = Reg {};
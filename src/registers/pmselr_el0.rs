@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Event Counter Selection Register - EL0
+//!
+//! Selects the current event counter `PMEVCNTR<n>_EL0` or the cycle counter, CCNT, for access
+//! through `PMXEVTYPER_EL0` and `PMXEVCNTR_EL0`.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PMSELR_EL0 [
+        /// Selects event counter `PMEVCNTR<n>_EL0`, where n is the value stored in this field. The
+        /// value 31 selects the cycle counter.
+        SEL OFFSET(0) NUMBITS(5) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMSELR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMSELR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMSELR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMSELR_EL0", "x");
+}
+
+pub const PMSELR_EL0: Reg = Reg {};
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors User Enable Register - EL0
+//!
+//! Enables or disables EL0 access to the Performance Monitors.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub PMUSERENR_EL0 [
+        /// Event counter read trap control. When set, EL0 can read the event counters and
+        /// `PMSELR_EL0`.
+        ER OFFSET(3) NUMBITS(1) [],
+
+        /// Cycle counter read trap control. When set, EL0 can read `PMCCNTR_EL0`.
+        CR OFFSET(2) NUMBITS(1) [],
+
+        /// Software Increment write trap control. When set, EL0 can write `PMSWINC_EL0`.
+        SW OFFSET(1) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the Performance Monitors registers to EL1 when clear.
+        EN OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMUSERENR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMUSERENR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMUSERENR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMUSERENR_EL0", "x");
+}
+
+pub const PMUSERENR_EL0: Reg = Reg {};
@@ -0,0 +1,460 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Process state
+//!
+//! A typed view of the process state as saved in [`SPSR_EL1`](const@crate::registers::SPSR_EL1),
+//! [`SPSR_EL2`](const@crate::registers::SPSR_EL2) and
+//! [`SPSR_EL3`](const@crate::registers::SPSR_EL3). All three registers share the same layout, which
+//! depends on whether the exception was taken from AArch64 or AArch32 state, so a [`Pstate`] can be
+//! converted to and from any of them.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::{
+//!     asm,
+//!     registers::pstate::{Mode, Pstate},
+//! };
+//!
+//! # let kernel_entry = 0x8_0000;
+//! // Enter EL1h at `kernel_entry`, with all exceptions masked.
+//! Pstate::new(Mode::EL1h).prepare_return(kernel_entry).unwrap();
+//! asm::eret();
+//! ```
+
+use super::{CurrentEL, ELR_EL1, ELR_EL2, ELR_EL3, SPSR_EL1, SPSR_EL2, SPSR_EL3};
+use tock_registers::interfaces::{Readable, Writeable};
+
+/// AArch32 processor mode.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Aarch32Mode {
+    User = 0b1_0000,
+    Fiq = 0b1_0001,
+    Irq = 0b1_0010,
+    Supervisor = 0b1_0011,
+    Monitor = 0b1_0110,
+    Abort = 0b1_0111,
+    Hyp = 0b1_1010,
+    Undefined = 0b1_1011,
+    System = 0b1_1111,
+}
+
+impl Aarch32Mode {
+    /// The Exception level of the mode.
+    pub const fn el(self) -> u8 {
+        match self {
+            Aarch32Mode::User => 0,
+            Aarch32Mode::Hyp => 2,
+            Aarch32Mode::Monitor => 3,
+            _ => 1,
+        }
+    }
+}
+
+/// Execution state, Exception level and stack pointer that an exception was taken from, or that
+/// an exception return goes to. The `M[4:0]` field of an SPSR.
+///
+/// The AArch64 modes use `SP_EL0` (`t`) or the stack pointer of their Exception level (`h`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    EL0t,
+    EL1t,
+    EL1h,
+    EL2t,
+    EL2h,
+    EL3t,
+    EL3h,
+    Aarch32(Aarch32Mode),
+    /// Reserved value. Returning to it is an illegal exception return.
+    Reserved(u8),
+}
+
+impl Mode {
+    /// Converts a 5-bit `M[4:0]` value.
+    pub const fn from_bits(m: u8) -> Self {
+        use Aarch32Mode::*;
+
+        match m & 0x1f {
+            0b0_0000 => Mode::EL0t,
+            0b0_0100 => Mode::EL1t,
+            0b0_0101 => Mode::EL1h,
+            0b0_1000 => Mode::EL2t,
+            0b0_1001 => Mode::EL2h,
+            0b0_1100 => Mode::EL3t,
+            0b0_1101 => Mode::EL3h,
+            0b1_0000 => Mode::Aarch32(User),
+            0b1_0001 => Mode::Aarch32(Fiq),
+            0b1_0010 => Mode::Aarch32(Irq),
+            0b1_0011 => Mode::Aarch32(Supervisor),
+            0b1_0110 => Mode::Aarch32(Monitor),
+            0b1_0111 => Mode::Aarch32(Abort),
+            0b1_1010 => Mode::Aarch32(Hyp),
+            0b1_1011 => Mode::Aarch32(Undefined),
+            0b1_1111 => Mode::Aarch32(System),
+            m => Mode::Reserved(m),
+        }
+    }
+
+    /// The 5-bit `M[4:0]` value.
+    pub const fn bits(self) -> u8 {
+        match self {
+            Mode::EL0t => 0b0_0000,
+            Mode::EL1t => 0b0_0100,
+            Mode::EL1h => 0b0_0101,
+            Mode::EL2t => 0b0_1000,
+            Mode::EL2h => 0b0_1001,
+            Mode::EL3t => 0b0_1100,
+            Mode::EL3h => 0b0_1101,
+            Mode::Aarch32(mode) => mode as u8,
+            Mode::Reserved(m) => m & 0x1f,
+        }
+    }
+
+    /// The Exception level of the mode, if it is not reserved.
+    pub const fn el(self) -> Option<u8> {
+        match self {
+            Mode::Aarch32(mode) => Some(mode.el()),
+            Mode::Reserved(_) => None,
+            mode => Some(mode.bits() >> 2),
+        }
+    }
+
+    pub const fn is_aarch32(self) -> bool {
+        matches!(self, Mode::Aarch32(_))
+    }
+}
+
+/// Process state that only exists in AArch32 state.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Aarch32State {
+    /// Overflow or saturation flag.
+    pub q: bool,
+    /// Greater than or Equal flags, set by the parallel addition and subtraction instructions.
+    pub ge: u8,
+    /// If-Then execution state bits for the T32 IT instruction.
+    pub it: u8,
+    /// Endianness of data accesses. Set for big-endian.
+    pub e: bool,
+    /// T32 instruction set state.
+    pub t: bool,
+}
+
+/// Process state, as saved in an SPSR.
+///
+/// Fields that do not exist in the execution state of [`mode`](Pstate::mode) are ignored when
+/// converting to an SPSR value, and read as zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Pstate {
+    /// Negative condition flag.
+    pub n: bool,
+    /// Zero condition flag.
+    pub z: bool,
+    /// Carry condition flag.
+    pub c: bool,
+    /// Overflow condition flag.
+    pub v: bool,
+    /// Profiling exception mask (FEAT_EBEP). AArch64 only.
+    pub pm: bool,
+    /// Tag Check Override (FEAT_MTE). AArch64 only.
+    pub tco: bool,
+    /// Data Independent Timing (FEAT_DIT).
+    pub dit: bool,
+    /// User Access Override (FEAT_UAO). AArch64 only.
+    pub uao: bool,
+    /// Privileged Access Never (FEAT_PAN).
+    pub pan: bool,
+    /// Software step.
+    pub ss: bool,
+    /// Illegal Execution state.
+    pub il: bool,
+    /// All IRQ or FIQ interrupts mask (FEAT_NMI). AArch64 only.
+    pub allint: bool,
+    /// Speculative Store Bypass Safe (FEAT_SSBS).
+    pub ssbs: bool,
+    /// Branch Type Indicator (FEAT_BTI). AArch64 only.
+    pub btype: u8,
+    /// Watchpoint, Breakpoint and Software Step exceptions mask. AArch64 only.
+    pub d: bool,
+    /// SError interrupt mask.
+    pub a: bool,
+    /// IRQ interrupt mask.
+    pub i: bool,
+    /// FIQ interrupt mask.
+    pub f: bool,
+    pub mode: Mode,
+    /// State that only exists in AArch32 state.
+    pub aarch32: Aarch32State,
+}
+
+/// Reason for an exception return being illegal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IllegalReturn {
+    /// The target mode is reserved.
+    ReservedMode(u8),
+    /// The target Exception level is higher than the current one.
+    HigherExceptionLevel,
+    /// The target is AArch32 state at the current Exception level.
+    Aarch32SameLevel,
+    /// Exception returns are UNDEFINED at EL0.
+    FromEL0,
+}
+
+impl Pstate {
+    /// Returns the state for entering `mode`, with D, A, I and F masked and everything else
+    /// cleared.
+    pub const fn new(mode: Mode) -> Self {
+        Pstate {
+            n: false,
+            z: false,
+            c: false,
+            v: false,
+            pm: false,
+            tco: false,
+            dit: false,
+            uao: false,
+            pan: false,
+            ss: false,
+            il: false,
+            allint: false,
+            ssbs: false,
+            btype: 0,
+            d: true,
+            a: true,
+            i: true,
+            f: true,
+            mode,
+            aarch32: Aarch32State {
+                q: false,
+                ge: 0,
+                it: 0,
+                e: false,
+                t: false,
+            },
+        }
+    }
+
+    /// Returns the state with D, A, I and F unmasked, e.g. for entering EL0.
+    pub const fn unmasked(mut self) -> Self {
+        self.d = false;
+        self.a = false;
+        self.i = false;
+        self.f = false;
+        self
+    }
+
+    /// Decodes a raw SPSR value.
+    pub const fn from_bits(spsr: u64) -> Self {
+        let mode = Mode::from_bits(spsr as u8);
+        let aarch64 = !mode.is_aarch32();
+        Pstate {
+            n: bit(spsr, 31),
+            z: bit(spsr, 30),
+            c: bit(spsr, 29),
+            v: bit(spsr, 28),
+            pm: aarch64 && bit(spsr, 32),
+            tco: aarch64 && bit(spsr, 25),
+            dit: bit(spsr, 24),
+            uao: aarch64 && bit(spsr, 23),
+            pan: bit(spsr, 22),
+            ss: bit(spsr, 21),
+            il: bit(spsr, 20),
+            allint: aarch64 && bit(spsr, 13),
+            ssbs: bit(spsr, if aarch64 { 12 } else { 23 }),
+            btype: if aarch64 {
+                ((spsr >> 10) & 0b11) as u8
+            } else {
+                0
+            },
+            d: aarch64 && bit(spsr, 9),
+            a: bit(spsr, 8),
+            i: bit(spsr, 7),
+            f: bit(spsr, 6),
+            mode,
+            aarch32: Aarch32State {
+                q: !aarch64 && bit(spsr, 27),
+                ge: if aarch64 {
+                    0
+                } else {
+                    ((spsr >> 16) & 0xf) as u8
+                },
+                it: if aarch64 {
+                    0
+                } else {
+                    (((spsr >> 8) & 0xfc) | ((spsr >> 25) & 0b11)) as u8
+                },
+                e: !aarch64 && bit(spsr, 9),
+                t: !aarch64 && bit(spsr, 5),
+            },
+        }
+    }
+
+    /// The raw SPSR value.
+    pub const fn bits(&self) -> u64 {
+        let mut spsr = (self.n as u64) << 31
+            | (self.z as u64) << 30
+            | (self.c as u64) << 29
+            | (self.v as u64) << 28
+            | (self.dit as u64) << 24
+            | (self.pan as u64) << 22
+            | (self.ss as u64) << 21
+            | (self.il as u64) << 20
+            | (self.a as u64) << 8
+            | (self.i as u64) << 7
+            | (self.f as u64) << 6
+            | self.mode.bits() as u64;
+
+        if self.mode.is_aarch32() {
+            let state = &self.aarch32;
+
+            spsr |= (state.q as u64) << 27
+                | ((state.it & 0b11) as u64) << 25
+                | (self.ssbs as u64) << 23
+                | ((state.ge & 0xf) as u64) << 16
+                | ((state.it & 0xfc) as u64) << 8
+                | (state.e as u64) << 9
+                | (state.t as u64) << 5;
+        } else {
+            spsr |= (self.pm as u64) << 32
+                | (self.tco as u64) << 25
+                | (self.uao as u64) << 23
+                | (self.allint as u64) << 13
+                | (self.ssbs as u64) << 12
+                | ((self.btype & 0b11) as u64) << 10
+                | (self.d as u64) << 9;
+        }
+
+        spsr
+    }
+
+    /// Checks that an exception return from the AArch64 Exception level `current_el` to this state
+    /// is legal.
+    ///
+    /// Whether the target Exception level and execution state are implemented is not checked.
+    pub const fn check(&self, current_el: u8) -> Result<(), IllegalReturn> {
+        let el = match self.mode.el() {
+            Some(el) => el,
+            None => return Err(IllegalReturn::ReservedMode(self.mode.bits())),
+        };
+
+        if current_el == 0 {
+            Err(IllegalReturn::FromEL0)
+        } else if el > current_el {
+            Err(IllegalReturn::HigherExceptionLevel)
+        } else if el == current_el && self.mode.is_aarch32() {
+            Err(IllegalReturn::Aarch32SameLevel)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Prepares an exception return from the current Exception level to this state at address
+    /// `elr`, by writing the `SPSR_ELx` and `ELR_ELx` of the current Exception level.
+    pub fn prepare_return(&self, elr: u64) -> Result<(), IllegalReturn> {
+        let current_el = CurrentEL.read(CurrentEL::EL) as u8;
+        self.check(current_el)?;
+
+        match current_el {
+            1 => {
+                SPSR_EL1.set(self.bits());
+                ELR_EL1.set(elr);
+            }
+            2 => {
+                SPSR_EL2.set(self.bits());
+                ELR_EL2.set(elr);
+            }
+            _ => {
+                SPSR_EL3.set(self.bits());
+                ELR_EL3.set(elr);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const fn bit(spsr: u64, n: u32) -> bool {
+    spsr & (1 << n) != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aarch64() {
+        let pstate = Pstate::from_bits(0x6000_03c5);
+        assert_eq!(pstate.mode, Mode::EL1h);
+        assert!(pstate.z && pstate.c && !pstate.n);
+        assert!(pstate.d && pstate.a && pstate.i && pstate.f);
+        assert_eq!(pstate.bits(), 0x6000_03c5);
+
+        let mut pstate = Pstate::new(Mode::EL0t).unmasked();
+        pstate.pan = true;
+        pstate.ssbs = true;
+        pstate.btype = 0b10;
+        pstate.pm = true;
+        assert_eq!(
+            pstate.bits(),
+            (1 << 32) | (1 << 22) | (1 << 12) | (0b10 << 10)
+        );
+        assert_eq!(Pstate::from_bits(pstate.bits()), pstate);
+    }
+
+    #[test]
+    fn aarch32() {
+        // T32 in Supervisor mode, within an IT block, with SSBS and GE[1:0] set.
+        let spsr = (0b01 << 25) | (1 << 23) | (0b0011 << 16) | (0b10_1000 << 10) | (1 << 5) | 0x13;
+        let pstate = Pstate::from_bits(spsr);
+        assert_eq!(pstate.mode, Mode::Aarch32(Aarch32Mode::Supervisor));
+        assert_eq!(pstate.mode.el(), Some(1));
+        assert!(pstate.ssbs && !pstate.uao && !pstate.tco);
+        assert_eq!(pstate.aarch32.it, 0b1010_0001);
+        assert_eq!(pstate.aarch32.ge, 0b0011);
+        assert!(pstate.aarch32.t);
+        assert_eq!(pstate.bits(), spsr);
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(Pstate::new(Mode::EL1h).check(2), Ok(()));
+        assert_eq!(Pstate::new(Mode::EL2h).check(2), Ok(()));
+        assert_eq!(
+            Pstate::new(Mode::EL2t).check(1),
+            Err(IllegalReturn::HigherExceptionLevel)
+        );
+        assert_eq!(
+            Pstate::from_bits(0b0_0001).check(1),
+            Err(IllegalReturn::ReservedMode(0b0_0001))
+        );
+        assert_eq!(
+            Pstate::new(Mode::Aarch32(Aarch32Mode::User)).check(1),
+            Ok(())
+        );
+        assert_eq!(
+            Pstate::new(Mode::Aarch32(Aarch32Mode::Supervisor)).check(1),
+            Err(IllegalReturn::Aarch32SameLevel)
+        );
+        assert_eq!(
+            Pstate::new(Mode::EL0t).check(0),
+            Err(IllegalReturn::FromEL0)
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn prepare_return() {
+        use crate::registers::mock;
+
+        mock::clear();
+        mock::set_reset_value("CurrentEL", 2 << 2);
+        Pstate::new(Mode::EL1h).prepare_return(0x8_0000).unwrap();
+        assert_eq!(mock::peek("SPSR_EL2"), 0x3c5);
+        assert_eq!(mock::peek("ELR_EL2"), 0x8_0000);
+    }
+}
@@ -12,12 +12,9 @@
 //! • Whether IRQ, FIQ, SError interrupts, and External abort exceptions are taken to EL3.
 //! • Whether various operations are trapped to EL3.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub SCR_EL3 [
         /// Execution state control for lower Exception levels:
         ///
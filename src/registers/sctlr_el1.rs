@@ -9,12 +9,9 @@
 //!
 //! Provides top level control of the system, including its memory system, at EL1 and EL0.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub SCTLR_EL1 [
         /// Traps EL0 execution of cache maintenance instructions to EL1, from AArch64 state only.
         ///
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! System Control Register - EL12
+//!
+//! Accesses SCTLR_EL1 from EL2 when HCR_EL2.E2H is 1, in which case SCTLR_EL1 accesses its EL2
+//! counterpart. Uses the SCTLR_EL1 layout. Requires FEAT_VHE.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::registers::*;
+//!
+//! SCTLR_EL12.modify(SCTLR_EL1::M::Enable);
+//! ```
+
+use crate::registers::SCTLR_EL1;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SCTLR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "SCTLR_EL12" = "S3_5_C1_C0_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SCTLR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "SCTLR_EL12" = "S3_5_C1_C0_0", "x");
+}
+
+pub const SCTLR_EL12: Reg = Reg {};
@@ -10,12 +10,9 @@
 //!
 //! Provides top level control of the system, including its memory system, at EL2.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub SCTLR_EL2 [
 
         /// Exception endianness. The possible values are:
@@ -9,12 +9,9 @@
 //!
 //! Provides top level control of the system, including its memory system, at EL3.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub SCTLR_EL3 [
 
         /// When FEAT_NMI is implemented:
@@ -309,6 +306,7 @@ register_bitfields! {u64,
         ///        exception entry to EL3, so that a direct read of the register after exception entry sees the
         ///        indirectly written value caused by the exception entry.
         ///
+        /// ```text
         ///        • Memory transactions, including instruction fetches, from an Exception level always use the
         ///        translation resources associated with that translation regime.
         ///
@@ -323,6 +321,7 @@ register_bitfields! {u64,
         ///        loads, stores and data processing instructions.
         ///
         ///        • Debug state exit.
+        /// ```
         ///
         /// The reset behavior of this field is:
         ///        • On a Warm reset, in a system where the PE resets into EL3, this field resets to an
@@ -340,9 +339,11 @@ register_bitfields! {u64,
         ///
         /// 0b1    An implicit error synchronization event is added:
         ///
+        /// ```text
         ///        • At each exception taken to EL3.
         ///
         ///        • Before the operational pseudocode of each ERET instruction executed at EL3.
+        /// ```
         ///
         /// When the PE is in Debug state, the effect of this field is CONSTRAINED UNPREDICTABLE, and its
         /// Effective value might be 0 or 1 regardless of the value of the field and, if implemented,
@@ -412,16 +413,20 @@ register_bitfields! {u64,
         /// 0b0    All instruction access to Normal memory from EL3 are Non-cacheable for all levels of
         ///        instruction and unified cache.
         ///
+        /// ```text
         ///        If the value of SCTLR_EL3.M is 0, instruction accesses from stage 1 of the EL3
         ///        translation regime are to Normal, Outer Shareable, Inner Non-cacheable, Outer
         ///        Non-cacheable memory.
+        /// ```
         ///
         /// 0b1    This control has no effect on the Cacheability of instruction access to Normal memory
         ///        from EL3.
         ///
+        /// ```text
         ///        If the value of SCTLR_EL3.M is 0, instruction accesses from stage 1 of the EL3
         ///        translation regime are to Normal, Outer Shareable, Inner Write-Through, Outer
         ///        Write-Through memory.
+        /// ```
         ///
         /// This bit has no effect on the EL1&0, EL2, or EL2&0 translation regimes.
         ///
@@ -444,6 +449,7 @@ register_bitfields! {u64,
         ///        • Memory transactions, including instruction fetches, from an Exception level always use the
         ///        translation resources associated with that translation regime.
         ///
+        /// ```text
         ///        • Exception Catch debug events are synchronous debug events.
         ///
         ///        • DCPS* and DRPS instructions are context synchronization events.
@@ -462,6 +468,7 @@ register_bitfields! {u64,
         ///        loads, stores and data processing instructions.
         ///
         ///        • Exit from Debug state.
+        /// ```
         ///
         /// The reset behavior of this field is:
         ///        • On a Warm reset, in a system where the PE resets into EL3, this field resets to an
@@ -477,10 +484,12 @@ register_bitfields! {u64,
         /// conditions. The following instructions generate an Alignment fault if all bytes being accessed are
         /// not within a single 16-byte quantity, aligned to 16 bytes for access:
         ///
+        /// ```text
         ///        • LDAPR, LDAPRH, LDAPUR, LDAPURH, LDAPURSH, LDAPURSW, LDAR, LDARH,
         ///        LDLAR, LDLARH.
         ///
         ///        • STLLR, STLLRH, STLR, STLRH, STLUR, and STLURH
+        /// ```
         ///
         /// 0b0    Unaligned accesses by the specified instructions generate an Alignment fault.
         ///
@@ -9,12 +9,9 @@
 //!
 //! Allows the Stack Pointer to be selected between SP_EL0 and SP_ELx.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub SPSel [
         /// Stack pointer to use. Possible values of this bit are:
         ///
@@ -9,12 +9,10 @@
 //!
 //! Holds the saved process state when an exception is taken to EL1.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use crate::registers::pstate::Pstate;
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub SPSR_EL1 [
         /// Negative condition flag.
         ///
@@ -128,6 +126,20 @@ register_bitfields! {u64,
 
 pub struct Reg;
 
+impl Reg {
+    /// Reads the register as a typed process state.
+    #[inline(always)]
+    pub fn pstate(&self) -> Pstate {
+        Pstate::from_bits(self.get())
+    }
+
+    /// Writes a typed process state to the register.
+    #[inline(always)]
+    pub fn set_pstate(&self, pstate: Pstate) {
+        self.set(pstate.bits())
+    }
+}
+
 impl Readable for Reg {
     type T = u64;
     type R = SPSR_EL1::Register;
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Saved Program Status Register - EL12
+//!
+//! Accesses SPSR_EL1 from EL2 when HCR_EL2.E2H is 1, in which case SPSR_EL1 accesses its EL2
+//! counterpart. Uses the SPSR_EL1 layout. Requires FEAT_VHE.
+
+use crate::registers::{pstate::Pstate, SPSR_EL1};
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Reg {
+    /// Reads the register as a typed process state.
+    #[inline(always)]
+    pub fn pstate(&self) -> Pstate {
+        Pstate::from_bits(self.get())
+    }
+
+    /// Writes a typed process state to the register.
+    #[inline(always)]
+    pub fn set_pstate(&self, pstate: Pstate) {
+        self.set(pstate.bits())
+    }
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SPSR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "SPSR_EL12" = "S3_5_C4_C0_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SPSR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "SPSR_EL12" = "S3_5_C4_C0_0", "x");
+}
+
+pub const SPSR_EL12: Reg = Reg {};
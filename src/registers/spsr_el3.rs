@@ -10,12 +10,10 @@
 //!
 //! Holds the saved process state when an exception is taken to EL3.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use crate::registers::pstate::Pstate;
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub SPSR_EL3 [
         /// Negative condition flag.
         ///
@@ -137,6 +135,20 @@ register_bitfields! {u64,
 
 pub struct Reg;
 
+impl Reg {
+    /// Reads the register as a typed process state.
+    #[inline(always)]
+    pub fn pstate(&self) -> Pstate {
+        Pstate::from_bits(self.get())
+    }
+
+    /// Writes a typed process state to the register.
+    #[inline(always)]
+    pub fn set_pstate(&self, pstate: Pstate) {
+        self.set(pstate.bits())
+    }
+}
+
 impl Readable for Reg {
     type T = u64;
     type R = SPSR_EL3::Register;
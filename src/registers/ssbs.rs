@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Speculative Store Bypass Safe
+//!
+//! Allows access to the Speculative Store Bypass Safe bit. Requires FEAT_SSBS.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub SSBS [
+        /// Speculative Store Bypass Safe.
+        ///
+        /// When clear, hardware is not permitted to load or store speculatively in a manner that could
+        /// allow data from a store to be bypassed by a speculative load.
+        SSBS OFFSET(12) NUMBITS(1) [
+            Disallowed = 0,
+            Allowed = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SSBS::Register;
+
+    sys_coproc_read_raw!(u64, "SSBS" = "S3_3_C4_C2_6", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SSBS::Register;
+
+    sys_coproc_write_raw!(u64, "SSBS" = "S3_3_C4_C2_6", "x");
+}
+
+pub const SSBS: Reg = Reg {};
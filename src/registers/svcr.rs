@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Streaming Vector Control Register
+//!
+//! Controls Streaming SVE mode and SME behavior. Requires FEAT_SME.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub SVCR [
+        /// Enables SME ZA storage.
+        ZA OFFSET(1) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ],
+
+        /// Enables Streaming SVE mode.
+        SM OFFSET(0) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SVCR::Register;
+
+    sys_coproc_read_raw!(u64, "SVCR" = "S3_3_C4_C2_2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SVCR::Register;
+
+    sys_coproc_write_raw!(u64, "SVCR" = "S3_3_C4_C2_2", "x");
+}
+
+pub const SVCR: Reg = Reg {};
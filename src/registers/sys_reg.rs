@@ -0,0 +1,274 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Generic system register access by encoding.
+//!
+//! [`SysReg`] accesses the system register `S<op0>_<op1>_C<n>_C<m>_<op2>` given by its const
+//! parameters, which makes IMPLEMENTATION DEFINED registers and registers not yet known to the
+//! assembler reachable. The [`sys_reg!`](crate::sys_reg) macro declares such a register under a
+//! name, optionally together with its fields.
+//!
+//! In the mock backend, these registers are named by their generic encoding, e.g.
+//! `"S3_1_C15_C2_0"`.
+
+use core::marker::PhantomData;
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    RegisterLongName,
+};
+
+/// The system register with the encoding `op0`, `op1`, `CRn`, `CRm`, `op2`, with the fields of `R`.
+///
+/// # Example
+///
+/// ```no_run
+/// use aarch64_cpu::registers::{Readable, SysReg};
+///
+/// // RNDR, read through its generic encoding.
+/// const RNDR: SysReg<3, 3, 2, 4, 0> = SysReg::new();
+///
+/// let random = RNDR.get();
+/// ```
+pub struct SysReg<
+    const OP0: u8,
+    const OP1: u8,
+    const CRN: u8,
+    const CRM: u8,
+    const OP2: u8,
+    R: RegisterLongName = (),
+> {
+    associated_register: PhantomData<R>,
+}
+
+/// Writes the decimal representation of `value` to `buf` at `len` and returns the new length.
+const fn push_decimal(buf: &mut [u8; 16], mut len: usize, value: u8) -> usize {
+    if value >= 10 {
+        buf[len] = b'0' + value / 10;
+        len += 1;
+    }
+    buf[len] = b'0' + value % 10;
+
+    len + 1
+}
+
+/// Returns the generic name `S<op0>_<op1>_C<n>_C<m>_<op2>` of an encoding, and its length.
+const fn encoding_name(op0: u8, op1: u8, crn: u8, crm: u8, op2: u8) -> ([u8; 16], usize) {
+    assert!(op0 <= 3 && op1 <= 7 && crn <= 15 && crm <= 15 && op2 <= 7);
+
+    let mut buf = [0; 16];
+    let mut len = 0;
+
+    buf[len] = b'S';
+    len = push_decimal(&mut buf, len + 1, op0);
+    buf[len] = b'_';
+    len = push_decimal(&mut buf, len + 1, op1);
+    buf[len] = b'_';
+    buf[len + 1] = b'C';
+    len = push_decimal(&mut buf, len + 2, crn);
+    buf[len] = b'_';
+    buf[len + 1] = b'C';
+    len = push_decimal(&mut buf, len + 2, crm);
+    buf[len] = b'_';
+    len = push_decimal(&mut buf, len + 1, op2);
+
+    (buf, len)
+}
+
+impl<
+        const OP0: u8,
+        const OP1: u8,
+        const CRN: u8,
+        const CRM: u8,
+        const OP2: u8,
+        R: RegisterLongName,
+    > SysReg<OP0, OP1, CRN, CRM, OP2, R>
+{
+    const ENCODING_NAME: ([u8; 16], usize) = encoding_name(OP0, OP1, CRN, CRM, OP2);
+
+    /// The generic name of the register, e.g. `S3_1_C15_C2_0`.
+    pub const NAME: &'static str = {
+        let (name, _) = Self::ENCODING_NAME.0.split_at(Self::ENCODING_NAME.1);
+
+        // Only contains ASCII characters.
+        unsafe { core::str::from_utf8_unchecked(name) }
+    };
+
+    pub const fn new() -> Self {
+        // Rejects invalid encodings at compile time.
+        let _ = Self::ENCODING_NAME;
+
+        SysReg {
+            associated_register: PhantomData,
+        }
+    }
+}
+
+impl<
+        const OP0: u8,
+        const OP1: u8,
+        const CRN: u8,
+        const CRM: u8,
+        const OP2: u8,
+        R: RegisterLongName,
+    > Default for SysReg<OP0, OP1, CRN, CRM, OP2, R>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<
+        const OP0: u8,
+        const OP1: u8,
+        const CRN: u8,
+        const CRM: u8,
+        const OP2: u8,
+        R: RegisterLongName,
+    > Readable for SysReg<OP0, OP1, CRN, CRM, OP2, R>
+{
+    type T = u64;
+    type R = R;
+
+    /// Reads the raw bits of the CPU register.
+    #[inline]
+    fn get(&self) -> u64 {
+        __read_reg!(
+            u64,
+            "mrs",
+            Self::NAME,
+            "S{op0}_{op1}_C{crn}_C{crm}_{op2}",
+            "x",
+            op0 = const OP0,
+            op1 = const OP1,
+            crn = const CRN,
+            crm = const CRM,
+            op2 = const OP2
+        )
+    }
+}
+
+impl<
+        const OP0: u8,
+        const OP1: u8,
+        const CRN: u8,
+        const CRM: u8,
+        const OP2: u8,
+        R: RegisterLongName,
+    > Writeable for SysReg<OP0, OP1, CRN, CRM, OP2, R>
+{
+    type T = u64;
+    type R = R;
+
+    /// Writes raw bits to the CPU register.
+    #[cfg_attr(
+        not(any(target_arch = "aarch64", feature = "mock")),
+        allow(unused_variables)
+    )]
+    #[inline]
+    fn set(&self, value: u64) {
+        __write_reg!(
+            u64,
+            "msr",
+            Self::NAME,
+            "S{op0}_{op1}_C{crn}_C{crm}_{op2}",
+            "x",
+            value,
+            op0 = const OP0,
+            op1 = const OP1,
+            crn = const CRN,
+            crm = const CRM,
+            op2 = const OP2
+        )
+    }
+}
+
+/// Declares a system register accessed by its encoding, optionally with the fields of
+/// [`register_bitfields!`](tock_registers::register_bitfields).
+///
+/// The register is a constant of type [`SysReg`], and the fields are declared in a module of the
+/// same name.
+///
+/// # Example
+///
+/// ```no_run
+/// use aarch64_cpu::{registers::*, sys_reg};
+///
+/// sys_reg!(pub CPUECTLR_EL1 = (3, 1, 15, 2, 1));
+///
+/// sys_reg! {
+///     /// Cortex-A53 CPU Auxiliary Control Register.
+///     pub CPUACTLR_EL1 = (3, 1, 15, 2, 0) [
+///         /// Enable data cache clean as data cache clean/invalidate.
+///         ENDCCASCI OFFSET(44) NUMBITS(1) []
+///     ]
+/// }
+///
+/// CPUACTLR_EL1.modify(CPUACTLR_EL1::ENDCCASCI::SET);
+/// let cpuectlr = CPUECTLR_EL1.get();
+/// ```
+#[macro_export]
+macro_rules! sys_reg {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident = ($op0:literal, $op1:literal, $crn:literal, $crm:literal, $op2:literal)
+    ) => {
+        $(#[$attr])*
+        #[allow(non_upper_case_globals)]
+        $vis const $name: $crate::registers::SysReg<$op0, $op1, $crn, $crm, $op2> =
+            $crate::registers::SysReg::new();
+    };
+
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident = ($op0:literal, $op1:literal, $crn:literal, $crm:literal, $op2:literal)
+        [$($fields:tt)*]
+    ) => {
+        $crate::registers::__register_bitfields! {u64,
+            $vis $name [$($fields)*]
+        }
+
+        $(#[$attr])*
+        #[allow(non_upper_case_globals)]
+        $vis const $name: $crate::registers::SysReg<
+            $op0,
+            $op1,
+            $crn,
+            $crm,
+            $op2,
+            $name::Register,
+        > = $crate::registers::SysReg::new();
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(SysReg::<3, 1, 15, 2, 0>::NAME, "S3_1_C15_C2_0");
+        assert_eq!(SysReg::<2, 0, 0, 0, 7>::NAME, "S2_0_C0_C0_7");
+        assert_eq!(SysReg::<3, 7, 15, 15, 7>::NAME, "S3_7_C15_C15_7");
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn declared() {
+        use crate::registers::mock;
+        use tock_registers::interfaces::ReadWriteable;
+
+        sys_reg! {
+            IMP_CTLR = (3, 1, 15, 2, 0) [
+                EN OFFSET(3) NUMBITS(1) []
+            ]
+        }
+
+        mock::clear();
+        IMP_CTLR.modify(IMP_CTLR::EN::SET);
+        assert_eq!(mock::peek("S3_1_C15_C2_0"), 1 << 3);
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Tag Check Override
+//!
+//! Allows access to the Tag Check Override bit. Requires FEAT_MTE.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub TCO [
+        /// Tag Check Override.
+        ///
+        /// When set, loads and stores are Tag Unchecked.
+        TCO OFFSET(25) NUMBITS(1) [
+            Unchecked = 0,
+            Override = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = TCO::Register;
+
+    sys_coproc_read_raw!(u64, "TCO" = "S3_3_C4_C2_7", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = TCO::Register;
+
+    sys_coproc_write_raw!(u64, "TCO" = "S3_3_C4_C2_7", "x");
+}
+
+pub const TCO: Reg = Reg {};
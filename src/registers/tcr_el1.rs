@@ -9,12 +9,9 @@
 //!
 //! The control register for stage 1 of the EL1&0 translation regime.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub TCR_EL1 [
         /// When ARMv8.3-PAuth is implemented:
         ///     Controls the use of the top byte of instruction addresses for address matching.
@@ -10,12 +10,9 @@
 //!
 //! The control register for stage 1 of the EL2, or EL2&0 translation regime.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub TCR_EL2 [
 
         /// When FEAT_HAFDBS is implemented hardware can update the dirty flags in the stage1
@@ -160,7 +157,6 @@ register_bitfields! {u64,
             WriteBack_ReadAlloc_NoWriteAlloc_Cacheable = 0b11
         ],
 
-
         /// The size offset of the memory region addressed by TTBR0_EL2. The region size is
         /// 2^(64-T0SZ) bytes.
         ///
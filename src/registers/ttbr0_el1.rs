@@ -11,12 +11,9 @@
 //! translation of an address from the lower VA range in the EL1&0 translation regime, and other
 //! information for this translation regime.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub TTBR0_EL1 [
         /// An ASID for the translation table base address. The TCR_EL1.A1 field selects either
         /// TTBR0_EL1.ASID or TTBR1_EL1.ASID.
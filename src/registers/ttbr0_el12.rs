@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Translation Table Base Register 0 - EL12
+//!
+//! Accesses TTBR0_EL1 from EL2 when HCR_EL2.E2H is 1, in which case TTBR0_EL1 accesses its EL2
+//! counterpart. Uses the TTBR0_EL1 layout. Requires FEAT_VHE.
+
+use crate::registers::TTBR0_EL1;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Reg {
+    #[inline(always)]
+    pub fn get_baddr(&self) -> u64 {
+        self.read(TTBR0_EL1::BADDR) << 1
+    }
+
+    #[inline(always)]
+    pub fn set_baddr(&self, addr: u64) {
+        self.write(TTBR0_EL1::BADDR.val(addr >> 1));
+    }
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = TTBR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "TTBR0_EL12" = "S3_5_C2_C0_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = TTBR0_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "TTBR0_EL12" = "S3_5_C2_C0_0", "x");
+}
+
+pub const TTBR0_EL12: Reg = Reg {};
@@ -11,12 +11,9 @@
 //! Holds the base address of the translation table for the initial lookup for stage 1 of the
 //! translation of an address from the lower VA range for accesses from EL2.
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub TTBR0_EL2 [
         /// Reserved
         RES0  OFFSET(48) NUMBITS(16) [],
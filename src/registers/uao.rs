@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! User Access Override
+//!
+//! Allows access to the User Access Override bit. Requires FEAT_UAO.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+described_bitfields! {u64,
+    pub UAO [
+        /// User Access Override.
+        ///
+        /// When set, the unprivileged load and store instructions (`LDTR*`, `STTR*`) executed at EL1, or
+        /// EL2 when `HCR_EL2.E2H` is 1, behave as the corresponding ordinary load and store instructions.
+        UAO OFFSET(23) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = UAO::Register;
+
+    sys_coproc_read_raw!(u64, "UAO" = "S3_0_C4_C2_4", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = UAO::Register;
+
+    sys_coproc_write_raw!(u64, "UAO" = "S3_0_C4_C2_4", "x");
+}
+
+pub const UAO: Reg = Reg {};
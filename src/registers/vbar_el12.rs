@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Vector Base Address Register - EL12
+//!
+//! Accesses VBAR_EL1 from EL2 when HCR_EL2.E2H is 1, in which case VBAR_EL1 accesses its EL2
+//! counterpart. Requires FEAT_VHE.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "VBAR_EL12" = "S3_5_C12_C0_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "VBAR_EL12" = "S3_5_C12_C0_0", "x");
+}
+
+pub const VBAR_EL12: Reg = Reg {};
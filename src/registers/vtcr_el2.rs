@@ -9,12 +9,9 @@
 //!
 //! Provides control of stage2 translation of EL0/1
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub VTCR_EL2 [
         /// Reserved, RES1.
         RES1 OFFSET(31) NUMBITS(1) [],
@@ -6,12 +6,9 @@
 //   - KarimAllah Ahmed <karahmed@amazon.com>
 //   - Andre Richter <andre.o.richter@gmail.com>
 
-use tock_registers::{
-    interfaces::{Readable, Writeable},
-    register_bitfields,
-};
+use tock_registers::interfaces::{Readable, Writeable};
 
-register_bitfields! {u64,
+described_bitfields! {u64,
     pub VTTBR_EL2 [
         /// An VMID for the translation table
         ///
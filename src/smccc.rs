@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Arm SMC Calling Convention.
+//!
+//! Calls pass the function identifier in `w0` and up to 17 arguments in `x1` to `x17`. Results
+//! are returned in `x0` to `x17`, as allowed since SMCCC v1.2. All of these registers are treated
+//! as clobbered by a call.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::smccc::{self, Conduit};
+//!
+//! let version = unsafe { smccc::call(Conduit::Smc, smccc::SMCCC_VERSION, &[]) }[0] as i32;
+//! ```
+
+pub mod psci;
+
+/// The instruction used to reach the firmware or hypervisor implementing a service.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Conduit {
+    /// Hypervisor Call, handled at EL2.
+    Hvc,
+    /// Secure Monitor Call, handled at EL3.
+    Smc,
+}
+
+/// Whether a call is atomic from the perspective of the caller.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CallType {
+    /// The call can be preempted, e.g. by interrupts.
+    Yielding,
+    /// The call executes atomically.
+    Fast,
+}
+
+/// Calling convention of a call, determining the width of the arguments and results.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Convention {
+    /// 32-bit arguments and results in `w0`-`w7`.
+    Smc32,
+    /// 64-bit arguments and results in `x0`-`x17`.
+    Smc64,
+}
+
+/// The service that owns a function identifier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Owner {
+    /// Arm Architecture Calls (0).
+    Arm,
+    /// CPU Service Calls (1).
+    Cpu,
+    /// Silicon Partner Service Calls (2).
+    SiP,
+    /// OEM Service Calls (3).
+    Oem,
+    /// Standard Secure Service Calls, e.g. PSCI (4).
+    StandardSecure,
+    /// Standard Hypervisor Service Calls (5).
+    StandardHypervisor,
+    /// Vendor Specific Hypervisor Service Calls (6).
+    VendorHypervisor,
+    /// Any other owning entity number, e.g. trusted applications (48-49) or trusted OSes (50-63).
+    Other(u8),
+}
+
+impl Owner {
+    /// The 6-bit owning entity number.
+    pub const fn number(self) -> u8 {
+        match self {
+            Owner::Arm => 0,
+            Owner::Cpu => 1,
+            Owner::SiP => 2,
+            Owner::Oem => 3,
+            Owner::StandardSecure => 4,
+            Owner::StandardHypervisor => 5,
+            Owner::VendorHypervisor => 6,
+            Owner::Other(n) => n & 0x3f,
+        }
+    }
+
+    /// Returns the owner with the 6-bit owning entity number `n`.
+    pub const fn from_number(n: u8) -> Self {
+        match n & 0x3f {
+            0 => Owner::Arm,
+            1 => Owner::Cpu,
+            2 => Owner::SiP,
+            3 => Owner::Oem,
+            4 => Owner::StandardSecure,
+            5 => Owner::StandardHypervisor,
+            6 => Owner::VendorHypervisor,
+            n => Owner::Other(n),
+        }
+    }
+}
+
+/// A 32-bit SMCCC function identifier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FunctionId(pub u32);
+
+impl FunctionId {
+    /// Builds a function identifier from its parts.
+    pub const fn new(
+        call_type: CallType,
+        convention: Convention,
+        owner: Owner,
+        number: u16,
+    ) -> Self {
+        let fast = match call_type {
+            CallType::Yielding => 0,
+            CallType::Fast => 1 << 31,
+        };
+        let smc64 = match convention {
+            Convention::Smc32 => 0,
+            Convention::Smc64 => 1 << 30,
+        };
+
+        FunctionId(fast | smc64 | ((owner.number() as u32) << 24) | number as u32)
+    }
+
+    /// Builds a fast call identifier using the SMC32 calling convention.
+    pub const fn fast32(owner: Owner, number: u16) -> Self {
+        Self::new(CallType::Fast, Convention::Smc32, owner, number)
+    }
+
+    /// Builds a fast call identifier using the SMC64 calling convention.
+    pub const fn fast64(owner: Owner, number: u16) -> Self {
+        Self::new(CallType::Fast, Convention::Smc64, owner, number)
+    }
+
+    pub const fn call_type(self) -> CallType {
+        if self.0 & (1 << 31) != 0 {
+            CallType::Fast
+        } else {
+            CallType::Yielding
+        }
+    }
+
+    pub const fn convention(self) -> Convention {
+        if self.0 & (1 << 30) != 0 {
+            Convention::Smc64
+        } else {
+            Convention::Smc32
+        }
+    }
+
+    pub const fn owner(self) -> Owner {
+        Owner::from_number((self.0 >> 24) as u8)
+    }
+
+    /// The function number within the range of the owner.
+    pub const fn number(self) -> u16 {
+        self.0 as u16
+    }
+}
+
+/// Returns the implemented SMCCC version: major in bits \[30:16\], minor in bits \[15:0\].
+pub const SMCCC_VERSION: FunctionId = FunctionId(0x8000_0000);
+/// Queries whether an Arm Architecture Service function is implemented.
+pub const SMCCC_ARCH_FEATURES: FunctionId = FunctionId(0x8000_0001);
+/// Returns the SoC revision or identifier. Requires SMCCC v1.2.
+pub const SMCCC_ARCH_SOC_ID: FunctionId = FunctionId(0x8000_0002);
+
+/// Return value of calls to unknown function identifiers.
+pub const NOT_SUPPORTED: i32 = -1;
+
+/// Issues the call `function` with `args` in `x1` onwards via `conduit` and returns `x0` to
+/// `x17`.
+///
+/// Panics if more than 17 arguments are given. For SMC32 calls, only the lower 32 bits of the
+/// arguments and results are significant.
+///
+/// # Safety
+///
+/// The call has whatever effect the firmware or hypervisor implements for `function`, e.g.
+/// starting a PE at an arbitrary address, powering off the system or writing to memory at an
+/// address in `args`. The caller must ensure that these effects do not violate memory safety.
+/// [`psci::Psci`] provides safe wrappers of the PSCI calls that have no such effects.
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+#[inline]
+pub unsafe fn call(conduit: Conduit, function: FunctionId, args: &[u64]) -> [u64; 18] {
+    let mut regs = [0u64; 18];
+    regs[0] = function.0 as u64;
+    regs[1..=args.len()].copy_from_slice(args);
+
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            macro_rules! conduit {
+                ($insn:literal) => {
+                    core::arch::asm!(
+                        $insn,
+                        inout("x0") regs[0],
+                        inout("x1") regs[1],
+                        inout("x2") regs[2],
+                        inout("x3") regs[3],
+                        inout("x4") regs[4],
+                        inout("x5") regs[5],
+                        inout("x6") regs[6],
+                        inout("x7") regs[7],
+                        inout("x8") regs[8],
+                        inout("x9") regs[9],
+                        inout("x10") regs[10],
+                        inout("x11") regs[11],
+                        inout("x12") regs[12],
+                        inout("x13") regs[13],
+                        inout("x14") regs[14],
+                        inout("x15") regs[15],
+                        inout("x16") regs[16],
+                        inout("x17") regs[17],
+                        options(nostack)
+                    )
+                };
+            }
+
+            match conduit {
+                Conduit::Hvc => conduit!("hvc #0"),
+                Conduit::Smc => conduit!("smc #0"),
+            }
+
+            regs
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_ids() {
+        let id = FunctionId::fast64(Owner::StandardSecure, 3);
+        assert_eq!(id, psci::CPU_ON_64);
+        assert_eq!(id.call_type(), CallType::Fast);
+        assert_eq!(id.convention(), Convention::Smc64);
+        assert_eq!(id.owner(), Owner::StandardSecure);
+        assert_eq!(id.number(), 3);
+
+        let id = FunctionId::new(CallType::Yielding, Convention::Smc32, Owner::Other(50), 7);
+        assert_eq!(id.0, 0x3200_0007);
+        assert_eq!(id.owner(), Owner::Other(50));
+        assert_eq!(FunctionId::fast32(Owner::Arm, 0), SMCCC_VERSION);
+    }
+}
@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Power State Coordination Interface.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::{
+//!     registers::MPIDR_EL1,
+//!     smccc::{psci::{self, Psci}, Conduit},
+//! };
+//! use tock_registers::interfaces::Readable;
+//!
+//! extern "C" {
+//!     fn secondary_entry();
+//! }
+//!
+//! let psci = Psci::new(Conduit::Smc);
+//! let target = psci::target_affinity(MPIDR_EL1.get()) + 1;
+//!
+//! // `secondary_entry` sets up its own stack and does not return.
+//! unsafe { psci.cpu_on(target, secondary_entry as usize as u64, 0) }.unwrap();
+//! ```
+
+use super::{call, Conduit, FunctionId};
+use crate::registers::MPIDR_EL1;
+use tock_registers::LocalRegisterCopy;
+
+/// Returns the implemented PSCI version.
+pub const PSCI_VERSION: FunctionId = FunctionId(0x8400_0000);
+/// Suspends the calling PE, SMC32 variant.
+pub const CPU_SUSPEND_32: FunctionId = FunctionId(0x8400_0001);
+/// Suspends the calling PE, SMC64 variant.
+pub const CPU_SUSPEND_64: FunctionId = FunctionId(0xc400_0001);
+/// Powers down the calling PE.
+pub const CPU_OFF: FunctionId = FunctionId(0x8400_0002);
+/// Powers up a PE, SMC32 variant.
+pub const CPU_ON_32: FunctionId = FunctionId(0x8400_0003);
+/// Powers up a PE, SMC64 variant.
+pub const CPU_ON_64: FunctionId = FunctionId(0xc400_0003);
+/// Returns the power state of an affinity instance, SMC32 variant.
+pub const AFFINITY_INFO_32: FunctionId = FunctionId(0x8400_0004);
+/// Returns the power state of an affinity instance, SMC64 variant.
+pub const AFFINITY_INFO_64: FunctionId = FunctionId(0xc400_0004);
+/// Shuts down the system.
+pub const SYSTEM_OFF: FunctionId = FunctionId(0x8400_0008);
+/// Resets the system.
+pub const SYSTEM_RESET: FunctionId = FunctionId(0x8400_0009);
+/// Queries whether a PSCI function is implemented.
+pub const PSCI_FEATURES: FunctionId = FunctionId(0x8400_000a);
+
+/// PSCI error codes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `NOT_SUPPORTED` (-1): the function is not implemented.
+    NotSupported,
+    /// `INVALID_PARAMETERS` (-2): an argument is invalid.
+    InvalidParameters,
+    /// `DENIED` (-3): the call is not permitted.
+    Denied,
+    /// `ALREADY_ON` (-4): the target PE is already on.
+    AlreadyOn,
+    /// `ON_PENDING` (-5): a `CPU_ON` call for the target PE is still pending.
+    OnPending,
+    /// `INTERNAL_FAILURE` (-6): the implementation failed.
+    InternalFailure,
+    /// `NOT_PRESENT` (-7): the target is not present.
+    NotPresent,
+    /// `DISABLED` (-8): the target is disabled.
+    Disabled,
+    /// `INVALID_ADDRESS` (-9): an address argument is invalid.
+    InvalidAddress,
+    /// A negative return value not defined by the specification.
+    Unknown(i32),
+}
+
+impl Error {
+    /// Maps a negative return value to an error.
+    pub const fn from_code(code: i32) -> Self {
+        match code {
+            -1 => Error::NotSupported,
+            -2 => Error::InvalidParameters,
+            -3 => Error::Denied,
+            -4 => Error::AlreadyOn,
+            -5 => Error::OnPending,
+            -6 => Error::InternalFailure,
+            -7 => Error::NotPresent,
+            -8 => Error::Disabled,
+            -9 => Error::InvalidAddress,
+            code => Error::Unknown(code),
+        }
+    }
+
+    /// The return value of the error.
+    pub const fn code(self) -> i32 {
+        match self {
+            Error::NotSupported => -1,
+            Error::InvalidParameters => -2,
+            Error::Denied => -3,
+            Error::AlreadyOn => -4,
+            Error::OnPending => -5,
+            Error::InternalFailure => -6,
+            Error::NotPresent => -7,
+            Error::Disabled => -8,
+            Error::InvalidAddress => -9,
+            Error::Unknown(code) => code,
+        }
+    }
+}
+
+/// Maps a 32-bit PSCI return value to a result.
+pub const fn result(ret: u64) -> Result<u32, Error> {
+    let ret = ret as u32 as i32;
+
+    if ret < 0 {
+        Err(Error::from_code(ret))
+    } else {
+        Ok(ret as u32)
+    }
+}
+
+/// A PSCI version.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    /// The major version, incremented for incompatible changes.
+    pub major: u16,
+    /// The minor version.
+    pub minor: u16,
+}
+
+/// Power state of an affinity instance, as returned by `AFFINITY_INFO`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AffinityState {
+    /// At least one PE of the affinity instance is on.
+    On,
+    /// All PEs of the affinity instance are off.
+    Off,
+    /// The affinity instance is transitioning to on.
+    OnPending,
+}
+
+/// Returns the target affinity for `CPU_ON` and `AFFINITY_INFO` of the PE with the `MPIDR_EL1`
+/// value `mpidr`, i.e. the `Aff3`, `Aff2`, `Aff1` and `Aff0` fields in their original positions.
+pub fn target_affinity(mpidr: u64) -> u64 {
+    let mpidr: LocalRegisterCopy<u64, MPIDR_EL1::Register> = LocalRegisterCopy::new(mpidr);
+
+    (MPIDR_EL1::Aff3.val(mpidr.read(MPIDR_EL1::Aff3))
+        + MPIDR_EL1::Aff2.val(mpidr.read(MPIDR_EL1::Aff2))
+        + MPIDR_EL1::Aff1.val(mpidr.read(MPIDR_EL1::Aff1))
+        + MPIDR_EL1::Aff0.val(mpidr.read(MPIDR_EL1::Aff0)))
+    .value
+}
+
+/// A PSCI client using the SMC64 variants of the calls.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Psci {
+    conduit: Conduit,
+}
+
+impl Psci {
+    /// Creates a client that issues calls via `conduit`.
+    pub const fn new(conduit: Conduit) -> Self {
+        Psci { conduit }
+    }
+
+    /// Returns the conduit the calls are issued via.
+    pub const fn conduit(&self) -> Conduit {
+        self.conduit
+    }
+
+    /// Issues the PSCI call `function`, see [`call`] for the safety requirements.
+    unsafe fn call(&self, function: FunctionId, args: &[u64]) -> u64 {
+        call(self.conduit, function, args)[0]
+    }
+
+    /// Returns the implemented PSCI version.
+    pub fn version(&self) -> Result<Version, Error> {
+        // SAFETY: PSCI_VERSION has no side effects.
+        let version = result(unsafe { self.call(PSCI_VERSION, &[]) })?;
+
+        Ok(Version {
+            major: (version >> 16) as u16,
+            minor: version as u16,
+        })
+    }
+
+    /// Suspends execution of the calling PE to the power state `power_state`.
+    ///
+    /// For standby states, the call returns on wakeup. For powerdown states, execution resumes at
+    /// `entry` with `context` in `x0` on wakeup, and the call does not return.
+    ///
+    /// # Safety
+    ///
+    /// `entry` must be the physical address of code that can run at the Exception level of the
+    /// caller with the MMU and the caches disabled, like the entry point of [`Psci::cpu_on`]. The
+    /// registers, the stack pointer and the system register state of the PE are lost in powerdown
+    /// states, so the code must restore what it needs before returning into the suspended context.
+    pub unsafe fn cpu_suspend(
+        &self,
+        power_state: u32,
+        entry: u64,
+        context: u64,
+    ) -> Result<(), Error> {
+        result(self.call(CPU_SUSPEND_64, &[power_state as u64, entry, context])).map(|_| ())
+    }
+
+    /// Powers down the calling PE. Only returns on failure.
+    pub fn cpu_off(&self) -> Error {
+        // SAFETY: CPU_OFF does not return on success, so the caller cannot observe its effects.
+        match result(unsafe { self.call(CPU_OFF, &[]) }) {
+            Err(e) => e,
+            Ok(_) => Error::Unknown(0),
+        }
+    }
+
+    /// Powers up the PE with the affinity `target` (see [`target_affinity`]), which starts
+    /// executing at `entry` with `context` in `x0`.
+    ///
+    /// # Safety
+    ///
+    /// `entry` must be the physical address of code that can run at the Exception level of the
+    /// caller with the MMU and the caches disabled and all exceptions masked. Apart from `x0`, the
+    /// general purpose registers, the stack pointers and the system registers of the Exception
+    /// level hold UNKNOWN values, so the code must set up its own stack and translation regime.
+    /// Any memory that the code accesses through `context` must remain valid until it does.
+    pub unsafe fn cpu_on(&self, target: u64, entry: u64, context: u64) -> Result<(), Error> {
+        result(self.call(CPU_ON_64, &[target, entry, context])).map(|_| ())
+    }
+
+    /// Returns the state of the affinity instance `target`, taking affinity levels below
+    /// `lowest_level` into account.
+    pub fn affinity_info(&self, target: u64, lowest_level: u32) -> Result<AffinityState, Error> {
+        // SAFETY: AFFINITY_INFO has no side effects.
+        let state = unsafe { self.call(AFFINITY_INFO_64, &[target, lowest_level as u64]) };
+
+        match result(state)? {
+            0 => Ok(AffinityState::On),
+            1 => Ok(AffinityState::Off),
+            2 => Ok(AffinityState::OnPending),
+            n => Err(Error::Unknown(n as i32)),
+        }
+    }
+
+    /// Shuts down the system. Only returns on failure.
+    pub fn system_off(&self) -> Error {
+        // SAFETY: SYSTEM_OFF does not return on success, so the caller cannot observe its effects.
+        Error::from_code(unsafe { self.call(SYSTEM_OFF, &[]) } as i32)
+    }
+
+    /// Resets the system. Only returns on failure.
+    pub fn system_reset(&self) -> Error {
+        // SAFETY: SYSTEM_RESET does not return on success, so the caller cannot observe its
+        // effects.
+        Error::from_code(unsafe { self.call(SYSTEM_RESET, &[]) } as i32)
+    }
+
+    /// Queries whether `function` is implemented and returns its feature flags.
+    pub fn features(&self, function: FunctionId) -> Result<u32, Error> {
+        // SAFETY: PSCI_FEATURES has no side effects.
+        result(unsafe { self.call(PSCI_FEATURES, &[function.0 as u64]) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results() {
+        assert_eq!(result(0), Ok(0));
+        assert_eq!(result(0x1_0002), Ok(0x1_0002));
+        assert_eq!(result(0xffff_fffe), Err(Error::InvalidParameters));
+        assert_eq!(result(-4i64 as u64), Err(Error::AlreadyOn));
+        assert_eq!(result(-42i64 as u64), Err(Error::Unknown(-42)));
+        assert_eq!(Error::from_code(-9).code(), -9);
+    }
+
+    #[test]
+    fn affinity() {
+        // Aff3 = 1, U, MT, Aff2 = 2, Aff1 = 3, Aff0 = 4.
+        assert_eq!(target_affinity(0x1_c102_0304), 0x1_0002_0304);
+    }
+}
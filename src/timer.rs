@@ -0,0 +1,724 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Generic Timer.
+//!
+//! [`Timer`] selects one of the timers of the Generic Timer and arms it with a one-shot deadline,
+//! either in ticks of its counter or as a [`Duration`]. Durations are converted to ticks with the
+//! counter frequency in `CNTFRQ_EL0`, which must have been programmed by firmware. [`delay`]
+//! busy-waits on the virtual counter.
+//!
+//! [`Clock`] takes timestamps ([`Instant`]) that are ordered with the surrounding instructions.
+//! It reads the self-synchronized counter views `CNTPCTSS_EL0` and `CNTVCTSS_EL0` where FEAT_ECV
+//! is implemented, and an `isb` followed by `CNTPCT_EL0` or `CNTVCT_EL0` otherwise.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::timer::{self, Clock, Counter, Timer};
+//! use core::time::Duration;
+//!
+//! Timer::Virtual.set_timeout(Duration::from_millis(10));
+//! while !Timer::Virtual.is_pending() {}
+//! Timer::Virtual.disable();
+//!
+//! timer::delay(Duration::from_micros(50));
+//!
+//! let clock = Clock::new(Counter::Virtual);
+//! let start = clock.now();
+//! let deadline = start + Duration::from_millis(1);
+//! while !deadline.is_reached() {}
+//! let latency = start.elapsed();
+//! ```
+
+use crate::{
+    asm::barrier,
+    features::{CpuFeatures, Feature},
+    registers::{
+        CNTFRQ_EL0, CNTHP_CTL_EL2, CNTHP_CVAL_EL2, CNTHV_CTL_EL2, CNTHV_CVAL_EL2, CNTKCTL_EL1,
+        CNTPCTSS_EL0, CNTPCT_EL0, CNTPOFF_EL2, CNTPS_CTL_EL1, CNTPS_CVAL_EL1, CNTP_CTL_EL0,
+        CNTP_CVAL_EL0, CNTVCTSS_EL0, CNTVCT_EL0, CNTVOFF_EL2, CNTV_CTL_EL0, CNTV_CVAL_EL0,
+    },
+};
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::Duration,
+};
+use tock_registers::interfaces::{Readable, Writeable};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+// Bits of the CNT*_CTL_EL* registers, which share one layout.
+const CTL_ENABLE: u64 = 1 << 0;
+const CTL_IMASK: u64 = 1 << 1;
+const CTL_ISTATUS: u64 = 1 << 2;
+
+/// A timer of the Generic Timer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Timer {
+    /// EL1 physical timer, `CNTP_*_EL0`.
+    Physical,
+    /// Virtual timer, `CNTV_*_EL0`.
+    Virtual,
+    /// EL2 physical timer, `CNTHP_*_EL2`.
+    HypPhysical,
+    /// EL2 virtual timer, `CNTHV_*_EL2`. Requires FEAT_VHE.
+    HypVirtual,
+    /// Secure physical timer, `CNTPS_*_EL1`.
+    SecurePhysical,
+}
+
+/// A view of the system counter.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Counter {
+    /// Physical count, `CNTPCT_EL0`.
+    Physical,
+    /// Virtual count, `CNTVCT_EL0`, which is the physical count minus the virtual offset.
+    Virtual,
+}
+
+impl Counter {
+    /// Reads the counter.
+    ///
+    /// The read is preceded by an `isb`, so that it is not performed ahead of earlier
+    /// instructions.
+    #[inline(always)]
+    pub fn read(self) -> u64 {
+        barrier::isb(barrier::SY);
+
+        match self {
+            Counter::Physical => CNTPCT_EL0.get(),
+            Counter::Virtual => CNTVCT_EL0.get(),
+        }
+    }
+
+    /// Reads the self-synchronized view of the counter, which is not performed ahead of earlier
+    /// instructions without needing an `isb`. Requires FEAT_ECV.
+    #[inline(always)]
+    pub fn read_self_synchronized(self) -> u64 {
+        match self {
+            Counter::Physical => CNTPCTSS_EL0.get(),
+            Counter::Virtual => CNTVCTSS_EL0.get(),
+        }
+    }
+}
+
+/// Evaluates `$body` with `$ctl` and `$cval` bound to the control and compare value registers of
+/// `$timer`.
+macro_rules! with_registers {
+    ($timer:expr, |$ctl:ident, $cval:ident| $body:expr) => {
+        match $timer {
+            Timer::Physical => {
+                let ($ctl, $cval) = (CNTP_CTL_EL0, CNTP_CVAL_EL0);
+                $body
+            }
+            Timer::Virtual => {
+                let ($ctl, $cval) = (CNTV_CTL_EL0, CNTV_CVAL_EL0);
+                $body
+            }
+            Timer::HypPhysical => {
+                let ($ctl, $cval) = (CNTHP_CTL_EL2, CNTHP_CVAL_EL2);
+                $body
+            }
+            Timer::HypVirtual => {
+                let ($ctl, $cval) = (CNTHV_CTL_EL2, CNTHV_CVAL_EL2);
+                $body
+            }
+            Timer::SecurePhysical => {
+                let ($ctl, $cval) = (CNTPS_CTL_EL1, CNTPS_CVAL_EL1);
+                $body
+            }
+        }
+    };
+}
+
+impl Timer {
+    /// Returns the counter the timer compares against.
+    pub const fn source(self) -> Counter {
+        match self {
+            Timer::Physical | Timer::HypPhysical | Timer::SecurePhysical => Counter::Physical,
+            Timer::Virtual | Timer::HypVirtual => Counter::Virtual,
+        }
+    }
+
+    /// Reads the counter the timer compares against, see [`Counter::read`].
+    #[inline(always)]
+    pub fn counter(self) -> u64 {
+        self.source().read()
+    }
+
+    /// Arms the timer to fire once its counter reaches `deadline`, and unmasks its interrupt.
+    #[inline(always)]
+    pub fn set_deadline(self, deadline: u64) {
+        with_registers!(self, |ctl, cval| {
+            cval.set(deadline);
+            ctl.set(CTL_ENABLE);
+        })
+    }
+
+    /// Arms the timer to fire once `timeout` has elapsed, and returns the deadline.
+    ///
+    /// The deadline saturates at the maximum count.
+    #[inline(always)]
+    pub fn set_timeout(self, timeout: Duration) -> u64 {
+        let deadline = self
+            .counter()
+            .saturating_add(duration_to_ticks(timeout, frequency()));
+
+        self.set_deadline(deadline);
+        deadline
+    }
+
+    /// Returns the deadline the timer was last armed with.
+    #[inline(always)]
+    pub fn deadline(self) -> u64 {
+        with_registers!(self, |_ctl, cval| cval.get())
+    }
+
+    /// Returns whether the timer is enabled and its deadline has been reached.
+    ///
+    /// This does not depend on whether the interrupt is masked.
+    #[inline(always)]
+    pub fn is_pending(self) -> bool {
+        let ctl = with_registers!(self, |ctl, _cval| ctl.get());
+
+        ctl & (CTL_ENABLE | CTL_ISTATUS) == CTL_ENABLE | CTL_ISTATUS
+    }
+
+    /// Masks or unmasks the interrupt of the timer, leaving it enabled or disabled.
+    #[inline(always)]
+    pub fn set_masked(self, masked: bool) {
+        with_registers!(self, |ctl, _cval| {
+            let value = ctl.get() & CTL_ENABLE;
+
+            ctl.set(if masked { value | CTL_IMASK } else { value })
+        })
+    }
+
+    /// Disables the timer, which also deasserts its interrupt.
+    #[inline(always)]
+    pub fn disable(self) {
+        with_registers!(self, |ctl, _cval| ctl.set(0))
+    }
+}
+
+/// Returns the frequency of the system counter in Hz, as programmed in `CNTFRQ_EL0`.
+#[inline(always)]
+pub fn frequency() -> u32 {
+    CNTFRQ_EL0.get() as u32
+}
+
+/// Converts a number of ticks at `frequency` Hz to a [`Duration`], rounding down.
+///
+/// # Panics
+///
+/// Panics if `frequency` is zero.
+pub const fn ticks_to_duration(ticks: u64, frequency: u32) -> Duration {
+    let frequency = frequency as u64;
+    let nanos = (ticks % frequency) as u128 * NANOS_PER_SEC / frequency as u128;
+
+    Duration::new(ticks / frequency, nanos as u32)
+}
+
+/// Converts a [`Duration`] to a number of ticks at `frequency` Hz, rounding up.
+///
+/// Rounding up means that waiting for the returned number of ticks takes at least `duration`. The
+/// result saturates at `u64::MAX`.
+pub const fn duration_to_ticks(duration: Duration, frequency: u32) -> u64 {
+    let ticks = (duration.as_nanos() * frequency as u128).div_ceil(NANOS_PER_SEC);
+
+    if ticks > u64::MAX as u128 {
+        u64::MAX
+    } else {
+        ticks as u64
+    }
+}
+
+/// Busy-waits until `ticks` ticks have been counted by `counter`.
+///
+/// The elapsed ticks are computed with a wrapping subtraction, so that the wait is correct even if
+/// the counter wraps.
+#[inline(always)]
+fn wait(ticks: u64, mut counter: impl FnMut() -> u64) {
+    let start = counter();
+
+    while counter().wrapping_sub(start) < ticks {
+        core::hint::spin_loop();
+    }
+}
+
+/// Busy-waits for at least `duration`, on the virtual counter.
+#[inline(always)]
+pub fn delay(duration: Duration) {
+    let ticks = duration_to_ticks(duration, frequency());
+
+    wait(ticks, || Counter::Virtual.read())
+}
+
+/// A source of [`Instant`]s, reading a [`Counter`] in program order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Clock {
+    counter: Counter,
+    self_synchronized: bool,
+    frequency: u32,
+}
+
+impl Clock {
+    /// Returns a clock on `counter` of the executing PE.
+    ///
+    /// This reads the ID registers to detect FEAT_ECV, and the counter frequency from
+    /// `CNTFRQ_EL0`. The clock can be copied to avoid repeating this.
+    pub fn new(counter: Counter) -> Self {
+        Self::with_features(counter, &CpuFeatures::read(), frequency())
+    }
+
+    /// Returns a clock on `counter` of a PE with `features`, counting at `frequency` Hz.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frequency` is zero.
+    pub fn with_features(counter: Counter, features: &CpuFeatures, frequency: u32) -> Self {
+        assert!(frequency != 0, "counter frequency is zero");
+
+        Clock {
+            counter,
+            self_synchronized: features.has(Feature::ECV),
+            frequency,
+        }
+    }
+
+    /// Returns the counter the clock reads.
+    pub const fn counter(&self) -> Counter {
+        self.counter
+    }
+
+    /// Returns the frequency of the counter in Hz.
+    pub const fn frequency(&self) -> u32 {
+        self.frequency
+    }
+
+    /// Returns whether the clock reads the self-synchronized view of the counter.
+    pub const fn is_self_synchronized(&self) -> bool {
+        self.self_synchronized
+    }
+
+    /// Returns the current time.
+    ///
+    /// The counter is not read ahead of earlier instructions.
+    #[inline(always)]
+    pub fn now(&self) -> Instant {
+        let ticks = if self.self_synchronized {
+            self.counter.read_self_synchronized()
+        } else {
+            self.counter.read()
+        };
+
+        self.instant(ticks)
+    }
+
+    /// Returns the instant at which the counter reads `ticks`.
+    pub const fn instant(&self, ticks: u64) -> Instant {
+        Instant {
+            ticks,
+            clock: *self,
+        }
+    }
+}
+
+/// A point in time of a [`Clock`].
+///
+/// Instants are compared by their count, so only instants of the same clock are meaningfully
+/// comparable. Adding or subtracting a [`Duration`] rounds it up to whole ticks, and panics if the
+/// result overflows the counter.
+#[derive(Copy, Clone, Debug)]
+pub struct Instant {
+    ticks: u64,
+    clock: Clock,
+}
+
+impl Instant {
+    /// Returns the count of the counter at this instant.
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns the clock of this instant.
+    pub const fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    /// Returns the time elapsed from `earlier` to this instant, or `None` if `earlier` is later.
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        let ticks = self.ticks.checked_sub(earlier.ticks)?;
+
+        Some(ticks_to_duration(ticks, self.clock.frequency))
+    }
+
+    /// Returns the time elapsed from `earlier` to this instant, or zero if `earlier` is later.
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
+
+    /// Returns the time elapsed since this instant.
+    #[inline(always)]
+    pub fn elapsed(&self) -> Duration {
+        self.clock.now().duration_since(*self)
+    }
+
+    /// Returns whether the clock has reached this instant, e.g. a deadline.
+    #[inline(always)]
+    pub fn is_reached(&self) -> bool {
+        self.clock.now() >= *self
+    }
+
+    /// Returns the time remaining until this instant, or zero if it has been reached.
+    #[inline(always)]
+    pub fn remaining(&self) -> Duration {
+        self.duration_since(self.clock.now())
+    }
+
+    /// Busy-waits until the clock has reached this instant.
+    #[inline(always)]
+    pub fn wait(&self) {
+        while !self.is_reached() {
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Returns this instant moved forward by `duration`, or `None` on overflow.
+    pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+        let ticks = duration_to_ticks(duration, self.clock.frequency);
+
+        Some(self.clock.instant(self.ticks.checked_add(ticks)?))
+    }
+
+    /// Returns this instant moved back by `duration`, or `None` on underflow.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+        let ticks = duration_to_ticks(duration, self.clock.frequency);
+
+        Some(self.clock.instant(self.ticks.checked_sub(ticks)?))
+    }
+}
+
+impl PartialEq for Instant {
+    fn eq(&self, other: &Self) -> bool {
+        self.ticks == other.ticks
+    }
+}
+
+impl Eq for Instant {}
+
+impl PartialOrd for Instant {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Instant {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ticks.cmp(&other.ticks)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, duration: Duration) -> Instant {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, duration: Duration) -> Instant {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    /// Returns the time elapsed from `earlier` to this instant, or zero if `earlier` is later.
+    fn sub(self, earlier: Instant) -> Duration {
+        self.duration_since(earlier)
+    }
+}
+
+/// Saved timer state of a vCPU.
+///
+/// Holds the EL1 timers, `CNTKCTL_EL1` and the counter offsets, i.e. the registers a hypervisor
+/// running with `HCR_EL2.E2H == 0` has to switch when scheduling vCPUs. `CNTPOFF_EL2` is only
+/// accessed where it is implemented, i.e. with FEAT_ECV_POFF.
+///
+/// The compare values are counts of the vCPU, so the timers of a migrated vCPU keep their
+/// deadlines if the offsets are adjusted to continue its counts on the new host:
+///
+/// ```no_run
+/// use aarch64_cpu::timer::{Context, Counter};
+///
+/// // Source host
+/// let mut context = Context::default();
+/// context.save();
+/// let count = context.virtual_count(Counter::Physical.read());
+///
+/// // Destination host
+/// context.set_virtual_count(count, Counter::Physical.read());
+/// context.restore();
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Context {
+    pub kctl: u64,
+    pub pctl: u64,
+    pub pcval: u64,
+    pub vctl: u64,
+    pub vcval: u64,
+    pub voff: u64,
+    pub poff: u64,
+}
+
+/// Whether `CNTPOFF_EL2` is implemented.
+fn has_cntpoff() -> bool {
+    Feature::ECV_POFF.is_present_on_current_cpu()
+}
+
+impl Context {
+    /// Saves the timer state of the vCPU and disables its timers.
+    pub fn save(&mut self) {
+        self.kctl = CNTKCTL_EL1.get();
+        self.pctl = CNTP_CTL_EL0.get();
+        self.pcval = CNTP_CVAL_EL0.get();
+        self.vctl = CNTV_CTL_EL0.get();
+        self.vcval = CNTV_CVAL_EL0.get();
+        self.voff = CNTVOFF_EL2.get();
+        if has_cntpoff() {
+            self.poff = CNTPOFF_EL2.get();
+        }
+
+        CNTP_CTL_EL0.set(0);
+        CNTV_CTL_EL0.set(0);
+    }
+
+    /// Restores the timer state of the vCPU, enabling the timers that were enabled when saved.
+    pub fn restore(&self) {
+        CNTVOFF_EL2.set(self.voff);
+        if has_cntpoff() {
+            CNTPOFF_EL2.set(self.poff);
+        }
+
+        CNTP_CVAL_EL0.set(self.pcval);
+        CNTV_CVAL_EL0.set(self.vcval);
+        CNTKCTL_EL1.set(self.kctl);
+        CNTP_CTL_EL0.set(self.pctl);
+        CNTV_CTL_EL0.set(self.vctl);
+    }
+
+    /// Returns the virtual count of the vCPU when the physical count is `physical`.
+    pub const fn virtual_count(&self, physical: u64) -> u64 {
+        physical.wrapping_sub(self.voff)
+    }
+
+    /// Sets the virtual offset, so that the virtual count of the vCPU is `count` when the physical
+    /// count is `physical`.
+    pub fn set_virtual_count(&mut self, count: u64, physical: u64) {
+        self.voff = physical.wrapping_sub(count);
+    }
+
+    /// Returns the physical count of the vCPU when the physical count is `physical`.
+    ///
+    /// The physical offset only applies if `CNTHCTL_EL2.ECV` is set.
+    pub const fn physical_count(&self, physical: u64) -> u64 {
+        physical.wrapping_sub(self.poff)
+    }
+
+    /// Sets the physical offset, so that the physical count of the vCPU is `count` when the
+    /// physical count is `physical`.
+    pub fn set_physical_count(&mut self, count: u64, physical: u64) {
+        self.poff = physical.wrapping_sub(count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            ticks_to_duration(62_500_000, 62_500_000),
+            Duration::from_secs(1)
+        );
+        assert_eq!(ticks_to_duration(1, 24_000_000), Duration::from_nanos(41));
+        assert_eq!(
+            ticks_to_duration(u64::MAX, 1_000_000_000),
+            Duration::new(18_446_744_073, 709_551_615)
+        );
+
+        assert_eq!(
+            duration_to_ticks(Duration::from_millis(10), 62_500_000),
+            625_000
+        );
+        assert_eq!(duration_to_ticks(Duration::from_nanos(1), 24_000_000), 1);
+        assert_eq!(duration_to_ticks(Duration::ZERO, 24_000_000), 0);
+        assert_eq!(duration_to_ticks(Duration::MAX, u32::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn wait_across_wrap() {
+        let mut now = u64::MAX - 2;
+        let mut reads = 0;
+
+        wait(5, || {
+            reads += 1;
+            now = now.wrapping_add(1);
+            now
+        });
+        assert_eq!(now, 3);
+        assert_eq!(reads, 6);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn timers() {
+        use crate::registers::mock;
+
+        mock::clear();
+        mock::set_reset_value("CNTFRQ_EL0", 62_500_000);
+        mock::set_reset_value("CNTPCT_EL0", 1_000);
+        mock::set_reset_value("CNTVCT_EL0", 500);
+
+        assert_eq!(
+            Timer::Virtual.set_timeout(Duration::from_millis(10)),
+            625_500
+        );
+        assert_eq!(mock::peek("CNTV_CVAL_EL0"), 625_500);
+        assert_eq!(mock::peek("CNTV_CTL_EL0"), CTL_ENABLE);
+
+        Timer::HypPhysical.set_timeout(Duration::from_millis(10));
+        assert_eq!(Timer::HypPhysical.deadline(), 626_000);
+
+        Timer::SecurePhysical.set_deadline(u64::MAX);
+        Timer::SecurePhysical.set_masked(true);
+        assert_eq!(mock::peek("CNTPS_CTL_EL1"), CTL_ENABLE | CTL_IMASK);
+        assert!(!Timer::SecurePhysical.is_pending());
+
+        mock::poke("CNTPS_CTL_EL1", CTL_ENABLE | CTL_IMASK | CTL_ISTATUS);
+        assert!(Timer::SecurePhysical.is_pending());
+        Timer::SecurePhysical.disable();
+        assert!(!Timer::SecurePhysical.is_pending());
+
+        delay(Duration::ZERO);
+    }
+
+    #[test]
+    fn instants() {
+        let clock = Clock::with_features(Counter::Virtual, &CpuFeatures::default(), 62_500_000);
+        let start = clock.instant(1_000);
+
+        let deadline = start + Duration::from_millis(10);
+        assert_eq!(deadline.ticks(), 626_000);
+        assert_eq!(deadline - start, Duration::from_millis(10));
+        assert_eq!(start - deadline, Duration::ZERO);
+        assert_eq!(start.checked_duration_since(deadline), None);
+        assert!(start < deadline);
+
+        // 1 ns is rounded up to one tick of 16 ns.
+        let mut later = start;
+        later += Duration::from_nanos(1);
+        assert_eq!(later - start, Duration::from_nanos(16));
+        later -= Duration::from_nanos(16);
+        assert_eq!(later, start);
+
+        assert!(start.checked_sub(Duration::from_secs(1)).is_none());
+        assert!(clock
+            .instant(u64::MAX)
+            .checked_add(Duration::from_nanos(1))
+            .is_none());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn clock() {
+        use crate::registers::mock;
+
+        mock::clear();
+        mock::set_reset_value("CNTFRQ_EL0", 1_000_000);
+        mock::set_reset_value("CNTPCT_EL0", 100);
+        mock::set_reset_value("CNTPCTSS_EL0", 200);
+
+        let clock = Clock::new(Counter::Physical);
+        assert!(!clock.is_self_synchronized());
+        assert_eq!(clock.now().ticks(), 100);
+
+        // FEAT_ECV
+        mock::set_reset_value("ID_AA64MMFR0_EL1", 1 << 60);
+        let clock = Clock::new(Counter::Physical);
+        assert!(clock.is_self_synchronized());
+        assert_eq!(clock.now().ticks(), 200);
+
+        let deadline = clock.instant(250);
+        assert!(!deadline.is_reached());
+        assert_eq!(deadline.remaining(), Duration::from_micros(50));
+        assert_eq!(clock.instant(150).elapsed(), Duration::from_micros(50));
+        mock::poke("CNTPCTSS_EL0", 250);
+        assert!(deadline.is_reached());
+        deadline.wait();
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn context() {
+        use crate::registers::mock;
+
+        mock::clear();
+        mock::set_reset_value("CNTV_CTL_EL0", CTL_ENABLE);
+        mock::set_reset_value("CNTV_CVAL_EL0", 5_000);
+        mock::set_reset_value("CNTVOFF_EL2", 1_000);
+        mock::set_reset_value("CNTPOFF_EL2", 7);
+
+        let mut context = Context::default();
+        context.save();
+        assert_eq!(context.vctl, CTL_ENABLE);
+        assert_eq!(context.vcval, 5_000);
+        assert_eq!(context.poff, 0);
+        assert_eq!(mock::peek("CNTV_CTL_EL0"), 0);
+
+        // Migrate to a host whose counter is 10_000 ticks ahead.
+        let count = context.virtual_count(3_000);
+        assert_eq!(count, 2_000);
+        context.set_virtual_count(count, 13_000);
+        assert_eq!(context.voff, 11_000);
+
+        context.set_physical_count(3_000, 13_000);
+        context.restore();
+        assert_eq!(mock::peek("CNTVOFF_EL2"), 11_000);
+        assert_eq!(mock::peek("CNTV_CTL_EL0"), CTL_ENABLE);
+        assert_eq!(mock::peek("CNTPOFF_EL2"), 7);
+
+        // CNTPOFF_EL2
+        mock::set_reset_value("ID_AA64MMFR0_EL1", 2 << 60);
+        context.restore();
+        assert_eq!(mock::peek("CNTPOFF_EL2"), 10_000);
+        context.save();
+        assert_eq!(context.poff, 10_000);
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2023 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! GICv3 virtual CPU interface context.
+//!
+//! [`Context`] holds the state of the virtual CPU interface of one vCPU, i.e. the `ICH_*_EL2`
+//! registers a hypervisor has to switch when scheduling vCPUs. Only the List and Active Priorities
+//! registers reported by `ICH_VTR_EL2` are accessed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aarch64_cpu::vgic::Context;
+//!
+//! let mut vcpu0 = Context::default();
+//! let vcpu1 = Context::default();
+//!
+//! vcpu0.save();
+//! vcpu1.restore();
+//! ```
+
+use crate::registers::{ichap0r, ichap1r, ichlr, ICH_HCR_EL2, ICH_VMCR_EL2, ICH_VTR_EL2};
+use tock_registers::interfaces::{Readable, Writeable};
+
+/// Returns the number of implemented List registers.
+pub fn num_list_registers() -> u8 {
+    ICH_VTR_EL2.read(ICH_VTR_EL2::ListRegs) as u8 + 1
+}
+
+/// Returns the number of implemented Active Priorities registers per group.
+pub fn num_apr_registers() -> u8 {
+    match ICH_VTR_EL2.read(ICH_VTR_EL2::PREbits) {
+        6 => 4,
+        5 => 2,
+        _ => 1,
+    }
+}
+
+/// Saved state of the virtual CPU interface.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Context {
+    pub hcr: u64,
+    pub vmcr: u64,
+    pub ap0r: [u64; 4],
+    pub ap1r: [u64; 4],
+    pub lr: [u64; 16],
+}
+
+impl Context {
+    /// Saves the state of the virtual CPU interface and disables it.
+    pub fn save(&mut self) {
+        self.vmcr = ICH_VMCR_EL2.get();
+        self.hcr = ICH_HCR_EL2.get();
+
+        for n in 0..num_apr_registers() {
+            self.ap0r[n as usize] = ichap0r(n).get();
+            self.ap1r[n as usize] = ichap1r(n).get();
+        }
+
+        for n in 0..num_list_registers() {
+            self.lr[n as usize] = ichlr(n).get();
+        }
+
+        ICH_HCR_EL2.set(0);
+    }
+
+    /// Restores the state of the virtual CPU interface, enabling it if it was enabled when saved.
+    pub fn restore(&self) {
+        for n in 0..num_apr_registers() {
+            ichap0r(n).set(self.ap0r[n as usize]);
+            ichap1r(n).set(self.ap1r[n as usize]);
+        }
+
+        for n in 0..num_list_registers() {
+            ichlr(n).set(self.lr[n as usize]);
+        }
+
+        ICH_VMCR_EL2.set(self.vmcr);
+        ICH_HCR_EL2.set(self.hcr);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::registers::{mock, ICH_LR_EL2};
+
+    #[test]
+    fn save_restore() {
+        mock::clear();
+        // Two List registers, 6 preemption bits.
+        mock::set_reset_value("ICH_VTR_EL2", (5 << 26) | 1);
+        mock::poke("ICH_HCR_EL2", 1);
+        mock::poke(
+            "ICH_LR1_EL2",
+            (ICH_LR_EL2::State::Pending + ICH_LR_EL2::vINTID.val(27)).value,
+        );
+        mock::poke("ICH_AP1R1_EL2", 1 << 3);
+
+        let mut context = Context::default();
+        context.save();
+        assert_eq!(context.hcr, 1);
+        assert_eq!(context.lr[1], (1 << 62) | 27);
+        assert_eq!(context.ap1r, [0, 1 << 3, 0, 0]);
+        assert_eq!(mock::peek("ICH_HCR_EL2"), 0);
+
+        mock::clear();
+        mock::set_reset_value("ICH_VTR_EL2", (5 << 26) | 1);
+        context.restore();
+        assert_eq!(mock::peek("ICH_LR1_EL2"), (1 << 62) | 27);
+        assert_eq!(mock::peek("ICH_AP1R1_EL2"), 1 << 3);
+        assert_eq!(mock::peek("ICH_HCR_EL2"), 1);
+        assert!(!mock::accesses()
+            .iter()
+            .any(|a| matches!(a, mock::Access::Write("ICH_LR2_EL2", _))));
+    }
+}